@@ -205,7 +205,7 @@ export default function BountyReviewPage() {
         const qualityScore = qualityScores[submission.id] || 80; // Default to 80
 
         const txSignature = await approveSubmissionOnChain(connection, wallet, {
-          submissionId: submission.submission_id!, // Use stored blockchain submission ID
+          submissionAddress: submission.on_chain_submission_address!,
           bountyId: bounty.bounty_id, // Use blockchain UUID for PDA derivation
           contributorWallet: submission.profiles!.wallet_address!,
           qualityScore,
@@ -303,9 +303,10 @@ export default function BountyReviewPage() {
         }
 
         const txSignature = await rejectSubmissionOnChain(connection, wallet, {
-          submissionId: submission.submission_id!, // Use stored blockchain submission ID
+          submissionAddress: submission.on_chain_submission_address!,
           bountyId: bounty.bounty_id, // Use blockchain UUID for PDA derivation
           contributorWallet: submission.profiles!.wallet_address!,
+          reason: "other",
         });
 
         console.log("Rejection transaction:", txSignature);
@@ -22,14 +22,44 @@ import { useConnection, useWallet } from "@solana/wallet-adapter-react";
 import {
   submitVideoOnChain,
   type SubmitVideoParams,
+  type VideoSpec,
 } from "@/lib/solana/submission-instructions";
 import {
   initializeProfileOnChain,
   checkProfileExists,
 } from "@/lib/solana/profile-instructions";
-import { getExplorerUrl } from "@/lib/solana/utils";
+import { getExplorerUrl, hashFile } from "@/lib/solana/utils";
 import { WalletButton } from "@/components/wallet-button";
 
+/**
+ * Read the declared spec for a video from its metadata
+ */
+function readVideoSpec(file: File): Promise<VideoSpec> {
+  return new Promise((resolve, reject) => {
+    const url = URL.createObjectURL(file);
+    const video = document.createElement("video");
+    video.preload = "metadata";
+    video.onloadedmetadata = () => {
+      URL.revokeObjectURL(url);
+      const { videoWidth: width, videoHeight: height } = video;
+      resolve({
+        durationSecs: Math.round(video.duration),
+        width,
+        height,
+        // Browsers don't expose the frame rate, so declare the common default
+        fps: 30,
+        orientation:
+          width > height ? "landscape" : width < height ? "portrait" : "square",
+      });
+    };
+    video.onerror = () => {
+      URL.revokeObjectURL(url);
+      reject(new Error("Could not read video metadata"));
+    };
+    video.src = url;
+  });
+}
+
 interface UserProfile {
   id: string;
   display_name: string;
@@ -275,15 +305,21 @@ export default function SubmitVideoPage() {
       setIsBlockchainStep(true);
 
       const submissionId = `${Date.now()}-${Math.random().toString(36).substring(2, 9)}`;
+      const [contentHash, spec] = await Promise.all([
+        hashFile(videoFile),
+        readVideoSpec(videoFile),
+      ]);
 
       // For now, use placeholder values for IPFS/Arweave
       // In production, you would upload to IPFS/Arweave first
       const blockchainResult = await submitVideoOnChain(connection, wallet, {
         submissionId,
         bountyId: bounty.bounty_id, // Use blockchain UUID, not database ID
+        contentHash,
         ipfsHash: `ipfs-${submissionId}`, // Placeholder
         arweaveTx: `ar-${submissionId}`, // Placeholder
         metadataUri: `https://metadata/${submissionId}`, // Placeholder
+        spec,
       });
 
       console.log("Blockchain submission result:", blockchainResult);
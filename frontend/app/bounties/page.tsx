@@ -227,9 +227,13 @@ export default function BountiesPage() {
         totalPool,
         videosTarget: totalSlots,
         taskDescription: formData.description.trim(),
-        minDurationSecs: 30,
-        minResolution: "720p",
-        minFps: 30,
+        requirements: {
+          minDurationSecs: 30,
+          minWidth: 1280,
+          minHeight: 720,
+          minFps: 30,
+          category: formData.category,
+        },
         expiresAt,
       });
 
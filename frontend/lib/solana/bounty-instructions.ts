@@ -1,7 +1,30 @@
 import { AnchorProvider, BN } from "@coral-xyz/anchor";
 import { Connection, PublicKey, SystemProgram, Transaction, TransactionInstruction } from "@solana/web3.js";
 import { getProgramWithWallet } from "./program";
-import { getBountyPDA, solToLamports, uuidToBytes } from "./utils";
+import type { CameraPosition, Orientation, SensorStream } from "./types";
+import {
+  getBountyPDA,
+  getBuyerProfilePDA,
+  solToLamports,
+  toAnchorEnum,
+  uuidToBytes,
+} from "./utils";
+
+/**
+ * Hard minimums a submission's declared spec is checked against on-chain
+ */
+export interface BountyRequirements {
+  minDurationSecs: number;
+  maxDurationSecs?: number; // 0 or unset = no upper limit
+  minWidth: number; // pixels; compared edge-for-edge regardless of orientation
+  minHeight: number;
+  minFps: number;
+  allowedOrientations?: Orientation[]; // empty or unset = any orientation
+  requiredSensors?: SensorStream[];
+  cameraPosition?: CameraPosition; // default "any"
+  category: string; // at most 32 bytes
+  tags?: string[]; // at most 8, each at most 32 bytes
+}
 
 export interface CreateBountyParams {
   bountyId: string;
@@ -9,9 +32,7 @@ export interface CreateBountyParams {
   totalPool: number; // in SOL
   videosTarget: number;
   taskDescription: string;
-  minDurationSecs: number;
-  minResolution: string; // e.g., "720p", "1080p"
-  minFps: number;
+  requirements: BountyRequirements;
   expiresAt: Date;
 }
 
@@ -56,6 +77,8 @@ export async function createBountyOnChain(
 
   // Convert UUID string to bytes for on-chain storage
   const bountyIdBytes = Array.from(uuidToBytes(params.bountyId));
+  const requirements = toRequirementsArg(params.requirements);
+  const [buyerProfilePDA] = getBuyerProfilePDA(wallet.publicKey);
 
   try {
     // CRITICAL: Get FRESH blockhash for each transaction attempt
@@ -71,14 +94,14 @@ export async function createBountyOnChain(
         totalPoolLamports,
         params.videosTarget,
         params.taskDescription,
-        params.minDurationSecs,
-        params.minResolution,
-        params.minFps,
+        requirements,
         expiresAtUnix
       )
       .accountsPartial({
         bountyPool: bountyPDA,
+        buyerProfile: buyerProfilePDA,
         authority: wallet.publicKey,
+        payer: wallet.publicKey,
         systemProgram: SystemProgram.programId,
       })
       .instruction();
@@ -172,6 +195,25 @@ export async function createBountyOnChain(
   }
 }
 
+/**
+ * The program's `Requirements` argument; `version` is set by the program
+ */
+function toRequirementsArg(requirements: BountyRequirements) {
+  return {
+    version: 0,
+    minDurationSecs: requirements.minDurationSecs,
+    maxDurationSecs: requirements.maxDurationSecs ?? 0,
+    minWidth: requirements.minWidth,
+    minHeight: requirements.minHeight,
+    minFps: requirements.minFps,
+    allowedOrientations: (requirements.allowedOrientations ?? []).map(toAnchorEnum),
+    requiredSensors: (requirements.requiredSensors ?? []).map(toAnchorEnum),
+    cameraPosition: toAnchorEnum(requirements.cameraPosition ?? "any"),
+    category: requirements.category,
+    tags: requirements.tags ?? [],
+  };
+}
+
 /**
  * Pause an active bounty
  */
@@ -217,7 +259,7 @@ export async function resumeBountyOnChain(
 }
 
 /**
- * Complete a bounty
+ * Complete a bounty and return its unspent funds
  */
 export async function completeBountyOnChain(
   connection: Connection,
@@ -232,6 +274,7 @@ export async function completeBountyOnChain(
     .accountsPartial({
       bountyPool: bountyPDA,
       authority: wallet.publicKey,
+      systemProgram: SystemProgram.programId,
     })
     .rpc();
 
@@ -279,3 +322,22 @@ export async function fetchBountyData(
     return null;
   }
 }
+
+/**
+ * Fetch a bounty authority's buyer profile: bounties created, payouts, approval
+ * ratio, disputes lost and review latency. Null until the wallet creates a bounty.
+ */
+export async function fetchBuyerProfile(
+  connection: Connection,
+  authority: string
+) {
+  const program = getProgramWithWallet(connection, {} as any);
+  const [buyerProfilePDA] = getBuyerProfilePDA(new PublicKey(authority));
+
+  try {
+    return await program.account.buyerProfile.fetch(buyerProfilePDA);
+  } catch (error) {
+    console.error("Error fetching buyer profile:", error);
+    return null;
+  }
+}
@@ -1,19 +1,45 @@
 import { Connection, PublicKey, SystemProgram, Transaction, TransactionInstruction } from "@solana/web3.js";
 import { getProgramWithWallet } from "./program";
-import { getBountyPDA, getProfilePDA, getSubmissionPDA } from "./utils";
+import type { CameraPosition, Orientation, RejectionReason, SensorStream } from "./types";
+import {
+  getBountyContentPDA,
+  getBountyPDA,
+  getBuyerProfilePDA,
+  getCategoryReputationPDA,
+  getContentPDA,
+  getProfilePDA,
+  getSubmissionPDA,
+  toAnchorEnum,
+} from "./utils";
+
+/**
+ * The contributor's self-declared spec, checked against the bounty's requirements
+ */
+export interface VideoSpec {
+  durationSecs: number;
+  width: number;
+  height: number;
+  fps: number;
+  orientation: Orientation;
+  sensors?: SensorStream[];
+  cameraPosition?: CameraPosition; // default "any"
+}
 
 export interface SubmitVideoParams {
   submissionId: string;
   bountyId: string;
+  contentHash: Uint8Array; // 32-byte SHA-256 of the video, see `hashFile`
   ipfsHash: string;
   arweaveTx: string;
   metadataUri: string;
+  spec: VideoSpec;
 }
 
 export interface SubmitVideoResult {
   signature: string;
   submissionPDA: string;
   submissionId: string;
+  sequence: number;
 }
 
 /**
@@ -25,28 +51,33 @@ export async function submitVideoOnChain(
   params: SubmitVideoParams
 ): Promise<SubmitVideoResult> {
   const program = getProgramWithWallet(connection, wallet);
-  const [submissionPDA] = getSubmissionPDA(params.submissionId);
   const [bountyPDA] = getBountyPDA(params.bountyId);
-
-  // Check if this submission PDA already exists
-  console.log("Checking if submission PDA exists:", submissionPDA.toString());
-  try {
-    const existingAccount = await connection.getAccountInfo(submissionPDA);
-    if (existingAccount) {
-      console.error("Submission PDA already exists on-chain!");
-      throw new Error(
-        `Submission with ID "${params.submissionId}" already exists on-chain. Please try again.`
-      );
-    }
-    console.log("Submission PDA is available (account does not exist)");
-  } catch (error: any) {
-    if (error.message?.includes("already exists")) {
-      throw error;
-    }
-    // Other errors (like network errors) are okay - we'll proceed
-    console.log("PDA check inconclusive, proceeding with transaction");
+  if (params.contentHash.length !== 32) {
+    throw new Error("Content hash must be 32 bytes (SHA-256)");
   }
 
+  // The submission takes the pool's next sequence number. If another submission
+  // lands first, this transaction fails its seed check and can simply be retried.
+  const bounty = await program.account.bountyPool.fetch(bountyPDA);
+  const sequence: number = bounty.submissionCount;
+  const [submissionPDA] = getSubmissionPDA(bountyPDA, sequence);
+  const [contentRecordPDA] = getContentPDA(params.contentHash);
+  const [bountyContentPDA] = getBountyContentPDA(bountyPDA, params.contentHash);
+  const [contributorProfilePDA] = getProfilePDA(wallet.publicKey);
+  const [categoryReputationPDA] = getCategoryReputationPDA(
+    wallet.publicKey,
+    bounty.requirements.category
+  );
+  const spec = {
+    durationSecs: params.spec.durationSecs,
+    width: params.spec.width,
+    height: params.spec.height,
+    fps: params.spec.fps,
+    orientation: toAnchorEnum(params.spec.orientation),
+    sensors: (params.spec.sensors ?? []).map(toAnchorEnum),
+    cameraPosition: toAnchorEnum(params.spec.cameraPosition ?? "any"),
+  };
+
   // Convert submission ID to 16-byte array
   // Submission IDs are timestamp-based strings, so we need to pad them
   const encoder = new TextEncoder();
@@ -66,13 +97,19 @@ export async function submitVideoOnChain(
     const instruction = await program.methods
       .submitVideo(
         submissionIdArray,
+        Array.from(params.contentHash),
         params.ipfsHash,
         params.arweaveTx,
-        params.metadataUri
+        params.metadataUri,
+        spec
       )
       .accountsPartial({
         submission: submissionPDA,
+        contentRecord: contentRecordPDA,
+        bountyContent: bountyContentPDA,
         bountyPool: bountyPDA,
+        contributorProfile: contributorProfilePDA,
+        categoryReputation: categoryReputationPDA,
         contributor: wallet.publicKey,
         systemProgram: SystemProgram.programId,
       })
@@ -144,6 +181,7 @@ export async function submitVideoOnChain(
       signature,
       submissionPDA: submissionPDA.toString(),
       submissionId: params.submissionId,
+      sequence,
     };
   } catch (error: any) {
     console.error("Submit video transaction failed:", error);
@@ -162,18 +200,52 @@ export async function submitVideoOnChain(
         "Transaction was already processed. This submission may already exist. Please refresh the page and try again."
       );
     }
+    if (error.message?.includes("DuplicateContent")) {
+      throw new Error("This video has already been submitted.");
+    }
 
     throw error;
   }
 }
 
 export interface ApproveSubmissionParams {
-  submissionId: string;
+  submissionAddress: string; // The submission PDA, as returned by `submitVideoOnChain`
   bountyId: string;
   contributorWallet: string; // Public key as string
   qualityScore: number; // 0-100
 }
 
+/**
+ * Accounts shared by `approve_submission` and `reject_submission`
+ */
+async function reviewAccounts(
+  program: ReturnType<typeof getProgramWithWallet>,
+  wallet: any,
+  params: { submissionAddress: string; bountyId: string; contributorWallet: string }
+) {
+  const [bountyPDA] = getBountyPDA(params.bountyId);
+  const contributorPubkey = new PublicKey(params.contributorWallet);
+  const submissionPDA = new PublicKey(params.submissionAddress);
+  const bounty = await program.account.bountyPool.fetch(bountyPDA);
+  const submission = await program.account.videoSubmission.fetch(submissionPDA);
+
+  return {
+    submission: submissionPDA,
+    // Released on rejection, so the content can be submitted to other bounties
+    contentRecord: getContentPDA(Uint8Array.from(submission.contentHash))[0],
+    bountyPool: bountyPDA,
+    contributorProfile: getProfilePDA(contributorPubkey)[0],
+    categoryReputation: getCategoryReputationPDA(
+      contributorPubkey,
+      bounty.requirements.category
+    )[0],
+    buyerProfile: getBuyerProfilePDA(bounty.authority)[0],
+    contributor: contributorPubkey,
+    authority: wallet.publicKey,
+    systemProgram: SystemProgram.programId,
+  };
+}
+
 /**
  * Approve a submission and release payment to contributor
  */
@@ -183,10 +255,7 @@ export async function approveSubmissionOnChain(
   params: ApproveSubmissionParams
 ): Promise<string> {
   const program = getProgramWithWallet(connection, wallet);
-  const [submissionPDA] = getSubmissionPDA(params.submissionId);
-  const [bountyPDA] = getBountyPDA(params.bountyId);
-  const contributorPubkey = new PublicKey(params.contributorWallet);
-  const [contributorProfilePDA] = getProfilePDA(contributorPubkey);
+  const submissionPDA = new PublicKey(params.submissionAddress);
 
   // Check if submission is already approved
   try {
@@ -211,14 +280,7 @@ export async function approveSubmissionOnChain(
     // Build the transaction instruction
     const instruction = await program.methods
       .approveSubmission(params.qualityScore)
-      .accountsPartial({
-        submission: submissionPDA,
-        bountyPool: bountyPDA,
-        contributorProfile: contributorProfilePDA,
-        contributor: contributorPubkey,
-        authority: wallet.publicKey,
-        systemProgram: SystemProgram.programId,
-      })
+      .accountsPartial(await reviewAccounts(program, wallet, params))
       .instruction();
 
     // Build transaction with explicit blockhash
@@ -344,9 +406,11 @@ export async function approveSubmissionOnChain(
 }
 
 export interface RejectSubmissionParams {
-  submissionId: string;
+  submissionAddress: string; // The submission PDA, as returned by `submitVideoOnChain`
   bountyId: string;
   contributorWallet: string; // Public key as string
+  reason: RejectionReason; // "spam" and "fraud" forfeit the contributor's bond
+  feedbackUri?: string; // URI or hash of the reviewer's notes, at most 200 bytes
 }
 
 /**
@@ -358,10 +422,6 @@ export async function rejectSubmissionOnChain(
   params: RejectSubmissionParams
 ): Promise<string> {
   const program = getProgramWithWallet(connection, wallet);
-  const [submissionPDA] = getSubmissionPDA(params.submissionId);
-  const [bountyPDA] = getBountyPDA(params.bountyId);
-  const contributorPubkey = new PublicKey(params.contributorWallet);
-  const [contributorProfilePDA] = getProfilePDA(contributorPubkey);
 
   try {
     // CRITICAL: Get FRESH blockhash for each transaction attempt
@@ -370,15 +430,8 @@ export async function rejectSubmissionOnChain(
 
     // Build the transaction instruction
     const instruction = await program.methods
-      .rejectSubmission()
-      .accountsPartial({
-        submission: submissionPDA,
-        bountyPool: bountyPDA,
-        contributorProfile: contributorProfilePDA,
-        contributor: contributorPubkey,
-        authority: wallet.publicKey,
-        systemProgram: SystemProgram.programId,
-      })
+      .rejectSubmission(toAnchorEnum(params.reason), params.feedbackUri ?? "")
+      .accountsPartial(await reviewAccounts(program, wallet, params))
       .instruction();
 
     // Build transaction with explicit blockhash
@@ -508,14 +561,13 @@ export async function rejectSubmissionOnChain(
  */
 export async function fetchSubmissionData(
   connection: Connection,
-  submissionId: string
+  submissionAddress: string
 ) {
   const program = getProgramWithWallet(connection, {} as any);
-  const [submissionPDA] = getSubmissionPDA(submissionId);
 
   try {
     const submissionAccount = await program.account.videoSubmission.fetch(
-      submissionPDA
+      new PublicKey(submissionAddress)
     );
     return submissionAccount;
   } catch (error) {
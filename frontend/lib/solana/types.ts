@@ -18,3 +18,20 @@ export type UnimakeBackend = {
   errors: Array<any>;
   types: Array<any>;
 };
+
+/**
+ * Unit enum variants as the program names them, in Anchor's camelCase.
+ * Convert with `toAnchorEnum` from `./utils` before passing them to the program.
+ */
+export type Orientation = "landscape" | "portrait" | "square";
+export type SensorStream = "imu" | "depth" | "audio" | "gps";
+export type CameraPosition = "any" | "egocentric" | "thirdPerson";
+export type RejectionReason =
+  | "tooShort"
+  | "lowResolution"
+  | "wrongTask"
+  | "duplicate"
+  | "inappropriate"
+  | "spam"
+  | "fraud"
+  | "other";
@@ -37,22 +37,39 @@ export function getBountyPDA(bountyId: string): [PublicKey, number] {
 }
 
 /**
- * Derive the submission PDA from submission ID
- * For submissions, we use a timestamp-based ID that needs to be padded to 16 bytes
+ * Derive a submission PDA from its bounty and sequence number
+ * Submissions are numbered per bounty from the pool's `submissionCount`, so a
+ * bounty's submissions can be enumerated without `getProgramAccounts`
  */
-export function getSubmissionPDA(submissionId: string): [PublicKey, number] {
-  // For submission IDs (e.g., "1234567890-abc123"), we'll pad to 16 bytes
-  // This ensures consistent length for PDA derivation
-  const encoder = new TextEncoder();
-  const idBytes = encoder.encode(submissionId);
+export function getSubmissionPDA(
+  bountyPDA: PublicKey,
+  sequence: number
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("submission"), bountyPDA.toBuffer(), u32ToLeBytes(sequence)],
+    PROGRAM_ID
+  );
+}
 
-  // Pad or truncate to 16 bytes
-  const paddedBytes = new Uint8Array(16);
-  const copyLength = Math.min(idBytes.length, 16);
-  paddedBytes.set(idBytes.slice(0, copyLength));
+/**
+ * Derive the program-wide record of a video's content hash
+ */
+export function getContentPDA(contentHash: Uint8Array): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("content"), contentHash],
+    PROGRAM_ID
+  );
+}
 
+/**
+ * Derive a bounty's record of a video's content hash
+ */
+export function getBountyContentPDA(
+  bountyPDA: PublicKey,
+  contentHash: Uint8Array
+): [PublicKey, number] {
   return PublicKey.findProgramAddressSync(
-    [Buffer.from("submission"), paddedBytes],
+    [Buffer.from("content"), bountyPDA.toBuffer(), contentHash],
     PROGRAM_ID
   );
 }
@@ -67,6 +84,29 @@ export function getProfilePDA(walletPubkey: PublicKey): [PublicKey, number] {
   );
 }
 
+/**
+ * Derive a contributor's reputation PDA for one bounty category
+ */
+export function getCategoryReputationPDA(
+  walletPubkey: PublicKey,
+  category: string
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("category_rep"), walletPubkey.toBuffer(), Buffer.from(category)],
+    PROGRAM_ID
+  );
+}
+
+/**
+ * Derive the buyer profile PDA of a bounty authority
+ */
+export function getBuyerProfilePDA(authority: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("buyer"), authority.toBuffer()],
+    PROGRAM_ID
+  );
+}
+
 /**
  * Derive the dataset NFT PDA from dataset ID
  * Dataset ID is a UUID string that gets converted to 16 bytes
@@ -79,6 +119,30 @@ export function getDatasetPDA(datasetId: string): [PublicKey, number] {
   );
 }
 
+/**
+ * Encode a u32 as the 4 little-endian bytes the program uses in seeds
+ */
+export function u32ToLeBytes(value: number): Buffer {
+  const bytes = Buffer.alloc(4);
+  bytes.writeUInt32LE(value);
+  return bytes;
+}
+
+/**
+ * Anchor's JS encoding of a unit enum variant, e.g. "landscape" -> { landscape: {} }
+ */
+export function toAnchorEnum<T extends string>(variant: T): { [K in T]: {} } {
+  return { [variant]: {} } as { [K in T]: {} };
+}
+
+/**
+ * SHA-256 of a file's bytes, the content hash `submit_video` deduplicates on
+ */
+export async function hashFile(file: Blob): Promise<Uint8Array> {
+  const digest = await crypto.subtle.digest("SHA-256", await file.arrayBuffer());
+  return new Uint8Array(digest);
+}
+
 /**
  * Convert SOL amount to lamports
  */
@@ -0,0 +1,335 @@
+//! What each command does: read-only commands produce JSON, the rest produce the
+//! instructions to sign and send.
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::instruction::Instruction;
+use clap::{Args, Subcommand};
+use serde_json::{json, Value};
+use unimake_client::accounts::{self as fetch, ProgramAccount};
+use unimake_client::program::{
+    instruction as args, BadgeType, CameraPosition, LicenseType, Orientation, RejectionReason,
+    Requirements, SensorStream, VideoSubmission,
+};
+use unimake_client::{instructions, pda, uuid_to_bytes, AccountSource, ClientError};
+
+use crate::json::{parse_named, ToJson};
+use crate::Error;
+
+const DAY: i64 = 24 * 60 * 60;
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Create and manage bounties
+    #[command(subcommand)]
+    Bounty(BountyCommand),
+    /// List and review submissions
+    #[command(subcommand)]
+    Submission(SubmissionCommand),
+    /// Award badges to contributors
+    #[command(subcommand)]
+    Badge(BadgeCommand),
+    /// Create and buy datasets
+    #[command(subcommand)]
+    Dataset(DatasetCommand),
+    /// Print any program account as JSON
+    Account {
+        #[arg(value_parser = parse_pubkey)]
+        address: Pubkey,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum BountyCommand {
+    /// Create and fund a bounty, with the keypair as its authority
+    Create(CreateBounty),
+    Pause(BountyId),
+    Resume(BountyId),
+    Complete(BountyId),
+    /// Cancel a bounty, refunding its unreserved pool
+    Cancel(BountyId),
+}
+
+#[derive(Args)]
+pub struct BountyId {
+    /// The bounty's UUID
+    #[arg(long, value_parser = parse_uuid)]
+    pub id: [u8; 16],
+}
+
+#[derive(Args)]
+pub struct CreateBounty {
+    /// The bounty's UUID, as in the frontend's `bounties` table
+    #[arg(long, value_parser = parse_uuid)]
+    pub id: [u8; 16],
+    /// Lamports paid for each approved video
+    #[arg(long)]
+    pub reward: u64,
+    /// Number of videos wanted
+    #[arg(long)]
+    pub videos: u32,
+    /// Lamports to deposit [default: reward × videos]
+    #[arg(long)]
+    pub pool: Option<u64>,
+    #[arg(long)]
+    pub description: String,
+    #[arg(long)]
+    pub category: String,
+    #[arg(long, default_value_t = 30)]
+    pub expires_in_days: i64,
+    #[arg(long, default_value_t = 0)]
+    pub min_duration: u32,
+    /// 0 = no upper limit
+    #[arg(long, default_value_t = 0)]
+    pub max_duration: u32,
+    #[arg(long, default_value_t = 0)]
+    pub min_width: u32,
+    #[arg(long, default_value_t = 0)]
+    pub min_height: u32,
+    #[arg(long, default_value_t = 0)]
+    pub min_fps: u32,
+    /// Allowed orientation, repeatable [default: any]
+    #[arg(long = "orientation", value_parser = parse_named::<Orientation>)]
+    pub orientations: Vec<Orientation>,
+    /// Required sensor stream, repeatable
+    #[arg(long = "sensor", value_parser = parse_named::<SensorStream>)]
+    pub sensors: Vec<SensorStream>,
+    #[arg(long, value_parser = parse_named::<CameraPosition>, default_value = "Any")]
+    pub camera: CameraPosition,
+    /// Repeatable
+    #[arg(long = "tag")]
+    pub tags: Vec<String>,
+}
+
+#[derive(Subcommand)]
+pub enum SubmissionCommand {
+    /// List a bounty's submissions awaiting review
+    Pending(BountyId),
+    /// Approve a submission, paying its escrow to the contributor
+    Approve {
+        #[command(flatten)]
+        submission: SubmissionRef,
+        /// Quality score, 0-100
+        #[arg(long, value_parser = clap::value_parser!(u8).range(0..=100))]
+        score: u8,
+    },
+    /// Reject a submission, returning its escrow to the pool
+    Reject {
+        #[command(flatten)]
+        submission: SubmissionRef,
+        #[arg(long, value_parser = parse_named::<RejectionReason>)]
+        reason: RejectionReason,
+        /// URI or hash of the reviewer's notes
+        #[arg(long, default_value = "")]
+        feedback: String,
+    },
+}
+
+#[derive(Args)]
+pub struct SubmissionRef {
+    /// The bounty's UUID
+    #[arg(long, value_parser = parse_uuid)]
+    pub bounty: [u8; 16],
+    /// The submission's sequence number within the bounty
+    #[arg(long)]
+    pub sequence: u32,
+}
+
+#[derive(Subcommand)]
+pub enum BadgeCommand {
+    /// Award a subjective badge, with the keypair as the config admin
+    Award {
+        #[arg(long, value_parser = parse_pubkey)]
+        wallet: Pubkey,
+        #[arg(long, value_parser = parse_named::<BadgeType>)]
+        badge: BadgeType,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum DatasetCommand {
+    /// List a dataset for sale, with the keypair as its creator
+    Create {
+        /// The dataset's UUID
+        #[arg(long, value_parser = parse_uuid)]
+        id: [u8; 16],
+        #[arg(long, value_parser = parse_named::<LicenseType>)]
+        license: LicenseType,
+        /// Price in lamports
+        #[arg(long)]
+        price: u64,
+        /// Percent, 0-100
+        #[arg(long, value_parser = clap::value_parser!(u8).range(0..=100))]
+        royalty: u8,
+        /// UUID of the completed bounty the dataset was built from; its sponsors
+        /// share in sales
+        #[arg(long, value_parser = parse_uuid)]
+        bounty: [u8; 16],
+    },
+    /// Buy a license to a dataset with the keypair
+    Purchase {
+        #[arg(long, value_parser = parse_uuid)]
+        id: [u8; 16],
+    },
+}
+
+fn parse_uuid(value: &str) -> Result<[u8; 16], String> {
+    uuid_to_bytes(value).map_err(|err| err.to_string())
+}
+
+fn parse_pubkey(value: &str) -> Result<Pubkey, String> {
+    value
+        .parse()
+        .map_err(|_| format!("{value} is not a base58 public key"))
+}
+
+/// A command's effect
+pub enum Action {
+    /// Print this
+    Show(Value),
+    /// Sign and send, or simulate, these
+    Send(Vec<Instruction>),
+}
+
+/// Resolve `command` for `signer`, fetching whatever accounts it needs from `source`
+pub fn resolve(
+    command: Command,
+    signer: &Pubkey,
+    source: &impl AccountSource,
+) -> Result<Action, Error> {
+    let ix = |ix: Instruction| Ok(Action::Send(vec![ix]));
+    match command {
+        Command::Bounty(BountyCommand::Create(create)) => {
+            ix(create_bounty(create, signer, unix_now()))
+        }
+        Command::Bounty(BountyCommand::Pause(bounty)) => {
+            ix(instructions::pause_bounty(&bounty.id, signer))
+        }
+        Command::Bounty(BountyCommand::Resume(bounty)) => {
+            ix(instructions::resume_bounty(&bounty.id, signer))
+        }
+        Command::Bounty(BountyCommand::Complete(bounty)) => {
+            ix(instructions::complete_bounty(&bounty.id, signer))
+        }
+        Command::Bounty(BountyCommand::Cancel(bounty)) => {
+            ix(instructions::cancel_bounty(&bounty.id, signer))
+        }
+
+        Command::Submission(SubmissionCommand::Pending(bounty)) => {
+            let pool = fetch::fetch_bounty(source, &bounty.id)?;
+            let pending = fetch::fetch_pending_submissions(source, &pool)?
+                .into_iter()
+                .map(|(address, submission)| {
+                    json!({ "address": address.to_string(), "submission": submission.to_json() })
+                })
+                .collect();
+            Ok(Action::Show(Value::Array(pending)))
+        }
+        Command::Submission(SubmissionCommand::Approve { submission, score }) => {
+            let (pool, submission) = fetch_submission(source, &submission)?;
+            ix(instructions::approve_submission(&pool, &submission, score))
+        }
+        Command::Submission(SubmissionCommand::Reject {
+            submission,
+            reason,
+            feedback,
+        }) => {
+            let (pool, submission) = fetch_submission(source, &submission)?;
+            ix(instructions::reject_submission(
+                &pool,
+                &submission,
+                reason,
+                feedback,
+            ))
+        }
+
+        Command::Badge(BadgeCommand::Award { wallet, badge }) => {
+            ix(instructions::award_badge(&wallet, signer, badge))
+        }
+
+        Command::Dataset(DatasetCommand::Create {
+            id,
+            license,
+            price,
+            royalty,
+            bounty,
+        }) => ix(instructions::create_dataset(
+            signer,
+            &bounty,
+            args::CreateDataset {
+                dataset_id: id,
+                license_type: license,
+                price,
+                royalty_percentage: royalty,
+            },
+        )),
+        Command::Dataset(DatasetCommand::Purchase { id }) => {
+            let dataset = fetch::fetch_dataset(source, &id)?;
+            ix(instructions::purchase_dataset(&dataset, signer))
+        }
+
+        Command::Account { address } => {
+            let data = source
+                .account_data(&address)?
+                .ok_or(ClientError::AccountNotFound(address))?;
+            let mut value = ProgramAccount::decode(&address, &data)?.to_json();
+            value["address"] = json!(address.to_string());
+            Ok(Action::Show(value))
+        }
+    }
+}
+
+/// Whether `command` needs the keypair
+pub fn signs(command: &Command) -> bool {
+    !matches!(
+        command,
+        Command::Account { .. } | Command::Submission(SubmissionCommand::Pending(_))
+    )
+}
+
+fn create_bounty(create: CreateBounty, authority: &Pubkey, now: i64) -> Instruction {
+    instructions::create_bounty(
+        authority,
+        authority,
+        args::CreateBounty {
+            bounty_id: create.id,
+            reward_per_video: create.reward,
+            total_pool: create
+                .pool
+                .unwrap_or(create.reward.saturating_mul(create.videos as u64)),
+            videos_target: create.videos,
+            task_description: create.description,
+            requirements: Requirements {
+                version: 0, // Set by the program
+                min_duration_secs: create.min_duration,
+                max_duration_secs: create.max_duration,
+                min_width: create.min_width,
+                min_height: create.min_height,
+                min_fps: create.min_fps,
+                allowed_orientations: create.orientations,
+                required_sensors: create.sensors,
+                camera_position: create.camera,
+                category: create.category,
+                tags: create.tags,
+            },
+            expires_at: now + create.expires_in_days * DAY,
+        },
+    )
+}
+
+fn fetch_submission(
+    source: &impl AccountSource,
+    submission: &SubmissionRef,
+) -> Result<(unimake_client::program::BountyPool, VideoSubmission), Error> {
+    let pool = fetch::fetch_bounty(source, &submission.bounty)?;
+    let address = pda::submission(&pda::bounty(&submission.bounty).0, submission.sequence).0;
+    let stored = fetch::fetch(source, &address)?;
+    Ok((pool, stored))
+}
+
+fn unix_now() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs() as i64)
+}
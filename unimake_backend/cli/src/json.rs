@@ -0,0 +1,393 @@
+//! JSON views of the program's accounts and events.
+//!
+//! The program's types don't derive serde, so each is listed here field by field.
+//! Ids are printed as UUIDs, hashes and bitmaps as hex, keys in base58 and enums by
+//! variant name, the same names the CLI accepts as arguments.
+
+use anchor_lang::prelude::Pubkey;
+use serde_json::{json, Map, Value};
+use unimake_client::accounts::ProgramAccount;
+use unimake_client::bytes_to_uuid;
+use unimake_client::events::ProgramEvent;
+use unimake_client::program::*;
+
+pub trait ToJson {
+    fn to_json(&self) -> Value;
+}
+
+macro_rules! plain_json {
+    ($($ty:ty),* $(,)?) => {
+        $(impl ToJson for $ty {
+            fn to_json(&self) -> Value {
+                json!(self)
+            }
+        })*
+    };
+}
+
+plain_json!(bool, u8, u16, u32, u64, i64, String);
+
+impl ToJson for Pubkey {
+    fn to_json(&self) -> Value {
+        Value::String(self.to_string())
+    }
+}
+
+impl ToJson for [u8; 16] {
+    fn to_json(&self) -> Value {
+        Value::String(bytes_to_uuid(self))
+    }
+}
+
+impl ToJson for [u8; 32] {
+    fn to_json(&self) -> Value {
+        Value::String(self.iter().map(|byte| format!("{byte:02x}")).collect())
+    }
+}
+
+impl<T: ToJson> ToJson for Option<T> {
+    fn to_json(&self) -> Value {
+        self.as_ref().map_or(Value::Null, ToJson::to_json)
+    }
+}
+
+impl<T: ToJson> ToJson for Vec<T> {
+    fn to_json(&self) -> Value {
+        Value::Array(self.iter().map(ToJson::to_json).collect())
+    }
+}
+
+macro_rules! struct_json {
+    ($($ty:ident { $($field:ident),* $(,)? })*) => {
+        $(impl ToJson for $ty {
+            fn to_json(&self) -> Value {
+                let mut object = Map::new();
+                $(object.insert(stringify!($field).to_string(), self.$field.to_json());)*
+                Value::Object(object)
+            }
+        })*
+    };
+}
+
+/// A unit-variant program enum, printed and parsed by variant name
+pub trait Named: Sized + 'static {
+    const VARIANTS: &'static [(&'static str, Self)];
+
+    fn name(&self) -> &'static str;
+}
+
+macro_rules! named_enums {
+    ($($ty:ident { $($variant:ident),* $(,)? })*) => {
+        $(
+            impl Named for $ty {
+                const VARIANTS: &'static [(&'static str, Self)] =
+                    &[$((stringify!($variant), $ty::$variant)),*];
+
+                fn name(&self) -> &'static str {
+                    match self {
+                        $($ty::$variant => stringify!($variant),)*
+                    }
+                }
+            }
+
+            impl ToJson for $ty {
+                fn to_json(&self) -> Value {
+                    Value::String(self.name().to_string())
+                }
+            }
+        )*
+    };
+}
+
+/// Parse a variant name, ignoring case, `-` and `_`: `early-adopter` is `EarlyAdopter`
+pub fn parse_named<T: Named + Clone>(value: &str) -> Result<T, String> {
+    let normalize = |name: &str| name.replace(['-', '_'], "").to_lowercase();
+    let wanted = normalize(value);
+    T::VARIANTS
+        .iter()
+        .find(|(name, _)| normalize(name) == wanted)
+        .map(|(_, variant)| variant.clone())
+        .ok_or_else(|| {
+            let names: Vec<_> = T::VARIANTS.iter().map(|(name, _)| *name).collect();
+            format!("expected one of {}", names.join(", "))
+        })
+}
+
+named_enums! {
+    Orientation { Landscape, Portrait, Square }
+    SensorStream { Imu, Depth, Audio, Gps }
+    CameraPosition { Any, Egocentric, ThirdPerson }
+    BountyStatus { Active, Paused, Completed, Cancelled, Expired }
+    SubmissionStatus { Pending, UnderReview, Approved, Rejected, Disputed }
+    RejectionReason {
+        TooShort, LowResolution, WrongTask, Duplicate, Inappropriate, Spam, Fraud, Other
+    }
+    SuspensionReason { StolenContent, SyntheticContent, Spam, TermsViolation, Other }
+    BadgeType {
+        FirstVideo, HundredVideos, ThousandVideos, HighQuality, EarlyAdopter, CategoryExpert
+    }
+    LicenseType { SingleUse, Unlimited, Exclusive, CommercialResale }
+}
+
+impl ToJson for AutoAwardRule {
+    fn to_json(&self) -> Value {
+        match self {
+            AutoAwardRule::Manual => json!({ "kind": "Manual" }),
+            AutoAwardRule::AcceptedSubmissions { min } => {
+                json!({ "kind": "AcceptedSubmissions", "min": min })
+            }
+            AutoAwardRule::AverageQuality {
+                min_score,
+                min_videos,
+            } => json!({
+                "kind": "AverageQuality",
+                "min_score": min_score,
+                "min_videos": min_videos,
+            }),
+            AutoAwardRule::Reputation { min_score } => {
+                json!({ "kind": "Reputation", "min_score": min_score })
+            }
+            AutoAwardRule::TotalEarnings { min_lamports } => {
+                json!({ "kind": "TotalEarnings", "min_lamports": min_lamports })
+            }
+        }
+    }
+}
+
+// Nested types
+struct_json! {
+    Requirements {
+        version, min_duration_secs, max_duration_secs, min_width, min_height, min_fps,
+        allowed_orientations, required_sensors, camera_position, category, tags,
+    }
+    VideoSpec { duration_secs, width, height, fps, orientation, sensors, camera_position }
+    AttestationPolicy { auto_approve_min_score, auto_reject_max_score, min_validator_stake }
+    RewardTier { min_score, payout_bps }
+    Badge { badge_type, earned_at, token_minted }
+}
+
+// Accounts
+struct_json! {
+    BountyPool {
+        authority, bounty_id, task_description, requirements, reward_per_video, total_pool,
+        remaining_pool, videos_target, videos_collected, pending_submissions, submission_count,
+        status, created_at, expires_at, attestation_policy, required_approvals, reviewers,
+        allow_content_reuse, min_category_reputation, submission_bond, reward_tiers, bonus_pool,
+        sponsored_amount, refund_pool, authority_refunded, sponsor_proceeds, pending_authority,
+    }
+    BountySponsor {
+        bounty, sponsor, amount, refunded, proceeds_claimed, first_contributed_at, bump,
+    }
+    VideoSubmission {
+        submission_id, sequence, contributor, bounty_id, content_hash, ipfs_hash, arweave_tx,
+        metadata_uri, spec, submission_timestamp, status, escrow_amount, quality_score,
+        approvals, rejections, review_scores, bond_amount, rejection_reason, feedback_uri, bump,
+    }
+    ContentRecord { content_hash, contributor, use_count, exclusive, bump }
+    ReviewVote { submission, reviewer, approve, quality_score, voted_at, bump }
+    ContributorProfile {
+        wallet, total_submissions, accepted_submissions, rejected_submissions,
+        rejection_weight, pending_submissions, average_quality_score, total_earnings,
+        reputation_score, reputation_confidence, reputation_version, badges, join_date,
+        last_active, defined_badges, suspended_until, suspension_reason, bump,
+    }
+    CategoryReputation {
+        wallet, category, accepted_submissions, rejected_submissions, rejection_weight,
+        average_quality_score, reputation_score, reputation_confidence, reputation_version,
+        last_active, bump,
+    }
+    BuyerProfile {
+        wallet, bounties_created, total_paid_out, approved_submissions, rejected_submissions,
+        disputes_lost, average_review_latency_secs, created_at, bump,
+    }
+    ProgramConfig { admin, bump }
+    ValidatorOracle { validator, stake, active, attestations_posted, registered_at, bump }
+    QualityAttestation {
+        submission, validator, score, checks_passed, model_version, attested_at, bump,
+    }
+    BadgeDefinition { badge_id, name, category, criteria, rule, created_at, bump }
+    DatasetNFT {
+        dataset_id, license_type, creator, price, royalty_percentage, created_at, total_sales,
+        pending_creator, bounty, sponsor_bps, bump,
+    }
+    DatasetLicense {
+        dataset, holder, license_type, purchases, total_paid, first_purchased_at,
+        last_purchased_at, bump,
+    }
+}
+
+// Events
+struct_json! {
+    BountyCreated { bounty_id, authority, total_pool, videos_target }
+    BountyStatusChanged { bounty_id, new_status }
+    SponsorContributed { bounty_id, sponsor, amount, total_contributed, share_bps }
+    SponsorRefunded { bounty_id, sponsor, refund }
+    SponsorProceedsClaimed { bounty_id, sponsor, proceeds }
+    AuthorityRefunded { bounty_id, authority, refund }
+    VideoSubmitted {
+        submission_id, bounty_id, sequence, contributor, content_hash, escrow_amount,
+        bond_amount,
+    }
+    SubmissionApproved { submission_id, contributor, reward, quality_score }
+    RewardTierApplied {
+        submission_id, quality_score, payout_bps, escrow_amount, reward, bonus,
+        refunded_to_pool,
+    }
+    BatchItemSkipped { index, submission, error_code }
+    BatchReviewed { bounty_id, approved, rejected, skipped }
+    SubmissionRejected { submission_id, contributor, reason, bond, bond_forfeited, feedback_uri }
+    AttestationPolicyUpdated { bounty_id, policy }
+    ContentReuseUpdated { bounty_id, allow }
+    ReputationGateUpdated { bounty_id, min_category_reputation }
+    BountyAuthorityTransferProposed { bounty_id, authority, proposed }
+    BountyAuthorityTransferCancelled { bounty_id, proposed }
+    BountyAuthorityTransferred { bounty_id, previous, authority }
+    SubmissionBondUpdated { bounty_id, bond }
+    RewardTiersUpdated { bounty_id, tiers }
+    BonusPoolFunded { bounty_id, amount, bonus_pool }
+    ReviewPanelUpdated { bounty_id, reviewers, required_approvals }
+    ReviewVoteCast { submission_id, reviewer, approve, quality_score, approvals, rejections }
+    SubmissionDisputed { submission_id, approvals, rejections }
+    DisputeResolved { submission_id, approved }
+    ConfigInitialized { admin }
+    ContributorSuspended { wallet, reason, suspended_until }
+    SuspensionLifted { wallet }
+    ValidatorRegistered { validator, stake }
+    ValidatorStatusChanged { validator, active }
+    ValidatorDeregistered { validator, stake }
+    QualityAttested { submission_id, validator, score, checks_passed, model_version }
+    ProfileCreated { wallet, reputation_score }
+    ReputationRefreshed { wallet, reputation_score, reputation_confidence }
+    BadgeAwarded { wallet, badge_type }
+    BadgeDefinitionCreated { badge_id, name, category }
+    DefinedBadgeAwarded { wallet, badge_id }
+    BadgeMintInitialized { badge_type, mint }
+    BadgeTokenMinted { wallet, badge_type, mint }
+    DatasetCreated { dataset_id, creator, price }
+    DatasetPurchased { dataset_id, buyer, price, sponsor_share }
+    DatasetCreatorTransferProposed { dataset_id, creator, proposed }
+    DatasetCreatorTransferCancelled { dataset_id, proposed }
+    DatasetCreatorTransferred { dataset_id, previous, creator }
+}
+
+macro_rules! tagged_json {
+    ($ty:ident: $($variant:ident),* $(,)?) => {
+        impl ToJson for $ty {
+            fn to_json(&self) -> Value {
+                let (name, data) = match self {
+                    $($ty::$variant(inner) => (stringify!($variant), inner.to_json()),)*
+                };
+                json!({ "type": name, "data": data })
+            }
+        }
+    };
+}
+
+tagged_json!(
+    ProgramAccount: BountyPool,
+    BountySponsor,
+    VideoSubmission,
+    ContentRecord,
+    ReviewVote,
+    ContributorProfile,
+    CategoryReputation,
+    BuyerProfile,
+    ProgramConfig,
+    ValidatorOracle,
+    QualityAttestation,
+    BadgeDefinition,
+    DatasetNFT,
+    DatasetLicense,
+);
+
+tagged_json!(
+    ProgramEvent: BountyCreated,
+    BountyStatusChanged,
+    SponsorContributed,
+    SponsorRefunded,
+    SponsorProceedsClaimed,
+    AuthorityRefunded,
+    VideoSubmitted,
+    SubmissionApproved,
+    RewardTierApplied,
+    BatchItemSkipped,
+    BatchReviewed,
+    SubmissionRejected,
+    AttestationPolicyUpdated,
+    ContentReuseUpdated,
+    ReputationGateUpdated,
+    BountyAuthorityTransferProposed,
+    BountyAuthorityTransferCancelled,
+    BountyAuthorityTransferred,
+    SubmissionBondUpdated,
+    RewardTiersUpdated,
+    BonusPoolFunded,
+    ReviewPanelUpdated,
+    ReviewVoteCast,
+    SubmissionDisputed,
+    DisputeResolved,
+    ConfigInitialized,
+    ContributorSuspended,
+    SuspensionLifted,
+    ValidatorRegistered,
+    ValidatorStatusChanged,
+    ValidatorDeregistered,
+    QualityAttested,
+    ProfileCreated,
+    ReputationRefreshed,
+    BadgeAwarded,
+    BadgeDefinitionCreated,
+    DefinedBadgeAwarded,
+    BadgeMintInitialized,
+    BadgeTokenMinted,
+    DatasetCreated,
+    DatasetPurchased,
+    DatasetCreatorTransferProposed,
+    DatasetCreatorTransferCancelled,
+    DatasetCreatorTransferred,
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn variant_names_parse_loosely_and_print_exactly() {
+        let badge: BadgeType = parse_named("early-adopter").unwrap();
+        assert!(badge == BadgeType::EarlyAdopter);
+        assert_eq!(badge.to_json(), json!("EarlyAdopter"));
+        assert!(parse_named::<LicenseType>("commercial_resale").is_ok());
+
+        let err = parse_named::<RejectionReason>("bad").err().unwrap();
+        assert!(err.starts_with("expected one of TooShort, LowResolution"));
+    }
+
+    #[test]
+    fn accounts_print_readable_fields() {
+        let owner = Pubkey::new_unique();
+        let dataset = DatasetNFT {
+            dataset_id: [0x55; 16],
+            license_type: LicenseType::Unlimited,
+            creator: owner,
+            price: 1_000,
+            royalty_percentage: 10,
+            created_at: 1_700_000_000,
+            total_sales: 2,
+            pending_creator: None,
+            bounty: Pubkey::new_unique(),
+            sponsor_bps: 0,
+            bump: 254,
+        };
+
+        let value = ProgramAccount::DatasetNFT(dataset).to_json();
+        assert_eq!(value["type"], "DatasetNFT");
+        assert_eq!(
+            value["data"]["dataset_id"],
+            "55555555-5555-5555-5555-555555555555"
+        );
+        assert_eq!(value["data"]["creator"], owner.to_string());
+        assert_eq!(value["data"]["license_type"], "Unlimited");
+        assert_eq!(value["data"]["pending_creator"], Value::Null);
+        assert_eq!(value["data"]["price"], 1_000);
+    }
+}
@@ -0,0 +1,377 @@
+//! `unimake`: operate the TerraTrain bounty program from the command line.
+//!
+//! Transactions are signed by a local keypair, which also acts as the bounty authority,
+//! dataset creator or buyer. `--dry-run` simulates instead of sending and prints the
+//! logs and decoded events. Output is JSON on stdout.
+
+mod commands;
+mod json;
+mod rpc;
+
+use std::fmt;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use anchor_lang::solana_program::instruction::Instruction;
+use clap::Parser;
+use serde_json::{json, Value};
+use solana_keypair::{read_keypair_file, Keypair};
+use solana_signer::Signer;
+use solana_transaction::Transaction;
+use unimake_client::events::parse_logs;
+use unimake_client::ClientError;
+
+use crate::commands::{Action, Command};
+use crate::json::ToJson;
+use crate::rpc::RpcClient;
+
+#[derive(Parser)]
+#[command(
+    name = "unimake",
+    version,
+    about = "Operate the TerraTrain bounty program"
+)]
+struct Cli {
+    /// JSON-RPC URL of the validator
+    #[arg(
+        long,
+        short,
+        global = true,
+        env = "UNIMAKE_RPC_URL",
+        default_value = "http://127.0.0.1:8899"
+    )]
+    url: String,
+
+    /// Keypair that signs and pays [default: ~/.config/solana/id.json]
+    #[arg(long, short, global = true, env = "UNIMAKE_KEYPAIR")]
+    keypair: Option<PathBuf>,
+
+    /// Simulate the transaction and print its logs and events instead of sending it
+    #[arg(long, global = true)]
+    dry_run: bool,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug)]
+pub enum Error {
+    /// Bad arguments or local configuration
+    Usage(String),
+    /// The validator couldn't be reached or returned an error
+    Rpc(String),
+    /// Fetching or decoding an account failed
+    Client(ClientError),
+    /// A `--dry-run` simulation failed; carries the full report
+    Simulation(Value),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Usage(message) | Error::Rpc(message) => f.write_str(message),
+            Error::Client(err) => err.fmt(f),
+            Error::Simulation(report) => write!(f, "simulation failed: {}", report["err"]),
+        }
+    }
+}
+
+impl From<ClientError> for Error {
+    fn from(err: ClientError) -> Self {
+        Error::Client(err)
+    }
+}
+
+fn main() -> ExitCode {
+    match run(Cli::parse()) {
+        Ok(output) => {
+            print(&output);
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            if let Error::Simulation(report) = &err {
+                print(report);
+            }
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn print(value: &Value) {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(value).unwrap_or_default()
+    );
+}
+
+fn run(cli: Cli) -> Result<Value, Error> {
+    let rpc = RpcClient::new(&cli.url)?;
+    let keypair = if commands::signs(&cli.command) {
+        Some(load_keypair(cli.keypair)?)
+    } else {
+        None
+    };
+    let signer = keypair.as_ref().map(Keypair::pubkey).unwrap_or_default();
+
+    match commands::resolve(cli.command, &signer, &rpc)? {
+        Action::Show(value) => Ok(value),
+        Action::Send(instructions) => {
+            let keypair = keypair.expect("signing commands load the keypair");
+            send(&rpc, &keypair, &instructions, cli.dry_run)
+        }
+    }
+}
+
+fn load_keypair(path: Option<PathBuf>) -> Result<Keypair, Error> {
+    let path = match path {
+        Some(path) => path,
+        None => std::env::var_os("HOME")
+            .map(|home| PathBuf::from(home).join(".config/solana/id.json"))
+            .ok_or_else(|| Error::Usage("no --keypair given and HOME is unset".to_string()))?,
+    };
+    read_keypair_file(&path)
+        .map_err(|err| Error::Usage(format!("could not read keypair {}: {err}", path.display())))
+}
+
+fn send(
+    rpc: &RpcClient,
+    keypair: &Keypair,
+    instructions: &[Instruction],
+    dry_run: bool,
+) -> Result<Value, Error> {
+    let transaction = Transaction::new_signed_with_payer(
+        instructions,
+        Some(&keypair.pubkey()),
+        &[keypair],
+        rpc.latest_blockhash()?,
+    );
+
+    if !dry_run {
+        let signature = rpc.send_and_confirm(&transaction)?;
+        return Ok(json!({ "signature": signature }));
+    }
+
+    let simulation = rpc.simulate(&transaction)?;
+    let events: Vec<Value> = parse_logs(&simulation.logs)
+        .iter()
+        .map(ToJson::to_json)
+        .collect();
+    let report = json!({
+        "dry_run": true,
+        "err": simulation.err,
+        "units_consumed": simulation.units_consumed,
+        "events": events,
+        "logs": simulation.logs,
+    });
+    if simulation.err.is_null() {
+        Ok(report)
+    } else {
+        Err(Error::Simulation(report))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use anchor_lang::prelude::Pubkey;
+    use anchor_lang::{AccountSerialize, InstructionData};
+    use clap::CommandFactory;
+    use unimake_client::program::{instruction, DatasetNFT, LicenseType};
+    use unimake_client::{pda, AccountSource};
+
+    use super::*;
+
+    const ID: &str = "550e8400-e29b-41d4-a716-446655440000";
+
+    #[derive(Default)]
+    struct Accounts(HashMap<Pubkey, Vec<u8>>);
+
+    impl AccountSource for Accounts {
+        fn account_data(&self, address: &Pubkey) -> unimake_client::Result<Option<Vec<u8>>> {
+            Ok(self.0.get(address).cloned())
+        }
+    }
+
+    fn resolve(args: &[&str], signer: &Pubkey, accounts: &Accounts) -> Action {
+        let cli = Cli::try_parse_from([&["unimake"], args].concat()).unwrap();
+        commands::resolve(cli.command, signer, accounts).unwrap()
+    }
+
+    fn sent(action: Action) -> Instruction {
+        match action {
+            Action::Send(mut instructions) => instructions.remove(0),
+            Action::Show(value) => panic!("expected a transaction, got {value}"),
+        }
+    }
+
+    #[test]
+    fn the_cli_is_well_formed() {
+        Cli::command().debug_assert();
+    }
+
+    #[test]
+    fn global_flags_go_anywhere() {
+        let cli = Cli::try_parse_from([
+            "unimake",
+            "bounty",
+            "pause",
+            "--id",
+            ID,
+            "--dry-run",
+            "-k",
+            "ops.json",
+        ])
+        .unwrap();
+        assert!(cli.dry_run);
+        assert_eq!(cli.keypair, Some(PathBuf::from("ops.json")));
+        assert!(commands::signs(&cli.command));
+
+        let read =
+            Cli::try_parse_from(["unimake", "account", &Pubkey::default().to_string()]).unwrap();
+        assert!(!commands::signs(&read.command));
+    }
+
+    #[test]
+    fn bad_arguments_are_refused() {
+        let parse = |args: &[&str]| Cli::try_parse_from([&["unimake"], args].concat());
+        assert!(parse(&["bounty", "pause", "--id", "not-a-uuid"]).is_err());
+        assert!(parse(&[
+            "badge",
+            "award",
+            "--wallet",
+            "x",
+            "--badge",
+            "early-adopter"
+        ])
+        .is_err());
+        let wallet = Pubkey::new_unique().to_string();
+        assert!(parse(&["badge", "award", "--wallet", &wallet, "--badge", "gold"]).is_err());
+        assert!(parse(&[
+            "submission",
+            "approve",
+            "--bounty",
+            ID,
+            "--sequence",
+            "0",
+            "--score",
+            "101"
+        ])
+        .is_err());
+    }
+
+    #[test]
+    fn commands_build_the_programs_instructions() {
+        let signer = Pubkey::new_unique();
+        let id = unimake_client::uuid_to_bytes(ID).unwrap();
+
+        let pause = sent(resolve(
+            &["bounty", "pause", "--id", ID],
+            &signer,
+            &Accounts::default(),
+        ));
+        assert_eq!(
+            pause,
+            unimake_client::instructions::pause_bounty(&id, &signer)
+        );
+
+        let create = sent(resolve(
+            &[
+                "bounty",
+                "create",
+                "--id",
+                ID,
+                "--reward",
+                "1000",
+                "--videos",
+                "5",
+                "--description",
+                "Chop vegetables",
+                "--category",
+                "kitchen",
+                "--orientation",
+                "landscape",
+                "--camera",
+                "egocentric",
+                "--tag",
+                "knife",
+            ],
+            &signer,
+            &Accounts::default(),
+        ));
+        assert_eq!(create.accounts[0].pubkey, pda::bounty(&id).0);
+        assert!(create.accounts[2].is_signer && create.accounts[3].is_signer);
+        let expected = instruction::CreateBounty {
+            bounty_id: id,
+            reward_per_video: 1000,
+            total_pool: 5000,
+            videos_target: 5,
+            task_description: "Chop vegetables".to_string(),
+            requirements: unimake_client::program::Requirements {
+                version: 0,
+                min_duration_secs: 0,
+                max_duration_secs: 0,
+                min_width: 0,
+                min_height: 0,
+                min_fps: 0,
+                allowed_orientations: vec![unimake_client::program::Orientation::Landscape],
+                required_sensors: vec![],
+                camera_position: unimake_client::program::CameraPosition::Egocentric,
+                category: "kitchen".to_string(),
+                tags: vec!["knife".to_string()],
+            },
+            expires_at: 0,
+        }
+        .data();
+        // Everything but the trailing expiry, which depends on the clock
+        assert_eq!(
+            create.data[..create.data.len() - 8],
+            expected[..expected.len() - 8]
+        );
+    }
+
+    #[test]
+    fn purchases_pay_the_current_creator() {
+        let buyer = Pubkey::new_unique();
+        let creator = Pubkey::new_unique();
+        let id = unimake_client::uuid_to_bytes(ID).unwrap();
+        let dataset = DatasetNFT {
+            dataset_id: id,
+            license_type: LicenseType::SingleUse,
+            creator,
+            price: 1000,
+            royalty_percentage: 5,
+            created_at: 0,
+            total_sales: 0,
+            pending_creator: None,
+            bounty: Pubkey::new_unique(),
+            sponsor_bps: 0,
+            bump: 255,
+        };
+        let mut data = Vec::new();
+        dataset.try_serialize(&mut data).unwrap();
+        let mut accounts = Accounts::default();
+        accounts.0.insert(pda::dataset(&id).0, data);
+
+        let purchase = sent(resolve(
+            &["dataset", "purchase", "--id", ID],
+            &buyer,
+            &accounts,
+        ));
+        assert_eq!(
+            purchase,
+            unimake_client::instructions::purchase_dataset(&dataset, &buyer)
+        );
+
+        let Action::Show(shown) = resolve(
+            &["account", &pda::dataset(&id).0.to_string()],
+            &buyer,
+            &accounts,
+        ) else {
+            panic!("account prints");
+        };
+        assert_eq!(shown["type"], "DatasetNFT");
+        assert_eq!(shown["data"]["creator"], creator.to_string());
+    }
+}
@@ -0,0 +1,400 @@
+//! A minimal JSON-RPC client: only the handful of methods the CLI needs, over HTTP or
+//! HTTPS (rustls), so it works against a local validator and remote clusters alike.
+
+use std::str::FromStr;
+use std::thread;
+use std::time::Duration;
+
+use anchor_lang::prelude::Pubkey;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use serde_json::{json, Value};
+use solana_hash::Hash;
+use solana_transaction::Transaction;
+use unimake_client::{AccountSource, ClientError};
+
+use crate::Error;
+
+pub struct RpcClient {
+    url: reqwest::Url,
+    http: reqwest::blocking::Client,
+}
+
+/// What `simulateTransaction` reports
+pub struct Simulation {
+    pub err: Value,
+    pub logs: Vec<String>,
+    pub units_consumed: Option<u64>,
+}
+
+impl RpcClient {
+    /// `url` is an `http://` or `https://` JSON-RPC endpoint
+    pub fn new(url: &str) -> Result<Self, Error> {
+        let url = reqwest::Url::parse(url).map_err(|err| Error::Usage(format!("{url}: {err}")))?;
+        if !matches!(url.scheme(), "http" | "https") {
+            return Err(Error::Usage(format!(
+                "{url}: expected an http:// or https:// URL"
+            )));
+        }
+        let http = reqwest::blocking::Client::builder()
+            .timeout(Duration::from_secs(30))
+            .build()
+            .map_err(|err| Error::Rpc(format!("could not start the HTTP client: {err}")))?;
+        Ok(Self { url, http })
+    }
+
+    pub fn call(&self, method: &str, params: Value) -> Result<Value, Error> {
+        let body = json!({ "jsonrpc": "2.0", "id": 1, "method": method, "params": params });
+        let http = |err: reqwest::Error| Error::Rpc(format!("{method}: {}: {err}", self.url));
+        let response = self
+            .http
+            .post(self.url.clone())
+            .json(&body)
+            .send()
+            .map_err(http)?;
+        let status = response.status();
+        if !status.is_success() {
+            let text = response.text().unwrap_or_default();
+            return Err(Error::Rpc(format!("{method}: HTTP {status}: {text}")));
+        }
+        let mut response: Value = response
+            .json()
+            .map_err(|err| Error::Rpc(format!("{method}: invalid response: {err}")))?;
+        if let Some(error) = response.get("error") {
+            return Err(Error::Rpc(format!("{method}: {error}")));
+        }
+        Ok(response["result"].take())
+    }
+
+    pub fn latest_blockhash(&self) -> Result<Hash, Error> {
+        let result = self.call("getLatestBlockhash", json!([{ "commitment": "confirmed" }]))?;
+        let blockhash = result["value"]["blockhash"]
+            .as_str()
+            .ok_or_else(|| Error::Rpc(format!("getLatestBlockhash: no blockhash in {result}")))?;
+        Hash::from_str(blockhash)
+            .map_err(|_| Error::Rpc(format!("invalid blockhash {blockhash:?}")))
+    }
+
+    pub fn simulate(&self, transaction: &Transaction) -> Result<Simulation, Error> {
+        let result = self.call(
+            "simulateTransaction",
+            json!([
+                encode(transaction)?,
+                { "encoding": "base64", "commitment": "confirmed", "sigVerify": true }
+            ]),
+        )?;
+        let value = &result["value"];
+        Ok(Simulation {
+            err: value["err"].clone(),
+            logs: value["logs"]
+                .as_array()
+                .map(|logs| {
+                    logs.iter()
+                        .filter_map(|line| line.as_str().map(str::to_string))
+                        .collect()
+                })
+                .unwrap_or_default(),
+            units_consumed: value["unitsConsumed"].as_u64(),
+        })
+    }
+
+    /// Send a signed transaction and wait until it's confirmed; returns its signature.
+    /// Gives up once the transaction's blockhash expires, after which it can no longer
+    /// land.
+    pub fn send_and_confirm(&self, transaction: &Transaction) -> Result<String, Error> {
+        let result = self.call(
+            "sendTransaction",
+            json!([encode(transaction)?, { "encoding": "base64", "preflightCommitment": "confirmed" }]),
+        )?;
+        let signature = result
+            .as_str()
+            .ok_or_else(|| Error::Rpc(format!("sendTransaction: no signature in {result}")))?
+            .to_string();
+
+        loop {
+            let statuses = self.call("getSignatureStatuses", json!([[signature]]))?;
+            let status = &statuses["value"][0];
+            if !status.is_null() {
+                if !status["err"].is_null() {
+                    return Err(Error::Rpc(format!("{signature} failed: {}", status["err"])));
+                }
+                if matches!(
+                    status["confirmationStatus"].as_str(),
+                    Some("confirmed" | "finalized")
+                ) {
+                    return Ok(signature);
+                }
+            }
+            if !self.blockhash_is_valid(&transaction.message.recent_blockhash)? {
+                return Err(Error::Rpc(format!(
+                    "{signature} was not confirmed before its blockhash expired"
+                )));
+            }
+            thread::sleep(Duration::from_millis(500));
+        }
+    }
+
+    fn blockhash_is_valid(&self, blockhash: &Hash) -> Result<bool, Error> {
+        let result = self.call(
+            "isBlockhashValid",
+            json!([blockhash.to_string(), { "commitment": "processed" }]),
+        )?;
+        result["value"]
+            .as_bool()
+            .ok_or_else(|| Error::Rpc(format!("isBlockhashValid: no value in {result}")))
+    }
+}
+
+impl AccountSource for RpcClient {
+    fn account_data(&self, address: &Pubkey) -> unimake_client::Result<Option<Vec<u8>>> {
+        let result = self
+            .call(
+                "getAccountInfo",
+                json!([address.to_string(), { "encoding": "base64", "commitment": "confirmed" }]),
+            )
+            .map_err(|err| ClientError::Source(err.to_string()))?;
+        account_data(address, &result["value"])
+    }
+
+    fn accounts_data(&self, addresses: &[Pubkey]) -> unimake_client::Result<Vec<Option<Vec<u8>>>> {
+        let keys: Vec<String> = addresses.iter().map(Pubkey::to_string).collect();
+        let result = self
+            .call(
+                "getMultipleAccounts",
+                json!([keys, { "encoding": "base64", "commitment": "confirmed" }]),
+            )
+            .map_err(|err| ClientError::Source(err.to_string()))?;
+        let values = result["value"]
+            .as_array()
+            .ok_or_else(|| ClientError::Source("getMultipleAccounts: missing value".to_string()))?;
+        addresses
+            .iter()
+            .zip(values)
+            .map(|(address, value)| account_data(address, value))
+            .collect()
+    }
+}
+
+/// Decode one base64 account `value` from `getAccountInfo` or `getMultipleAccounts`
+fn account_data(address: &Pubkey, value: &Value) -> unimake_client::Result<Option<Vec<u8>>> {
+    if value.is_null() {
+        return Ok(None);
+    }
+    let data = value["data"][0]
+        .as_str()
+        .ok_or_else(|| ClientError::Source(format!("{address}: no base64 data in {value}")))?;
+    BASE64
+        .decode(data)
+        .map(Some)
+        .map_err(|err| ClientError::Source(format!("{address}: {err}")))
+}
+
+fn encode(transaction: &Transaction) -> Result<String, Error> {
+    let bytes = bincode::serialize(transaction)
+        .map_err(|err| Error::Usage(format!("could not serialize transaction: {err}")))?;
+    Ok(BASE64.encode(bytes))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Read, Write};
+
+    use super::*;
+
+    #[test]
+    fn http_and_https_urls_are_accepted() {
+        assert!(RpcClient::new("http://127.0.0.1:8899").is_ok());
+        assert!(RpcClient::new("https://api.devnet.solana.com").is_ok());
+        assert!(RpcClient::new("ws://127.0.0.1:8900").is_err());
+        assert!(RpcClient::new("127.0.0.1:8899").is_err());
+    }
+
+    #[test]
+    fn http_errors_are_reported() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let server = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            read_request(&mut stream).unwrap();
+            write!(
+                stream,
+                "HTTP/1.1 500 Internal Server Error\r\nContent-Length: 4\r\n\r\noops"
+            )
+            .unwrap();
+        });
+
+        let err = RpcClient::new(&url)
+            .unwrap()
+            .call("getHealth", json!([]))
+            .err()
+            .unwrap();
+        server.join().unwrap();
+        assert!(err.to_string().contains("HTTP 500"));
+        assert!(err.to_string().contains("oops"));
+    }
+
+    /// Read one HTTP request with a JSON body off `stream`; `None` once the client
+    /// closes the connection
+    fn read_request(stream: &mut std::net::TcpStream) -> Option<String> {
+        let mut request = String::new();
+        while !request.ends_with('}') {
+            let mut chunk = [0u8; 1024];
+            let read = stream.read(&mut chunk).unwrap();
+            if read == 0 {
+                return None;
+            }
+            request.push_str(&String::from_utf8_lossy(&chunk[..read]));
+        }
+        Some(request)
+    }
+
+    /// Answer JSON-RPC requests on a local port with `results`, in order; the handle
+    /// returns the raw requests
+    fn serve(results: Vec<Value>) -> (String, thread::JoinHandle<Vec<String>>) {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let server = thread::spawn(move || {
+            let mut requests = Vec::new();
+            let mut results = std::collections::VecDeque::from(results);
+            while !results.is_empty() {
+                let (mut stream, _) = listener.accept().unwrap();
+                // The client keeps the connection alive between calls
+                while let Some(request) = read_request(&mut stream) {
+                    requests.push(request);
+                    let result = results.pop_front().unwrap();
+                    let body = json!({ "jsonrpc": "2.0", "id": 1, "result": result }).to_string();
+                    write!(
+                        stream,
+                        "HTTP/1.1 200 OK\r\nContent-Length: {}\r\n\r\n{body}",
+                        body.len()
+                    )
+                    .unwrap();
+                    if results.is_empty() {
+                        break;
+                    }
+                }
+            }
+            requests
+        });
+        (url, server)
+    }
+
+    /// Answer one JSON-RPC request with `result`; the handle returns the raw request
+    fn serve_once(result: Value) -> (String, thread::JoinHandle<String>) {
+        let (url, server) = serve(vec![result]);
+        let server = thread::spawn(move || server.join().unwrap().remove(0));
+        (url, server)
+    }
+
+    #[test]
+    fn accounts_are_fetched_over_json_rpc() {
+        let (url, server) =
+            serve_once(json!({ "value": { "data": [BASE64.encode([1, 2, 3]), "base64"] } }));
+
+        let address = Pubkey::new_unique();
+        let data = RpcClient::new(&url)
+            .unwrap()
+            .account_data(&address)
+            .unwrap();
+        assert_eq!(data, Some(vec![1, 2, 3]));
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("POST / HTTP/1.1\r\n"));
+        assert!(request
+            .to_lowercase()
+            .contains("content-type: application/json"));
+        assert!(request.contains("\"method\":\"getAccountInfo\""));
+        assert!(request.contains(&address.to_string()));
+    }
+
+    #[test]
+    fn account_batches_are_one_get_multiple_accounts_call() {
+        let (url, server) = serve_once(json!({
+            "value": [{ "data": [BASE64.encode([4, 5]), "base64"] }, null],
+        }));
+
+        let addresses = [Pubkey::new_unique(), Pubkey::new_unique()];
+        let data = RpcClient::new(&url)
+            .unwrap()
+            .accounts_data(&addresses)
+            .unwrap();
+        assert_eq!(data, [Some(vec![4, 5]), None]);
+
+        let request = server.join().unwrap();
+        assert!(request.contains("\"method\":\"getMultipleAccounts\""));
+        assert!(addresses
+            .iter()
+            .all(|address| request.contains(&address.to_string())));
+    }
+
+    #[test]
+    fn responses_without_a_blockhash_are_errors() {
+        let (url, server) = serve_once(json!({ "value": { "lastValidBlockHeight": 10 } }));
+
+        let err = RpcClient::new(&url)
+            .unwrap()
+            .latest_blockhash()
+            .err()
+            .unwrap();
+        server.join().unwrap();
+        assert!(err.to_string().contains("no blockhash"));
+    }
+
+    fn transaction() -> Transaction {
+        let payer = solana_keypair::Keypair::new();
+        Transaction::new_signed_with_payer(
+            &[],
+            Some(&solana_signer::Signer::pubkey(&payer)),
+            &[&payer],
+            Hash::new_unique(),
+        )
+    }
+
+    #[test]
+    fn sends_without_a_signature_are_errors() {
+        let (url, server) = serve_once(Value::Null);
+
+        let err = RpcClient::new(&url)
+            .unwrap()
+            .send_and_confirm(&transaction())
+            .err()
+            .unwrap();
+        server.join().unwrap();
+        assert!(err.to_string().contains("no signature"));
+    }
+
+    #[test]
+    fn confirmation_waits_until_the_transaction_lands() {
+        let (url, server) = serve(vec![
+            json!("sig"),
+            json!({ "value": [null] }),
+            json!({ "value": true }),
+            json!({ "value": [{ "err": null, "confirmationStatus": "confirmed" }] }),
+        ]);
+
+        let signature = RpcClient::new(&url)
+            .unwrap()
+            .send_and_confirm(&transaction())
+            .unwrap();
+        assert_eq!(signature, "sig");
+        let requests = server.join().unwrap();
+        assert!(requests[2].contains("\"method\":\"isBlockhashValid\""));
+    }
+
+    #[test]
+    fn confirmation_stops_once_the_blockhash_expires() {
+        let (url, server) = serve(vec![
+            json!("sig"),
+            json!({ "value": [null] }),
+            json!({ "value": false }),
+        ]);
+
+        let err = RpcClient::new(&url)
+            .unwrap()
+            .send_and_confirm(&transaction())
+            .err()
+            .unwrap();
+        server.join().unwrap();
+        assert!(err.to_string().contains("blockhash expired"));
+    }
+}
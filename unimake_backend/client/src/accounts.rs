@@ -0,0 +1,194 @@
+//! Fetching and decoding program accounts.
+//!
+//! [`AccountSource`] is the one thing a caller provides: raw account data by address,
+//! from an RPC client, a cache or a test harness. Everything else here is decoding.
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::{AccountDeserialize, Discriminator};
+use unimake_backend::{
+    BadgeDefinition, BountyPool, BountySponsor, BuyerProfile, CategoryReputation, ContentRecord,
+    ContributorProfile, DatasetLicense, DatasetNFT, ProgramConfig, QualityAttestation, ReviewVote,
+    SubmissionStatus, ValidatorOracle, VideoSubmission,
+};
+
+use crate::{pda, ClientError, Result};
+
+/// Most accounts a source is asked for at once, matching RPC `getMultipleAccounts`
+pub const MAX_MULTIPLE_ACCOUNTS: usize = 100;
+
+/// Raw account data by address
+pub trait AccountSource {
+    /// The account's data, or `None` if no account exists at `address`
+    fn account_data(&self, address: &Pubkey) -> Result<Option<Vec<u8>>>;
+
+    /// Each account's data, in the order of `addresses`. Sources that can fetch in
+    /// one round trip should override this; callers pass at most
+    /// [`MAX_MULTIPLE_ACCOUNTS`] addresses.
+    fn accounts_data(&self, addresses: &[Pubkey]) -> Result<Vec<Option<Vec<u8>>>> {
+        addresses
+            .iter()
+            .map(|address| self.account_data(address))
+            .collect()
+    }
+}
+
+/// Decode `data` as a `T`, checking its discriminator
+pub fn decode<T: AccountDeserialize>(address: &Pubkey, data: &[u8]) -> Result<T> {
+    T::try_deserialize(&mut &data[..]).map_err(|err| ClientError::InvalidAccount {
+        address: *address,
+        reason: err.to_string(),
+    })
+}
+
+/// Fetch and decode the `T` at `address`, or `None` if there is no account
+pub fn fetch_optional<T: AccountDeserialize>(
+    source: &impl AccountSource,
+    address: &Pubkey,
+) -> Result<Option<T>> {
+    source
+        .account_data(address)?
+        .map(|data| decode(address, &data))
+        .transpose()
+}
+
+/// Fetch and decode the `T` at `address`
+pub fn fetch<T: AccountDeserialize>(source: &impl AccountSource, address: &Pubkey) -> Result<T> {
+    fetch_optional(source, address)?.ok_or(ClientError::AccountNotFound(*address))
+}
+
+pub fn fetch_bounty(source: &impl AccountSource, bounty_id: &[u8; 16]) -> Result<BountyPool> {
+    fetch(source, &pda::bounty(bounty_id).0)
+}
+
+/// Every submission to a bounty, in sequence order, with its address. Fetched in
+/// batches of [`MAX_MULTIPLE_ACCOUNTS`].
+pub fn fetch_submissions(
+    source: &impl AccountSource,
+    bounty: &BountyPool,
+) -> Result<Vec<(Pubkey, VideoSubmission)>> {
+    let pool = pda::bounty(&bounty.bounty_id).0;
+    let addresses: Vec<Pubkey> = (0..bounty.submission_count)
+        .map(|sequence| pda::submission(&pool, sequence).0)
+        .collect();
+    let mut submissions = Vec::with_capacity(addresses.len());
+    for batch in addresses.chunks(MAX_MULTIPLE_ACCOUNTS) {
+        let accounts = source.accounts_data(batch)?;
+        if accounts.len() != batch.len() {
+            return Err(ClientError::Source(format!(
+                "asked for {} accounts, got {}",
+                batch.len(),
+                accounts.len()
+            )));
+        }
+        for (address, data) in batch.iter().zip(accounts) {
+            let data = data.ok_or(ClientError::AccountNotFound(*address))?;
+            submissions.push((*address, decode(address, &data)?));
+        }
+    }
+    Ok(submissions)
+}
+
+/// A bounty's submissions still awaiting review
+pub fn fetch_pending_submissions(
+    source: &impl AccountSource,
+    bounty: &BountyPool,
+) -> Result<Vec<(Pubkey, VideoSubmission)>> {
+    let mut submissions = fetch_submissions(source, bounty)?;
+    submissions.retain(|(_, submission)| submission.status == SubmissionStatus::Pending);
+    Ok(submissions)
+}
+
+pub fn fetch_profile(
+    source: &impl AccountSource,
+    wallet: &Pubkey,
+) -> Result<Option<ContributorProfile>> {
+    fetch_optional(source, &pda::profile(wallet).0)
+}
+
+pub fn fetch_dataset(source: &impl AccountSource, dataset_id: &[u8; 16]) -> Result<DatasetNFT> {
+    fetch(source, &pda::dataset(dataset_id).0)
+}
+
+pub fn fetch_license(
+    source: &impl AccountSource,
+    dataset: &Pubkey,
+    holder: &Pubkey,
+) -> Result<Option<DatasetLicense>> {
+    fetch_optional(source, &pda::license(dataset, holder).0)
+}
+
+/// Any program account, identified by its discriminator
+pub enum ProgramAccount {
+    BountyPool(BountyPool),
+    BountySponsor(BountySponsor),
+    VideoSubmission(VideoSubmission),
+    ContentRecord(ContentRecord),
+    ReviewVote(ReviewVote),
+    ContributorProfile(ContributorProfile),
+    CategoryReputation(CategoryReputation),
+    BuyerProfile(BuyerProfile),
+    ProgramConfig(ProgramConfig),
+    ValidatorOracle(ValidatorOracle),
+    QualityAttestation(QualityAttestation),
+    BadgeDefinition(BadgeDefinition),
+    DatasetNFT(DatasetNFT),
+    DatasetLicense(DatasetLicense),
+}
+
+macro_rules! decode_any {
+    ($address:expr, $data:expr, $($kind:ident),* $(,)?) => {
+        $(
+            if $data.starts_with($kind::DISCRIMINATOR) {
+                return decode($address, $data).map(ProgramAccount::$kind);
+            }
+        )*
+    };
+}
+
+impl ProgramAccount {
+    /// Decode an account of any program type
+    pub fn decode(address: &Pubkey, data: &[u8]) -> Result<Self> {
+        decode_any!(
+            address,
+            data,
+            BountyPool,
+            BountySponsor,
+            VideoSubmission,
+            ContentRecord,
+            ReviewVote,
+            ContributorProfile,
+            CategoryReputation,
+            BuyerProfile,
+            ProgramConfig,
+            ValidatorOracle,
+            QualityAttestation,
+            BadgeDefinition,
+            DatasetNFT,
+            DatasetLicense,
+        );
+        Err(ClientError::InvalidAccount {
+            address: *address,
+            reason: "unknown account discriminator".to_string(),
+        })
+    }
+
+    /// The account type's name
+    pub fn name(&self) -> &'static str {
+        match self {
+            ProgramAccount::BountyPool(_) => "BountyPool",
+            ProgramAccount::BountySponsor(_) => "BountySponsor",
+            ProgramAccount::VideoSubmission(_) => "VideoSubmission",
+            ProgramAccount::ContentRecord(_) => "ContentRecord",
+            ProgramAccount::ReviewVote(_) => "ReviewVote",
+            ProgramAccount::ContributorProfile(_) => "ContributorProfile",
+            ProgramAccount::CategoryReputation(_) => "CategoryReputation",
+            ProgramAccount::BuyerProfile(_) => "BuyerProfile",
+            ProgramAccount::ProgramConfig(_) => "ProgramConfig",
+            ProgramAccount::ValidatorOracle(_) => "ValidatorOracle",
+            ProgramAccount::QualityAttestation(_) => "QualityAttestation",
+            ProgramAccount::BadgeDefinition(_) => "BadgeDefinition",
+            ProgramAccount::DatasetNFT(_) => "DatasetNFT",
+            ProgramAccount::DatasetLicense(_) => "DatasetLicense",
+        }
+    }
+}
@@ -0,0 +1,32 @@
+use std::fmt;
+
+use anchor_lang::prelude::Pubkey;
+
+pub type Result<T> = std::result::Result<T, ClientError>;
+
+#[derive(Debug)]
+pub enum ClientError {
+    /// Not a 32-digit hex UUID
+    InvalidUuid(String),
+    /// No account exists at the address
+    AccountNotFound(Pubkey),
+    /// The account exists but isn't the expected program account
+    InvalidAccount { address: Pubkey, reason: String },
+    /// The caller's [`AccountSource`](crate::AccountSource) failed
+    Source(String),
+}
+
+impl fmt::Display for ClientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ClientError::InvalidUuid(uuid) => write!(f, "invalid UUID: {uuid}"),
+            ClientError::AccountNotFound(address) => write!(f, "account {address} not found"),
+            ClientError::InvalidAccount { address, reason } => {
+                write!(f, "account {address} could not be decoded: {reason}")
+            }
+            ClientError::Source(reason) => write!(f, "account source failed: {reason}"),
+        }
+    }
+}
+
+impl std::error::Error for ClientError {}
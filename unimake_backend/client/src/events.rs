@@ -0,0 +1,124 @@
+//! Decoding program events from transaction logs.
+//!
+//! `emit!` writes each event as a `Program data: <base64>` log line, the event's
+//! discriminator followed by its borsh encoding.
+
+use anchor_lang::{AnchorDeserialize, Discriminator};
+use base64::Engine;
+use unimake_backend::ID;
+
+macro_rules! program_events {
+    ($($event:ident),* $(,)?) => {
+        /// Any event the program emits
+        pub enum ProgramEvent {
+            $($event(unimake_backend::$event),)*
+        }
+
+        /// Decode one event from its discriminator-prefixed bytes. `None` if the bytes
+        /// aren't a known event or don't decode.
+        pub fn decode_event(data: &[u8]) -> Option<ProgramEvent> {
+            $(
+                if let Some(mut body) = data.strip_prefix(unimake_backend::$event::DISCRIMINATOR) {
+                    return unimake_backend::$event::deserialize(&mut body).ok().map(ProgramEvent::$event);
+                }
+            )*
+            None
+        }
+
+        impl ProgramEvent {
+            /// The event's name, as in the IDL
+            pub fn name(&self) -> &'static str {
+                match self {
+                    $(ProgramEvent::$event(_) => stringify!($event),)*
+                }
+            }
+        }
+    };
+}
+
+program_events!(
+    BountyCreated,
+    BountyStatusChanged,
+    SponsorContributed,
+    SponsorRefunded,
+    SponsorProceedsClaimed,
+    AuthorityRefunded,
+    VideoSubmitted,
+    SubmissionApproved,
+    RewardTierApplied,
+    BatchItemSkipped,
+    BatchReviewed,
+    SubmissionRejected,
+    AttestationPolicyUpdated,
+    ContentReuseUpdated,
+    ReputationGateUpdated,
+    BountyAuthorityTransferProposed,
+    BountyAuthorityTransferCancelled,
+    BountyAuthorityTransferred,
+    SubmissionBondUpdated,
+    RewardTiersUpdated,
+    BonusPoolFunded,
+    ReviewPanelUpdated,
+    ReviewVoteCast,
+    SubmissionDisputed,
+    DisputeResolved,
+    ConfigInitialized,
+    ContributorSuspended,
+    SuspensionLifted,
+    ValidatorRegistered,
+    ValidatorStatusChanged,
+    ValidatorDeregistered,
+    QualityAttested,
+    ProfileCreated,
+    ReputationRefreshed,
+    BadgeAwarded,
+    BadgeDefinitionCreated,
+    DefinedBadgeAwarded,
+    BadgeMintInitialized,
+    BadgeTokenMinted,
+    DatasetCreated,
+    DatasetPurchased,
+    DatasetCreatorTransferProposed,
+    DatasetCreatorTransferCancelled,
+    DatasetCreatorTransferred,
+);
+
+const DATA_PREFIX: &str = "Program data: ";
+
+/// Every event this program emitted in a transaction's logs, in order.
+///
+/// Data lines are attributed to whichever program is executing, tracked through the
+/// `invoke`/`success`/`failed` lines, so events from other programs in the same
+/// transaction - including ones that CPI into this one - are skipped.
+pub fn parse_logs<S: AsRef<str>>(logs: &[S]) -> Vec<ProgramEvent> {
+    let invoke = format!("Program {ID} invoke");
+    let mut stack: Vec<bool> = Vec::new();
+    let mut events = Vec::new();
+
+    for line in logs.iter().map(AsRef::as_ref) {
+        if let Some(data) = line.strip_prefix(DATA_PREFIX) {
+            if stack.last() != Some(&true) {
+                continue;
+            }
+            let Ok(bytes) = base64::engine::general_purpose::STANDARD.decode(data) else {
+                continue;
+            };
+            events.extend(decode_event(&bytes));
+        } else if line.starts_with("Program ") && line.contains(" invoke [") {
+            stack.push(line.starts_with(&invoke));
+        } else if line.starts_with("Program ")
+            && (line.ends_with(" success") || line.contains(" failed: "))
+        {
+            stack.pop();
+        }
+    }
+    events
+}
+
+/// Encode an event as the log line `emit!` writes for it
+pub fn log_line<E: anchor_lang::Event>(event: &E) -> String {
+    format!(
+        "{DATA_PREFIX}{}",
+        base64::engine::general_purpose::STANDARD.encode(event.data())
+    )
+}
@@ -0,0 +1,645 @@
+//! Typed instruction builders.
+//!
+//! Each builder derives the instruction's PDAs and fills in its accounts from the
+//! program's generated `accounts` structs, so signer and writable flags always match
+//! the program. Instructions with several arguments take the program's generated
+//! `instruction` struct for them, e.g. [`program::instruction::CreateBounty`].
+//!
+//! Builders that act on an existing bounty or submission take the decoded account,
+//! which carries the ids and category the remaining addresses derive from.
+//!
+//! [`program::instruction::CreateBounty`]: unimake_backend::instruction::CreateBounty
+
+use anchor_lang::prelude::{pubkey, AccountMeta, Pubkey};
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::{system_program, InstructionData, ToAccountMetas};
+use anchor_spl::{associated_token, token_2022};
+use unimake_backend::{
+    accounts, instruction as args, BadgeType, BountyPool, DatasetNFT, RejectionReason,
+    ReviewDecision, SuspensionReason, VideoSubmission, ID,
+};
+
+use crate::pda;
+
+const BPF_LOADER_UPGRADEABLE: Pubkey = pubkey!("BPFLoaderUpgradeab1e11111111111111111111111");
+
+fn build(accounts: impl ToAccountMetas, data: impl InstructionData) -> Instruction {
+    Instruction {
+        program_id: ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+// Bounty lifecycle
+
+/// Create a bounty funded by `payer` and owned by `authority`, which may be the same key
+pub fn create_bounty(authority: &Pubkey, payer: &Pubkey, args: args::CreateBounty) -> Instruction {
+    build(
+        accounts::CreateBounty {
+            bounty_pool: pda::bounty(&args.bounty_id).0,
+            buyer_profile: pda::buyer_profile(authority).0,
+            authority: *authority,
+            payer: *payer,
+            system_program: system_program::ID,
+        },
+        args,
+    )
+}
+
+fn update_bounty(bounty_id: &[u8; 16], authority: &Pubkey) -> accounts::UpdateBounty {
+    accounts::UpdateBounty {
+        bounty_pool: pda::bounty(bounty_id).0,
+        authority: *authority,
+    }
+}
+
+pub fn pause_bounty(bounty_id: &[u8; 16], authority: &Pubkey) -> Instruction {
+    build(update_bounty(bounty_id, authority), args::PauseBounty {})
+}
+
+pub fn resume_bounty(bounty_id: &[u8; 16], authority: &Pubkey) -> Instruction {
+    build(update_bounty(bounty_id, authority), args::ResumeBounty {})
+}
+
+fn close_bounty(bounty_id: &[u8; 16], authority: &Pubkey) -> accounts::CloseBounty {
+    accounts::CloseBounty {
+        bounty_pool: pda::bounty(bounty_id).0,
+        authority: *authority,
+        system_program: system_program::ID,
+    }
+}
+
+pub fn complete_bounty(bounty_id: &[u8; 16], authority: &Pubkey) -> Instruction {
+    build(close_bounty(bounty_id, authority), args::CompleteBounty {})
+}
+
+pub fn cancel_bounty(bounty_id: &[u8; 16], authority: &Pubkey) -> Instruction {
+    build(close_bounty(bounty_id, authority), args::CancelBounty {})
+}
+
+/// A sponsor's cut of sales of datasets built from the bounty
+pub fn claim_sponsor_proceeds(bounty_id: &[u8; 16], sponsor: &Pubkey) -> Instruction {
+    let bounty_pool = pda::bounty(bounty_id).0;
+    build(
+        accounts::ClaimSponsorRefund {
+            bounty_pool,
+            sponsor_record: pda::sponsor(&bounty_pool, sponsor).0,
+            sponsor: *sponsor,
+        },
+        args::ClaimSponsorProceeds {},
+    )
+}
+
+/// The authority's share of escrow returned after its bounty closed
+pub fn claim_authority_refund(bounty_id: &[u8; 16], authority: &Pubkey) -> Instruction {
+    build(
+        close_bounty(bounty_id, authority),
+        args::ClaimAuthorityRefund {},
+    )
+}
+
+/// Permissionless: anyone may close an expired bounty, refunding its `authority`
+pub fn close_expired_bounty(bounty: &BountyPool) -> Instruction {
+    build(
+        accounts::CloseExpiredBounty {
+            bounty_pool: pda::bounty(&bounty.bounty_id).0,
+            authority: bounty.authority,
+        },
+        args::CloseExpiredBounty {},
+    )
+}
+
+pub fn contribute_to_bounty(bounty_id: &[u8; 16], sponsor: &Pubkey, amount: u64) -> Instruction {
+    let bounty_pool = pda::bounty(bounty_id).0;
+    build(
+        accounts::ContributeToBounty {
+            bounty_pool,
+            sponsor_record: pda::sponsor(&bounty_pool, sponsor).0,
+            sponsor: *sponsor,
+            system_program: system_program::ID,
+        },
+        args::ContributeToBounty { amount },
+    )
+}
+
+pub fn claim_sponsor_refund(bounty_id: &[u8; 16], sponsor: &Pubkey) -> Instruction {
+    let bounty_pool = pda::bounty(bounty_id).0;
+    build(
+        accounts::ClaimSponsorRefund {
+            bounty_pool,
+            sponsor_record: pda::sponsor(&bounty_pool, sponsor).0,
+            sponsor: *sponsor,
+        },
+        args::ClaimSponsorRefund {},
+    )
+}
+
+pub fn fund_bonus_pool(
+    bounty_id: &[u8; 16],
+    authority: &Pubkey,
+    payer: &Pubkey,
+    amount: u64,
+) -> Instruction {
+    build(
+        accounts::FundBonusPool {
+            bounty_pool: pda::bounty(bounty_id).0,
+            authority: *authority,
+            payer: *payer,
+            system_program: system_program::ID,
+        },
+        args::FundBonusPool { amount },
+    )
+}
+
+/// Any of the authority's bounty settings, e.g. [`args::SetRewardTiers`]
+pub fn update_bounty_settings(
+    bounty_id: &[u8; 16],
+    authority: &Pubkey,
+    settings: impl InstructionData,
+) -> Instruction {
+    build(update_bounty(bounty_id, authority), settings)
+}
+
+pub fn propose_bounty_authority(
+    bounty_id: &[u8; 16],
+    authority: &Pubkey,
+    new_authority: &Pubkey,
+) -> Instruction {
+    build(
+        update_bounty(bounty_id, authority),
+        args::ProposeBountyAuthority {
+            new_authority: *new_authority,
+        },
+    )
+}
+
+pub fn cancel_bounty_authority_transfer(bounty_id: &[u8; 16], authority: &Pubkey) -> Instruction {
+    build(
+        update_bounty(bounty_id, authority),
+        args::CancelBountyAuthorityTransfer {},
+    )
+}
+
+pub fn accept_bounty_authority(
+    bounty_id: &[u8; 16],
+    new_authority: &Pubkey,
+    payer: &Pubkey,
+) -> Instruction {
+    build(
+        accounts::AcceptBountyAuthority {
+            bounty_pool: pda::bounty(bounty_id).0,
+            buyer_profile: pda::buyer_profile(new_authority).0,
+            new_authority: *new_authority,
+            payer: *payer,
+            system_program: system_program::ID,
+        },
+        args::AcceptBountyAuthority {},
+    )
+}
+
+// Submissions and review
+
+/// Submit to `bounty` as its next submission, `bounty.submission_count`
+pub fn submit_video(
+    bounty: &BountyPool,
+    contributor: &Pubkey,
+    args: args::SubmitVideo,
+) -> Instruction {
+    let bounty_pool = pda::bounty(&bounty.bounty_id).0;
+    build(
+        accounts::SubmitVideo {
+            submission: pda::submission(&bounty_pool, bounty.submission_count).0,
+            content_record: pda::content(&args.content_hash).0,
+            bounty_content: pda::bounty_content(&bounty_pool, &args.content_hash).0,
+            bounty_pool,
+            contributor_profile: pda::profile(contributor).0,
+            category_reputation: pda::category_reputation(
+                contributor,
+                &bounty.requirements.category,
+            )
+            .0,
+            contributor: *contributor,
+            system_program: system_program::ID,
+        },
+        args,
+    )
+}
+
+fn review_submission(
+    bounty: &BountyPool,
+    submission: &VideoSubmission,
+) -> accounts::ReviewSubmission {
+    let bounty_pool = pda::bounty(&bounty.bounty_id).0;
+    let contributor = submission.contributor;
+    accounts::ReviewSubmission {
+        submission: pda::submission(&bounty_pool, submission.sequence).0,
+        content_record: pda::content(&submission.content_hash).0,
+        bounty_pool,
+        contributor_profile: pda::profile(&contributor).0,
+        category_reputation: pda::category_reputation(&contributor, &bounty.requirements.category)
+            .0,
+        buyer_profile: pda::buyer_profile(&bounty.authority).0,
+        contributor,
+        authority: bounty.authority,
+        system_program: system_program::ID,
+    }
+}
+
+pub fn approve_submission(
+    bounty: &BountyPool,
+    submission: &VideoSubmission,
+    quality_score: u8,
+) -> Instruction {
+    build(
+        review_submission(bounty, submission),
+        args::ApproveSubmission { quality_score },
+    )
+}
+
+pub fn reject_submission(
+    bounty: &BountyPool,
+    submission: &VideoSubmission,
+    reason: RejectionReason,
+    feedback_uri: String,
+) -> Instruction {
+    build(
+        review_submission(bounty, submission),
+        args::RejectSubmission {
+            reason,
+            feedback_uri,
+        },
+    )
+}
+
+/// Review several submissions at once. Each item's five accounts are appended as
+/// remaining accounts in the order `batch_review` reads them.
+pub fn batch_review(
+    bounty: &BountyPool,
+    items: &[(&VideoSubmission, ReviewDecision)],
+    atomic: bool,
+) -> Instruction {
+    let bounty_pool = pda::bounty(&bounty.bounty_id).0;
+    let mut ix = build(
+        accounts::BatchReview {
+            bounty_pool,
+            buyer_profile: pda::buyer_profile(&bounty.authority).0,
+            authority: bounty.authority,
+            system_program: system_program::ID,
+        },
+        args::BatchReview {
+            decisions: items.iter().map(|(_, decision)| *decision).collect(),
+            atomic,
+        },
+    );
+    for (submission, _) in items {
+        let contributor = submission.contributor;
+        ix.accounts.extend([
+            AccountMeta::new(pda::submission(&bounty_pool, submission.sequence).0, false),
+            AccountMeta::new(contributor, false),
+            AccountMeta::new(pda::profile(&contributor).0, false),
+            AccountMeta::new(
+                pda::category_reputation(&contributor, &bounty.requirements.category).0,
+                false,
+            ),
+            AccountMeta::new(pda::content(&submission.content_hash).0, false),
+        ]);
+    }
+    ix
+}
+
+pub fn cast_review_vote(
+    bounty: &BountyPool,
+    submission: &VideoSubmission,
+    reviewer: &Pubkey,
+    approve: bool,
+    quality_score: u8,
+) -> Instruction {
+    let review = review_submission(bounty, submission);
+    build(
+        accounts::CastReviewVote {
+            review_vote: pda::review_vote(&review.submission, reviewer).0,
+            submission: review.submission,
+            content_record: review.content_record,
+            bounty_pool: review.bounty_pool,
+            contributor_profile: review.contributor_profile,
+            category_reputation: review.category_reputation,
+            buyer_profile: review.buyer_profile,
+            contributor: review.contributor,
+            reviewer: *reviewer,
+            system_program: system_program::ID,
+        },
+        args::CastReviewVote {
+            approve,
+            quality_score,
+        },
+    )
+}
+
+pub fn resolve_dispute(
+    bounty: &BountyPool,
+    submission: &VideoSubmission,
+    admin: &Pubkey,
+    approve: bool,
+    quality_score: u8,
+) -> Instruction {
+    let review = review_submission(bounty, submission);
+    build(
+        accounts::ResolveDispute {
+            submission: review.submission,
+            content_record: review.content_record,
+            bounty_pool: review.bounty_pool,
+            contributor_profile: review.contributor_profile,
+            category_reputation: review.category_reputation,
+            buyer_profile: review.buyer_profile,
+            contributor: review.contributor,
+            config: pda::config().0,
+            admin: *admin,
+        },
+        args::ResolveDispute {
+            approve,
+            quality_score,
+        },
+    )
+}
+
+pub fn attest_submission(
+    bounty: &BountyPool,
+    submission: &VideoSubmission,
+    validator: &Pubkey,
+    args: args::AttestSubmission,
+) -> Instruction {
+    let review = review_submission(bounty, submission);
+    build(
+        accounts::AttestSubmission {
+            attestation: pda::attestation(&review.submission, validator).0,
+            validator_oracle: pda::validator(validator).0,
+            submission: review.submission,
+            content_record: review.content_record,
+            bounty_pool: review.bounty_pool,
+            contributor_profile: review.contributor_profile,
+            category_reputation: review.category_reputation,
+            buyer_profile: review.buyer_profile,
+            contributor: review.contributor,
+            validator: *validator,
+            system_program: system_program::ID,
+        },
+        args,
+    )
+}
+
+// Profiles and badges
+
+pub fn initialize_profile(contributor: &Pubkey) -> Instruction {
+    build(
+        accounts::InitializeProfile {
+            contributor_profile: pda::profile(contributor).0,
+            contributor: *contributor,
+            system_program: system_program::ID,
+        },
+        args::InitializeProfile {},
+    )
+}
+
+/// Permissionless: applies time decay to a contributor's reputation
+pub fn refresh_reputation(contributor: &Pubkey) -> Instruction {
+    build(
+        accounts::RefreshReputation {
+            contributor_profile: pda::profile(contributor).0,
+        },
+        args::RefreshReputation {},
+    )
+}
+
+pub fn award_badge(contributor: &Pubkey, admin: &Pubkey, badge_type: BadgeType) -> Instruction {
+    build(
+        accounts::AwardBadge {
+            contributor_profile: pda::profile(contributor).0,
+            config: pda::config().0,
+            admin: *admin,
+        },
+        args::AwardBadge { badge_type },
+    )
+}
+
+pub fn initialize_badge_mint(admin: &Pubkey, badge_type: BadgeType, uri: String) -> Instruction {
+    build(
+        accounts::InitializeBadgeMint {
+            badge_mint: pda::badge_mint(&badge_type).0,
+            config: pda::config().0,
+            admin: *admin,
+            token_program: token_2022::ID,
+            system_program: system_program::ID,
+        },
+        args::InitializeBadgeMint { badge_type, uri },
+    )
+}
+
+pub fn mint_badge_token(
+    contributor: &Pubkey,
+    payer: &Pubkey,
+    badge_type: BadgeType,
+) -> Instruction {
+    build(
+        accounts::MintBadgeToken {
+            contributor_profile: pda::profile(contributor).0,
+            badge_mint: pda::badge_mint(&badge_type).0,
+            contributor_token_account: pda::badge_token_account(contributor, &badge_type),
+            contributor: *contributor,
+            payer: *payer,
+            token_program: token_2022::ID,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+        },
+        args::MintBadgeToken { badge_type },
+    )
+}
+
+pub fn create_badge_definition(admin: &Pubkey, args: args::CreateBadgeDefinition) -> Instruction {
+    build(
+        accounts::CreateBadgeDefinition {
+            badge_definition: pda::badge_definition(args.badge_id).0,
+            config: pda::config().0,
+            admin: *admin,
+            system_program: system_program::ID,
+        },
+        args,
+    )
+}
+
+pub fn award_defined_badge(contributor: &Pubkey, admin: &Pubkey, badge_id: u8) -> Instruction {
+    build(
+        accounts::AwardDefinedBadge {
+            contributor_profile: pda::profile(contributor).0,
+            badge_definition: pda::badge_definition(badge_id).0,
+            config: pda::config().0,
+            admin: *admin,
+        },
+        args::AwardDefinedBadge {},
+    )
+}
+
+/// Permissionless: awards a defined badge whose rule the contributor meets
+pub fn claim_defined_badge(contributor: &Pubkey, badge_id: u8) -> Instruction {
+    build(
+        accounts::ClaimDefinedBadge {
+            contributor_profile: pda::profile(contributor).0,
+            badge_definition: pda::badge_definition(badge_id).0,
+        },
+        args::ClaimDefinedBadge {},
+    )
+}
+
+// Administration
+
+/// Signed by the program's upgrade authority
+pub fn initialize_config(upgrade_authority: &Pubkey, admin: &Pubkey) -> Instruction {
+    build(
+        accounts::InitializeConfig {
+            config: pda::config().0,
+            program: ID,
+            program_data: Pubkey::find_program_address(&[ID.as_ref()], &BPF_LOADER_UPGRADEABLE).0,
+            authority: *upgrade_authority,
+            system_program: system_program::ID,
+        },
+        args::InitializeConfig { admin: *admin },
+    )
+}
+
+pub fn suspend_contributor(
+    contributor: &Pubkey,
+    admin: &Pubkey,
+    reason: SuspensionReason,
+    duration_secs: u64,
+) -> Instruction {
+    build(
+        accounts::SuspendContributor {
+            contributor_profile: pda::profile(contributor).0,
+            contributor: *contributor,
+            config: pda::config().0,
+            admin: *admin,
+            system_program: system_program::ID,
+        },
+        args::SuspendContributor {
+            reason,
+            duration_secs,
+        },
+    )
+}
+
+pub fn lift_suspension(contributor: &Pubkey, admin: &Pubkey) -> Instruction {
+    build(
+        accounts::LiftSuspension {
+            contributor_profile: pda::profile(contributor).0,
+            config: pda::config().0,
+            admin: *admin,
+        },
+        args::LiftSuspension {},
+    )
+}
+
+pub fn register_validator(validator: &Pubkey, admin: &Pubkey, stake: u64) -> Instruction {
+    build(
+        accounts::RegisterValidator {
+            validator_oracle: pda::validator(validator).0,
+            config: pda::config().0,
+            admin: *admin,
+            validator: *validator,
+            system_program: system_program::ID,
+        },
+        args::RegisterValidator { stake },
+    )
+}
+
+pub fn set_validator_active(validator: &Pubkey, admin: &Pubkey, active: bool) -> Instruction {
+    build(
+        accounts::SetValidatorActive {
+            validator_oracle: pda::validator(validator).0,
+            config: pda::config().0,
+            admin: *admin,
+        },
+        args::SetValidatorActive { active },
+    )
+}
+
+pub fn deregister_validator(validator: &Pubkey) -> Instruction {
+    build(
+        accounts::DeregisterValidator {
+            validator_oracle: pda::validator(validator).0,
+            validator: *validator,
+        },
+        args::DeregisterValidator {},
+    )
+}
+
+// Datasets
+
+/// `bounty_id` is the completed bounty the dataset was built from, whose sponsors
+/// share in its sales
+pub fn create_dataset(
+    creator: &Pubkey,
+    bounty_id: &[u8; 16],
+    args: args::CreateDataset,
+) -> Instruction {
+    build(
+        accounts::CreateDataset {
+            dataset_nft: pda::dataset(&args.dataset_id).0,
+            creator: *creator,
+            bounty_pool: pda::bounty(bounty_id).0,
+            system_program: system_program::ID,
+        },
+        args,
+    )
+}
+
+/// Pays the dataset's current creator, and its source bounty's sponsors
+pub fn purchase_dataset(dataset: &DatasetNFT, buyer: &Pubkey) -> Instruction {
+    let dataset_nft = pda::dataset(&dataset.dataset_id).0;
+    build(
+        accounts::PurchaseDataset {
+            dataset_nft,
+            buyer: *buyer,
+            creator: dataset.creator,
+            license: pda::license(&dataset_nft, buyer).0,
+            bounty_pool: dataset.bounty,
+            system_program: system_program::ID,
+        },
+        args::PurchaseDataset {},
+    )
+}
+
+pub fn propose_dataset_creator(
+    dataset_id: &[u8; 16],
+    creator: &Pubkey,
+    new_creator: &Pubkey,
+) -> Instruction {
+    build(
+        accounts::UpdateDataset {
+            dataset_nft: pda::dataset(dataset_id).0,
+            creator: *creator,
+        },
+        args::ProposeDatasetCreator {
+            new_creator: *new_creator,
+        },
+    )
+}
+
+pub fn cancel_dataset_creator_transfer(dataset_id: &[u8; 16], creator: &Pubkey) -> Instruction {
+    build(
+        accounts::UpdateDataset {
+            dataset_nft: pda::dataset(dataset_id).0,
+            creator: *creator,
+        },
+        args::CancelDatasetCreatorTransfer {},
+    )
+}
+
+pub fn accept_dataset_creator(dataset_id: &[u8; 16], new_creator: &Pubkey) -> Instruction {
+    build(
+        accounts::AcceptDatasetCreator {
+            dataset_nft: pda::dataset(dataset_id).0,
+            new_creator: *new_creator,
+        },
+        args::AcceptDatasetCreator {},
+    )
+}
@@ -0,0 +1,68 @@
+//! Rust client for the TerraTrain bounty program, for backend services and indexers.
+//!
+//! - [`pda`] derives every program address from its seeds
+//! - [`instructions`] builds typed instructions with their accounts filled in
+//! - [`accounts`] fetches and decodes program accounts from any [`AccountSource`]
+//! - [`events`] decodes program events from transaction logs
+//!
+//! Account and argument types are the program's own, re-exported as [`program`].
+//! Nothing here talks to a cluster directly: callers bring their own RPC client and
+//! implement [`AccountSource`] on it.
+
+pub mod accounts;
+pub mod events;
+pub mod instructions;
+pub mod pda;
+
+mod error;
+
+pub use accounts::AccountSource;
+pub use error::{ClientError, Result};
+pub use unimake_backend as program;
+pub use unimake_backend::ID as PROGRAM_ID;
+
+/// Parse a UUID (`550e8400-e29b-41d4-a716-446655440000`) into the 16-byte id that
+/// bounties, submissions and datasets are keyed by, as the frontend's `uuidToBytes` does
+pub fn uuid_to_bytes(uuid: &str) -> Result<[u8; 16]> {
+    let hex: String = uuid.chars().filter(|c| *c != '-').collect();
+    if hex.len() != 32 || !hex.is_ascii() {
+        return Err(ClientError::InvalidUuid(uuid.to_string()));
+    }
+
+    let mut bytes = [0u8; 16];
+    for (i, byte) in bytes.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&hex[i * 2..i * 2 + 2], 16)
+            .map_err(|_| ClientError::InvalidUuid(uuid.to_string()))?;
+    }
+    Ok(bytes)
+}
+
+/// Format a 16-byte id as a hyphenated UUID
+pub fn bytes_to_uuid(bytes: &[u8; 16]) -> String {
+    let hex: String = bytes.iter().map(|byte| format!("{byte:02x}")).collect();
+    format!(
+        "{}-{}-{}-{}-{}",
+        &hex[0..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..32]
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn uuids_round_trip() {
+        let uuid = "550e8400-e29b-41d4-a716-446655440000";
+        let bytes = uuid_to_bytes(uuid).unwrap();
+        assert_eq!(bytes[0], 0x55);
+        assert_eq!(bytes[15], 0x00);
+        assert_eq!(bytes_to_uuid(&bytes), uuid);
+
+        assert!(uuid_to_bytes("550e8400").is_err());
+        assert!(uuid_to_bytes("zz0e8400-e29b-41d4-a716-446655440000").is_err());
+    }
+}
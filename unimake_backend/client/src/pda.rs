@@ -0,0 +1,97 @@
+//! Program addresses, derived with the same seeds as the program's account constraints.
+//! Each returns the address and its bump.
+
+use anchor_lang::prelude::Pubkey;
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+use anchor_spl::token_2022;
+use unimake_backend::{BadgeType, ID};
+
+fn find(seeds: &[&[u8]]) -> (Pubkey, u8) {
+    Pubkey::find_program_address(seeds, &ID)
+}
+
+/// `BountyPool`: `["bounty", bounty_id]`
+pub fn bounty(bounty_id: &[u8; 16]) -> (Pubkey, u8) {
+    find(&[b"bounty", bounty_id])
+}
+
+/// `VideoSubmission`: `["submission", bounty_pool, sequence]`, sequence little-endian.
+/// A bounty's submissions are sequences `0..submission_count`.
+pub fn submission(bounty_pool: &Pubkey, sequence: u32) -> (Pubkey, u8) {
+    find(&[b"submission", bounty_pool.as_ref(), &sequence.to_le_bytes()])
+}
+
+/// `BountySponsor`: `["sponsor", bounty_pool, sponsor]`
+pub fn sponsor(bounty_pool: &Pubkey, sponsor: &Pubkey) -> (Pubkey, u8) {
+    find(&[b"sponsor", bounty_pool.as_ref(), sponsor.as_ref()])
+}
+
+/// `BuyerProfile`: `["buyer", authority]`
+pub fn buyer_profile(authority: &Pubkey) -> (Pubkey, u8) {
+    find(&[b"buyer", authority.as_ref()])
+}
+
+/// Program-wide `ContentRecord`: `["content", content_hash]`
+pub fn content(content_hash: &[u8; 32]) -> (Pubkey, u8) {
+    find(&[b"content", content_hash])
+}
+
+/// Per-bounty `ContentRecord`: `["content", bounty_pool, content_hash]`
+pub fn bounty_content(bounty_pool: &Pubkey, content_hash: &[u8; 32]) -> (Pubkey, u8) {
+    find(&[b"content", bounty_pool.as_ref(), content_hash])
+}
+
+/// `ReviewVote`: `["vote", submission, reviewer]`
+pub fn review_vote(submission: &Pubkey, reviewer: &Pubkey) -> (Pubkey, u8) {
+    find(&[b"vote", submission.as_ref(), reviewer.as_ref()])
+}
+
+/// `ContributorProfile`: `["profile", wallet]`
+pub fn profile(wallet: &Pubkey) -> (Pubkey, u8) {
+    find(&[b"profile", wallet.as_ref()])
+}
+
+/// `CategoryReputation`: `["category_rep", wallet, category]`
+pub fn category_reputation(wallet: &Pubkey, category: &str) -> (Pubkey, u8) {
+    find(&[b"category_rep", wallet.as_ref(), category.as_bytes()])
+}
+
+/// `ProgramConfig`: `["config"]`
+pub fn config() -> (Pubkey, u8) {
+    find(&[b"config"])
+}
+
+/// `ValidatorOracle`: `["validator", validator]`
+pub fn validator(validator: &Pubkey) -> (Pubkey, u8) {
+    find(&[b"validator", validator.as_ref()])
+}
+
+/// `QualityAttestation`: `["attestation", submission, validator]`
+pub fn attestation(submission: &Pubkey, validator: &Pubkey) -> (Pubkey, u8) {
+    find(&[b"attestation", submission.as_ref(), validator.as_ref()])
+}
+
+/// `BadgeDefinition`: `["badge_def", badge_id]`
+pub fn badge_definition(badge_id: u8) -> (Pubkey, u8) {
+    find(&[b"badge_def", &[badge_id]])
+}
+
+/// Token-2022 badge mint: `["badge_mint", badge_type.seed()]`
+pub fn badge_mint(badge_type: &BadgeType) -> (Pubkey, u8) {
+    find(&[b"badge_mint", &[badge_type.seed()]])
+}
+
+/// A contributor's associated token account for a badge mint
+pub fn badge_token_account(wallet: &Pubkey, badge_type: &BadgeType) -> Pubkey {
+    get_associated_token_address_with_program_id(wallet, &badge_mint(badge_type).0, &token_2022::ID)
+}
+
+/// `DatasetNFT`: `["dataset", dataset_id]`
+pub fn dataset(dataset_id: &[u8; 16]) -> (Pubkey, u8) {
+    find(&[b"dataset", dataset_id])
+}
+
+/// `DatasetLicense`: `["license", dataset, buyer]`
+pub fn license(dataset: &Pubkey, buyer: &Pubkey) -> (Pubkey, u8) {
+    find(&[b"license", dataset.as_ref(), buyer.as_ref()])
+}
@@ -0,0 +1,74 @@
+//! Event decoding from transaction logs. `emit!` is a no-op off-chain, so the logs are
+//! built the way the runtime writes them.
+
+use anchor_lang::prelude::Pubkey;
+use unimake_backend::{BountyStatus, BountyStatusChanged, SubmissionApproved, ID};
+use unimake_client::events::{decode_event, log_line, parse_logs, ProgramEvent};
+
+const OTHER: &str = "Comp1ngProgram11111111111111111111111111111";
+
+fn approved() -> SubmissionApproved {
+    SubmissionApproved {
+        submission_id: [7; 16],
+        contributor: Pubkey::new_unique(),
+        reward: 100_000_000,
+        quality_score: 90,
+    }
+}
+
+#[test]
+fn events_round_trip_through_their_log_lines() {
+    let event = approved();
+    let line = log_line(&event);
+    let logs = [
+        format!("Program {ID} invoke [1]"),
+        "Program log: Instruction: ApproveSubmission".to_string(),
+        line,
+        format!("Program {ID} consumed 20000 of 200000 compute units"),
+        format!("Program {ID} success"),
+    ];
+
+    let events = parse_logs(&logs);
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].name(), "SubmissionApproved");
+    let ProgramEvent::SubmissionApproved(decoded) = &events[0] else {
+        panic!("decoded as {}", events[0].name());
+    };
+    assert_eq!(decoded.contributor, event.contributor);
+    assert_eq!(decoded.reward, event.reward);
+    assert_eq!(decoded.quality_score, 90);
+}
+
+#[test]
+fn only_this_programs_data_lines_are_decoded() {
+    let status = BountyStatusChanged {
+        bounty_id: [1; 16],
+        new_status: BountyStatus::Paused,
+    };
+    let logs = [
+        format!("Program {OTHER} invoke [1]"),
+        // Same bytes, but written by the outer program
+        log_line(&status),
+        format!("Program {ID} invoke [2]"),
+        log_line(&approved()),
+        format!("Program {ID} success"),
+        log_line(&status),
+        format!("Program {OTHER} success"),
+        format!("Program {ID} invoke [1]"),
+        log_line(&status),
+        format!("Program {ID} failed: custom program error: 0x1770"),
+        "Program data: not base64!".to_string(),
+    ];
+
+    let names: Vec<_> = parse_logs(&logs).iter().map(ProgramEvent::name).collect();
+    assert_eq!(names, ["SubmissionApproved", "BountyStatusChanged"]);
+}
+
+#[test]
+fn unknown_or_truncated_data_is_skipped() {
+    let data = anchor_lang::Event::data(&approved());
+    assert!(decode_event(&data).is_some());
+    assert!(decode_event(&data[..data.len() - 1]).is_none());
+    assert!(decode_event(&[0; 32]).is_none());
+    assert!(decode_event(&[]).is_none());
+}
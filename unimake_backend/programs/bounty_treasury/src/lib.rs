@@ -0,0 +1,274 @@
+//! Example controller program: a treasury PDA owns TerraTrain bounties and drives them
+//! through CPI. Multisig vaults (e.g. Squads) follow the same pattern: the bounty
+//! authority is a PDA that signs with seeds, and a separate wallet pays for rent and
+//! the reward pool.
+
+use anchor_lang::prelude::*;
+use unimake_backend::cpi::accounts::{CloseBounty, CreateBounty, ReviewSubmission};
+use unimake_backend::program::UnimakeBackend;
+use unimake_backend::Requirements;
+
+declare_id!("3dCmK7HyBd9dkVZ5Kcy56LkAQUKjdMECHDBY5k9f9etm");
+
+#[program]
+pub mod bounty_treasury {
+    use super::*;
+
+    /// Create the treasury PDA that will act as bounty authority
+    pub fn initialize_treasury(ctx: Context<InitializeTreasury>) -> Result<()> {
+        let treasury = &mut ctx.accounts.treasury;
+        treasury.admin = ctx.accounts.admin.key();
+        treasury.bump = ctx.bumps.treasury;
+        Ok(())
+    }
+
+    /// Create a bounty owned by the treasury; the admin funds it
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_bounty(
+        ctx: Context<CreateTreasuryBounty>,
+        bounty_id: [u8; 16],
+        reward_per_video: u64,
+        total_pool: u64,
+        videos_target: u32,
+        task_description: String,
+        requirements: Requirements,
+        expires_at: i64,
+    ) -> Result<()> {
+        let admin = ctx.accounts.admin.key();
+        let seeds: &[&[u8]] = &[b"treasury", admin.as_ref(), &[ctx.accounts.treasury.bump]];
+        let signer = &[seeds];
+
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.unimake_program.to_account_info(),
+            CreateBounty {
+                bounty_pool: ctx.accounts.bounty_pool.to_account_info(),
+                buyer_profile: ctx.accounts.buyer_profile.to_account_info(),
+                authority: ctx.accounts.treasury.to_account_info(),
+                payer: ctx.accounts.admin.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+            },
+            signer,
+        );
+        unimake_backend::cpi::create_bounty(
+            cpi_ctx,
+            bounty_id,
+            reward_per_video,
+            total_pool,
+            videos_target,
+            task_description,
+            requirements,
+            expires_at,
+        )
+    }
+
+    /// Approve a submission to a treasury-owned bounty
+    pub fn approve_submission(
+        ctx: Context<ReviewTreasurySubmission>,
+        quality_score: u8,
+    ) -> Result<()> {
+        let admin = ctx.accounts.admin.key();
+        let seeds: &[&[u8]] = &[b"treasury", admin.as_ref(), &[ctx.accounts.treasury.bump]];
+        let signer = &[seeds];
+
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.unimake_program.to_account_info(),
+            ReviewSubmission {
+                submission: ctx.accounts.submission.to_account_info(),
+                content_record: ctx.accounts.content_record.to_account_info(),
+                bounty_pool: ctx.accounts.bounty_pool.to_account_info(),
+                contributor_profile: ctx.accounts.contributor_profile.to_account_info(),
+                category_reputation: ctx.accounts.category_reputation.to_account_info(),
+                buyer_profile: ctx.accounts.buyer_profile.to_account_info(),
+                contributor: ctx.accounts.contributor.to_account_info(),
+                authority: ctx.accounts.treasury.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+            },
+            signer,
+        );
+        unimake_backend::cpi::approve_submission(cpi_ctx, quality_score)
+    }
+
+    /// Cancel a treasury-owned bounty; the authority's refund lands in the treasury
+    pub fn cancel_bounty(ctx: Context<CancelTreasuryBounty>) -> Result<()> {
+        let admin = ctx.accounts.admin.key();
+        let seeds: &[&[u8]] = &[b"treasury", admin.as_ref(), &[ctx.accounts.treasury.bump]];
+        let signer = &[seeds];
+
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.unimake_program.to_account_info(),
+            CloseBounty {
+                bounty_pool: ctx.accounts.bounty_pool.to_account_info(),
+                authority: ctx.accounts.treasury.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+            },
+            signer,
+        );
+        unimake_backend::cpi::cancel_bounty(cpi_ctx)
+    }
+
+    /// Move refunded lamports from the treasury back to the admin, keeping it rent-exempt
+    pub fn withdraw(ctx: Context<Withdraw>, amount: u64) -> Result<()> {
+        let treasury = ctx.accounts.treasury.to_account_info();
+        let rent_floor = Rent::get()?.minimum_balance(treasury.data_len());
+        require!(
+            treasury.lamports().saturating_sub(amount) >= rent_floor,
+            TreasuryError::InsufficientFunds
+        );
+
+        **treasury.try_borrow_mut_lamports()? -= amount;
+        **ctx
+            .accounts
+            .admin
+            .to_account_info()
+            .try_borrow_mut_lamports()? += amount;
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct InitializeTreasury<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + Treasury::INIT_SPACE,
+        seeds = [b"treasury".as_ref(), admin.key().as_ref()],
+        bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CreateTreasuryBounty<'info> {
+    #[account(
+        seeds = [b"treasury".as_ref(), admin.key().as_ref()],
+        bump = treasury.bump,
+        has_one = admin
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    /// Pays rent and the reward pool so the treasury PDA never has to
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// CHECK: Created and validated by the bounty program
+    #[account(mut)]
+    pub bounty_pool: UncheckedAccount<'info>,
+
+    /// CHECK: Created and validated by the bounty program
+    #[account(mut)]
+    pub buyer_profile: UncheckedAccount<'info>,
+
+    pub unimake_program: Program<'info, UnimakeBackend>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ReviewTreasurySubmission<'info> {
+    #[account(
+        mut,
+        seeds = [b"treasury".as_ref(), admin.key().as_ref()],
+        bump = treasury.bump,
+        has_one = admin
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    pub admin: Signer<'info>,
+
+    /// CHECK: Validated by the bounty program
+    #[account(mut)]
+    pub submission: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by the bounty program
+    #[account(mut)]
+    pub content_record: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by the bounty program
+    #[account(mut)]
+    pub bounty_pool: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by the bounty program
+    #[account(mut)]
+    pub contributor_profile: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by the bounty program
+    #[account(mut)]
+    pub category_reputation: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by the bounty program
+    #[account(mut)]
+    pub buyer_profile: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by the bounty program
+    #[account(mut)]
+    pub contributor: UncheckedAccount<'info>,
+
+    pub unimake_program: Program<'info, UnimakeBackend>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CancelTreasuryBounty<'info> {
+    #[account(
+        mut,
+        seeds = [b"treasury".as_ref(), admin.key().as_ref()],
+        bump = treasury.bump,
+        has_one = admin
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    pub admin: Signer<'info>,
+
+    /// CHECK: Validated by the bounty program
+    #[account(mut)]
+    pub bounty_pool: UncheckedAccount<'info>,
+
+    pub unimake_program: Program<'info, UnimakeBackend>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct Withdraw<'info> {
+    #[account(
+        mut,
+        seeds = [b"treasury".as_ref(), admin.key().as_ref()],
+        bump = treasury.bump,
+        has_one = admin
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+#[derive(InitSpace)]
+pub struct Treasury {
+    pub admin: Pubkey,
+    pub bump: u8,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum TreasuryError {
+    #[msg("Treasury would fall below rent exemption")]
+    InsufficientFunds,
+}
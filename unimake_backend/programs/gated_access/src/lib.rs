@@ -0,0 +1,255 @@
+//! Example composing program: a `Gate` admits members who either have enough
+//! TerraTrain reputation or hold a license for a given dataset. Reputation is refreshed
+//! through CPI before it is read, and both checks go through `unimake_backend::interface`.
+
+use anchor_lang::prelude::*;
+use unimake_backend::cpi::accounts::RefreshReputation;
+use unimake_backend::interface;
+use unimake_backend::program::UnimakeBackend;
+
+declare_id!("D43mihcoZJF1jCLWeNn6vWn1E9hTktKtAh2cmtvfGHKu");
+
+#[program]
+pub mod gated_access {
+    use super::*;
+
+    /// Create a gate; `dataset` enables entry by license, `min_reputation` by reputation
+    pub fn create_gate(
+        ctx: Context<CreateGate>,
+        gate_id: [u8; 16],
+        min_reputation: u16,
+        dataset: Option<Pubkey>,
+    ) -> Result<()> {
+        require!(min_reputation <= 1000, GateError::InvalidThreshold);
+
+        let gate = &mut ctx.accounts.gate;
+        gate.gate_id = gate_id;
+        gate.admin = ctx.accounts.admin.key();
+        gate.min_reputation = min_reputation;
+        gate.dataset = dataset;
+        gate.members = 0;
+        gate.bump = ctx.bumps.gate;
+        Ok(())
+    }
+
+    /// Enter on reputation; the score is brought up to date before it is checked
+    pub fn enter_with_reputation(ctx: Context<EnterWithReputation>) -> Result<()> {
+        let cpi_ctx = CpiContext::new(
+            ctx.accounts.unimake_program.to_account_info(),
+            RefreshReputation {
+                contributor_profile: ctx.accounts.contributor_profile.to_account_info(),
+            },
+        );
+        unimake_backend::cpi::refresh_reputation(cpi_ctx)?;
+
+        let now = Clock::get()?.unix_timestamp;
+        let standing = interface::contributor_standing(
+            &ctx.accounts.contributor_profile,
+            &ctx.accounts.member.key(),
+            now,
+        )?;
+        require!(!standing.suspended, GateError::MemberSuspended);
+        require!(
+            standing.reputation_score >= ctx.accounts.gate.min_reputation,
+            GateError::ReputationTooLow
+        );
+
+        admit(
+            &mut ctx.accounts.gate,
+            &mut ctx.accounts.pass,
+            ctx.accounts.member.key(),
+            EntryRoute::Reputation,
+            now,
+            ctx.bumps.pass,
+        )
+    }
+
+    /// Enter by holding a license for the gate's dataset
+    pub fn enter_with_license(ctx: Context<EnterWithLicense>) -> Result<()> {
+        let dataset = ctx.accounts.gate.dataset.ok_or(GateError::NoDataset)?;
+        require!(
+            interface::holds_license(&ctx.accounts.license, &dataset, &ctx.accounts.member.key())?,
+            GateError::NoLicense
+        );
+
+        admit(
+            &mut ctx.accounts.gate,
+            &mut ctx.accounts.pass,
+            ctx.accounts.member.key(),
+            EntryRoute::License,
+            Clock::get()?.unix_timestamp,
+            ctx.bumps.pass,
+        )
+    }
+}
+
+fn admit(
+    gate: &mut Account<Gate>,
+    pass: &mut Account<Pass>,
+    member: Pubkey,
+    route: EntryRoute,
+    now: i64,
+    bump: u8,
+) -> Result<()> {
+    pass.gate = gate.key();
+    pass.member = member;
+    pass.route = route;
+    pass.granted_at = now;
+    pass.bump = bump;
+    gate.members = gate.members.checked_add(1).ok_or(GateError::Overflow)?;
+
+    emit!(MemberAdmitted {
+        gate_id: gate.gate_id,
+        member,
+        route,
+    });
+
+    Ok(())
+}
+
+// ============================================================================
+// ACCOUNT STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+#[instruction(gate_id: [u8; 16])]
+pub struct CreateGate<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + Gate::INIT_SPACE,
+        seeds = [b"gate".as_ref(), gate_id.as_ref()],
+        bump
+    )]
+    pub gate: Account<'info, Gate>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct EnterWithReputation<'info> {
+    #[account(
+        mut,
+        seeds = [b"gate".as_ref(), gate.gate_id.as_ref()],
+        bump = gate.bump
+    )]
+    pub gate: Account<'info, Gate>,
+
+    #[account(
+        init,
+        payer = member,
+        space = 8 + Pass::INIT_SPACE,
+        seeds = [b"pass".as_ref(), gate.key().as_ref(), member.key().as_ref()],
+        bump
+    )]
+    pub pass: Account<'info, Pass>,
+
+    /// CHECK: The member's profile; verified by `interface::contributor_standing`
+    #[account(mut)]
+    pub contributor_profile: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub member: Signer<'info>,
+
+    pub unimake_program: Program<'info, UnimakeBackend>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct EnterWithLicense<'info> {
+    #[account(
+        mut,
+        seeds = [b"gate".as_ref(), gate.gate_id.as_ref()],
+        bump = gate.bump
+    )]
+    pub gate: Account<'info, Gate>,
+
+    #[account(
+        init,
+        payer = member,
+        space = 8 + Pass::INIT_SPACE,
+        seeds = [b"pass".as_ref(), gate.key().as_ref(), member.key().as_ref()],
+        bump
+    )]
+    pub pass: Account<'info, Pass>,
+
+    /// CHECK: The member's license PDA, possibly uncreated; verified by
+    /// `interface::holds_license`
+    pub license: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub member: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+#[derive(InitSpace)]
+pub struct Gate {
+    pub gate_id: [u8; 16],
+    pub admin: Pubkey,
+    pub min_reputation: u16,     // 0-1000, TerraTrain reputation scale
+    pub dataset: Option<Pubkey>, // None = no entry by license
+    pub members: u32,
+    pub bump: u8,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Pass {
+    pub gate: Pubkey,
+    pub member: Pubkey,
+    pub route: EntryRoute,
+    pub granted_at: i64,
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum EntryRoute {
+    Reputation,
+    License,
+}
+
+// ============================================================================
+// EVENTS
+// ============================================================================
+
+#[event]
+pub struct MemberAdmitted {
+    pub gate_id: [u8; 16],
+    pub member: Pubkey,
+    pub route: EntryRoute,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum GateError {
+    #[msg("Reputation threshold must be 0-1000")]
+    InvalidThreshold,
+
+    #[msg("Reputation is below the gate's threshold")]
+    ReputationTooLow,
+
+    #[msg("Member is suspended from TerraTrain")]
+    MemberSuspended,
+
+    #[msg("Gate does not admit by license")]
+    NoDataset,
+
+    #[msg("Member holds no license for the gate's dataset")]
+    NoLicense,
+
+    #[msg("Arithmetic overflow")]
+    Overflow,
+}
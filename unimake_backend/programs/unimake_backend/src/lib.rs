@@ -1,5 +1,20 @@
+// Generated CPI wrappers mirror instruction signatures, which the allow on each
+// instruction doesn't reach
+#![cfg_attr(feature = "cpi", allow(clippy::too_many_arguments))]
+
 use anchor_lang::prelude::*;
-use anchor_lang::system_program::{transfer, Transfer};
+use anchor_lang::system_program::{
+    allocate, assign, create_account, transfer, Allocate, Assign, CreateAccount, Transfer,
+};
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_2022::spl_token_2022::{extension::ExtensionType, state::Mint as MintState};
+use anchor_spl::token_interface::{
+    initialize_mint2, metadata_pointer_initialize, mint_to, non_transferable_mint_initialize,
+    spl_pod::optional_keys::OptionalNonZeroPubkey,
+    spl_token_metadata_interface::state::TokenMetadata, token_metadata_initialize, InitializeMint2,
+    MetadataPointerInitialize, Mint, MintTo, NonTransferableMintInitialize, Token2022,
+    TokenAccount, TokenMetadataInitialize,
+};
 
 declare_id!("CJpjA6x7h3GZZzDnzFggjrV6JG7UVhsX5kCp7N95UDDG");
 
@@ -12,17 +27,15 @@ pub mod unimake_backend {
     // ============================================================================
 
     /// Create a new bounty with reward pool
+    #[allow(clippy::too_many_arguments)]
     pub fn create_bounty(
         ctx: Context<CreateBounty>,
-
         bounty_id: [u8; 16],
         reward_per_video: u64,
         total_pool: u64,
         videos_target: u32,
         task_description: String,
-        min_duration_secs: u32,
-        min_resolution: String,
-        min_fps: u32,
+        requirements: Requirements,
         expires_at: i64,
     ) -> Result<()> {
         require!(total_pool > 0, ErrorCode::InvalidAmount);
@@ -32,12 +45,13 @@ pub mod unimake_backend {
             total_pool >= reward_per_video * videos_target as u64,
             ErrorCode::InsufficientPool
         );
+        requirements.validate()?;
 
-        // Transfer SOL from authority to bounty pool PDA first
+        // Transfer SOL from payer to bounty pool PDA first
         let transfer_ctx = CpiContext::new(
             ctx.accounts.system_program.to_account_info(),
             Transfer {
-                from: ctx.accounts.authority.to_account_info(),
+                from: ctx.accounts.payer.to_account_info(),
                 to: ctx.accounts.bounty_pool.to_account_info(),
             },
         );
@@ -53,14 +67,35 @@ pub mod unimake_backend {
         bounty.remaining_pool = total_pool;
         bounty.videos_target = videos_target;
         bounty.videos_collected = 0;
+        bounty.pending_submissions = 0;
         bounty.status = BountyStatus::Active;
         bounty.created_at = Clock::get()?.unix_timestamp;
         bounty.expires_at = expires_at;
         bounty.requirements = Requirements {
-            min_duration_secs,
-            min_resolution,
-            min_fps,
+            version: Requirements::VERSION,
+            ..requirements
         };
+        bounty.attestation_policy = AttestationPolicy::default();
+        bounty.required_approvals = 0;
+        bounty.reviewers = Vec::new();
+        bounty.allow_content_reuse = false;
+        bounty.min_category_reputation = 0;
+        bounty.submission_bond = 0;
+        bounty.reward_tiers = Vec::new();
+        bounty.bonus_pool = 0;
+        bounty.sponsored_amount = 0;
+        bounty.refund_pool = 0;
+        bounty.authority_refunded = 0;
+        bounty.sponsor_proceeds = 0;
+        bounty.pending_authority = None;
+        bounty.submission_count = 0;
+
+        let buyer = &mut ctx.accounts.buyer_profile;
+        buyer.ensure_initialized(bounty.authority, bounty.created_at, ctx.bumps.buyer_profile);
+        buyer.bounties_created = buyer
+            .bounties_created
+            .checked_add(1)
+            .ok_or(ErrorCode::Overflow)?;
 
         emit!(BountyCreated {
             bounty_id,
@@ -106,9 +141,16 @@ pub mod unimake_backend {
         Ok(())
     }
 
-    /// Complete a bounty
-    pub fn complete_bounty(ctx: Context<UpdateBounty>) -> Result<()> {
+    /// Complete a bounty and return its unspent funds
+    pub fn complete_bounty(ctx: Context<CloseBounty>) -> Result<()> {
         let bounty = &mut ctx.accounts.bounty_pool;
+        require!(
+            bounty.status == BountyStatus::Active || bounty.status == BountyStatus::Paused,
+            ErrorCode::InvalidStatus
+        );
+
+        // Sponsors' shares stay in escrow until they claim them
+        close_bounty_funds(bounty, &ctx.accounts.authority.to_account_info())?;
         bounty.status = BountyStatus::Completed;
 
         emit!(BountyStatusChanged {
@@ -120,31 +162,16 @@ pub mod unimake_backend {
     }
 
     /// Cancel bounty and return remaining funds
-    pub fn cancel_bounty(ctx: Context<CancelBounty>) -> Result<()> {
-        let bounty = &ctx.accounts.bounty_pool;
+    pub fn cancel_bounty(ctx: Context<CloseBounty>) -> Result<()> {
+        let bounty = &mut ctx.accounts.bounty_pool;
         require!(
             bounty.status == BountyStatus::Active || bounty.status == BountyStatus::Paused,
             ErrorCode::InvalidStatus
         );
 
-        let remaining = bounty.remaining_pool;
-
-        // Transfer remaining pool back to authority
-        // Can't use system program transfer from PDA with data, must manipulate lamports directly
-        **ctx
-            .accounts
-            .bounty_pool
-            .to_account_info()
-            .try_borrow_mut_lamports()? -= remaining;
-        **ctx
-            .accounts
-            .authority
-            .to_account_info()
-            .try_borrow_mut_lamports()? += remaining;
-
-        let bounty = &mut ctx.accounts.bounty_pool;
+        // Sponsors' shares stay in escrow until they claim them
+        close_bounty_funds(bounty, &ctx.accounts.authority.to_account_info())?;
         bounty.status = BountyStatus::Cancelled;
-        bounty.remaining_pool = 0;
 
         emit!(BountyStatusChanged {
             bounty_id: bounty.bounty_id,
@@ -154,358 +181,2477 @@ pub mod unimake_backend {
         Ok(())
     }
 
-    // ============================================================================
-    // ESCROW PROGRAM INSTRUCTIONS
-    // ============================================================================
-
-    /// Submit a video for review (creates escrow)
-    pub fn submit_video(
-        ctx: Context<SubmitVideo>,
-        submission_id: [u8; 16],
-        ipfs_hash: String,
-        arweave_tx: String,
-        metadata_uri: String,
-    ) -> Result<()> {
+    /// Close a bounty past its expiry and refund its funds (permissionless)
+    pub fn close_expired_bounty(ctx: Context<CloseExpiredBounty>) -> Result<()> {
         let bounty = &mut ctx.accounts.bounty_pool;
-
-        require!(
-            bounty.status == BountyStatus::Active,
-            ErrorCode::BountyNotActive
-        );
-        require!(
-            bounty.videos_collected < bounty.videos_target,
-            ErrorCode::BountyFull
-        );
         require!(
-            bounty.remaining_pool >= bounty.reward_per_video,
-            ErrorCode::InsufficientPool
+            bounty.status == BountyStatus::Active || bounty.status == BountyStatus::Paused,
+            ErrorCode::InvalidStatus
         );
         require!(
-            Clock::get()?.unix_timestamp < bounty.expires_at,
-            ErrorCode::BountyExpired
+            Clock::get()?.unix_timestamp >= bounty.expires_at,
+            ErrorCode::BountyNotExpired
         );
 
-        let submission = &mut ctx.accounts.submission;
-        submission.submission_id = submission_id;
-        submission.contributor = ctx.accounts.contributor.key();
-        submission.bounty_id = bounty.bounty_id;
-        submission.ipfs_hash = ipfs_hash;
-        submission.arweave_tx = arweave_tx;
-        submission.metadata_uri = metadata_uri;
-        submission.submission_timestamp = Clock::get()?.unix_timestamp;
-        submission.status = SubmissionStatus::Pending;
-        submission.escrow_amount = bounty.reward_per_video;
-        submission.quality_score = 0;
-        submission.bump = ctx.bumps.submission;
-
-        // Reserve funds in the bounty pool
-        bounty.remaining_pool = bounty
-            .remaining_pool
-            .checked_sub(bounty.reward_per_video)
-            .ok_or(ErrorCode::InsufficientPool)?;
+        close_bounty_funds(bounty, &ctx.accounts.authority)?;
+        bounty.status = BountyStatus::Expired;
 
-        emit!(VideoSubmitted {
-            submission_id: submission.submission_id,
+        emit!(BountyStatusChanged {
             bounty_id: bounty.bounty_id,
-            contributor: submission.contributor,
-            escrow_amount: submission.escrow_amount,
+            new_status: BountyStatus::Expired,
         });
 
         Ok(())
     }
 
-    /// Approve a submission and release payment
-    pub fn approve_submission(ctx: Context<ReviewSubmission>, quality_score: u8) -> Result<()> {
-        let submission = &mut ctx.accounts.submission;
-        require!(
-            submission.status == SubmissionStatus::Pending,
-            ErrorCode::InvalidStatus
+    /// Co-fund an open bounty; the deposit is recorded per sponsor for refunds and
+    /// dataset rights
+    pub fn contribute_to_bounty(ctx: Context<ContributeToBounty>, amount: u64) -> Result<()> {
+        require!(amount > 0, ErrorCode::InvalidAmount);
+        let now = Clock::get()?.unix_timestamp;
+        {
+            let bounty = &ctx.accounts.bounty_pool;
+            require!(
+                bounty.status == BountyStatus::Active || bounty.status == BountyStatus::Paused,
+                ErrorCode::BountyNotActive
+            );
+            require!(now < bounty.expires_at, ErrorCode::BountyExpired);
+        }
+
+        let transfer_ctx = CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.sponsor.to_account_info(),
+                to: ctx.accounts.bounty_pool.to_account_info(),
+            },
         );
+        transfer(transfer_ctx, amount)?;
+
+        let bounty = &mut ctx.accounts.bounty_pool;
+        bounty.total_pool = bounty
+            .total_pool
+            .checked_add(amount)
+            .ok_or(ErrorCode::Overflow)?;
+        bounty.remaining_pool = bounty
+            .remaining_pool
+            .checked_add(amount)
+            .ok_or(ErrorCode::Overflow)?;
+        bounty.sponsored_amount = bounty
+            .sponsored_amount
+            .checked_add(amount)
+            .ok_or(ErrorCode::Overflow)?;
 
-        submission.status = SubmissionStatus::Approved;
-        submission.quality_score = quality_score;
+        let record = &mut ctx.accounts.sponsor_record;
+        if record.sponsor == Pubkey::default() {
+            record.bounty = bounty.key();
+            record.sponsor = ctx.accounts.sponsor.key();
+            record.first_contributed_at = now;
+            record.bump = ctx.bumps.sponsor_record;
+        }
+        record.amount = record
+            .amount
+            .checked_add(amount)
+            .ok_or(ErrorCode::Overflow)?;
+
+        emit!(SponsorContributed {
+            bounty_id: bounty.bounty_id,
+            sponsor: record.sponsor,
+            amount,
+            total_contributed: record.amount,
+            share_bps: record.share_bps(bounty.total_pool),
+        });
+
+        Ok(())
+    }
 
-        let reward = submission.escrow_amount;
+    /// Pay a sponsor their pro-rata share of a closed bounty's unspent pool. Escrow
+    /// returned after the close grows the share, so a sponsor can claim again.
+    pub fn claim_sponsor_refund(ctx: Context<ClaimSponsorRefund>) -> Result<()> {
+        let bounty = &ctx.accounts.bounty_pool;
+        require!(bounty.is_closed(), ErrorCode::InvalidStatus);
+        let record = &mut ctx.accounts.sponsor_record;
+        let refund = bounty
+            .refund_share(record.amount)
+            .saturating_sub(record.refunded);
+        require!(refund > 0, ErrorCode::RefundAlreadyClaimed);
+        record.refunded = record
+            .refunded
+            .checked_add(refund)
+            .ok_or(ErrorCode::Overflow)?;
 
-        // Transfer reward from bounty pool to contributor
-        // Can't use system program transfer from PDA with data, must manipulate lamports directly
         **ctx
             .accounts
             .bounty_pool
             .to_account_info()
-            .try_borrow_mut_lamports()? -= reward;
+            .try_borrow_mut_lamports()? -= refund;
         **ctx
             .accounts
-            .contributor
+            .sponsor
             .to_account_info()
-            .try_borrow_mut_lamports()? += reward;
-
-        // Update counters
-        let bounty = &mut ctx.accounts.bounty_pool;
-        bounty.videos_collected += 1;
-
-        // Update contributor reputation
-        let profile = &mut ctx.accounts.contributor_profile;
-        profile.total_submissions += 1;
-        profile.accepted_submissions += 1;
-        profile.total_earnings = profile
-            .total_earnings
-            .checked_add(reward)
-            .ok_or(ErrorCode::Overflow)?;
-
-        // Recalculate reputation score
-        profile.recalculate_reputation(quality_score);
+            .try_borrow_mut_lamports()? += refund;
 
-        emit!(SubmissionApproved {
-            submission_id: submission.submission_id,
-            contributor: submission.contributor,
-            reward,
-            quality_score,
+        emit!(SponsorRefunded {
+            bounty_id: ctx.accounts.bounty_pool.bounty_id,
+            sponsor: record.sponsor,
+            refund,
         });
 
         Ok(())
     }
 
-    /// Reject a submission and return funds to pool
-    pub fn reject_submission(ctx: Context<ReviewSubmission>) -> Result<()> {
-        let submission = &mut ctx.accounts.submission;
-        require!(
-            submission.status == SubmissionStatus::Pending,
-            ErrorCode::InvalidStatus
-        );
-
-        submission.status = SubmissionStatus::Rejected;
-
-        // Return funds to bounty pool available balance
+    /// Pay the authority its share of escrow returned to a bounty after it closed
+    pub fn claim_authority_refund(ctx: Context<CloseBounty>) -> Result<()> {
         let bounty = &mut ctx.accounts.bounty_pool;
-        bounty.remaining_pool = bounty
-            .remaining_pool
-            .checked_add(submission.escrow_amount)
+        require!(bounty.is_closed(), ErrorCode::InvalidStatus);
+        let refund = bounty
+            .refund_share(bounty.authority_deposit())
+            .saturating_sub(bounty.authority_refunded);
+        require!(refund > 0, ErrorCode::RefundAlreadyClaimed);
+        bounty.authority_refunded = bounty
+            .authority_refunded
+            .checked_add(refund)
             .ok_or(ErrorCode::Overflow)?;
 
-        // Update contributor reputation
-        let profile = &mut ctx.accounts.contributor_profile;
-        profile.total_submissions += 1;
-        profile.rejected_submissions += 1;
-        profile.recalculate_reputation(0);
+        **bounty.to_account_info().try_borrow_mut_lamports()? -= refund;
+        **ctx
+            .accounts
+            .authority
+            .to_account_info()
+            .try_borrow_mut_lamports()? += refund;
 
-        emit!(SubmissionRejected {
-            submission_id: submission.submission_id,
-            contributor: submission.contributor,
+        emit!(AuthorityRefunded {
+            bounty_id: bounty.bounty_id,
+            authority: bounty.authority,
+            refund,
         });
 
         Ok(())
     }
 
-    // ============================================================================
-    // REPUTATION PROGRAM INSTRUCTIONS
-    // ============================================================================
+    /// Pay a sponsor their pro-rata share of the sales of datasets built from the bounty
+    pub fn claim_sponsor_proceeds(ctx: Context<ClaimSponsorRefund>) -> Result<()> {
+        let bounty = &ctx.accounts.bounty_pool;
+        let record = &mut ctx.accounts.sponsor_record;
+        let proceeds = bounty
+            .proceeds_share(record.amount)
+            .saturating_sub(record.proceeds_claimed);
+        require!(proceeds > 0, ErrorCode::RefundAlreadyClaimed);
+        record.proceeds_claimed = record
+            .proceeds_claimed
+            .checked_add(proceeds)
+            .ok_or(ErrorCode::Overflow)?;
 
-    /// Initialize a contributor profile
-    pub fn initialize_profile(ctx: Context<InitializeProfile>) -> Result<()> {
-        let profile = &mut ctx.accounts.contributor_profile;
-        profile.wallet = ctx.accounts.contributor.key();
-        profile.total_submissions = 0;
-        profile.accepted_submissions = 0;
-        profile.rejected_submissions = 0;
-        profile.average_quality_score = 0;
-        profile.total_earnings = 0;
-        profile.reputation_score = 500; // Start at neutral 500
-        profile.join_date = Clock::get()?.unix_timestamp;
-        profile.last_active = Clock::get()?.unix_timestamp;
-        profile.bump = ctx.bumps.contributor_profile;
+        **ctx
+            .accounts
+            .bounty_pool
+            .to_account_info()
+            .try_borrow_mut_lamports()? -= proceeds;
+        **ctx
+            .accounts
+            .sponsor
+            .to_account_info()
+            .try_borrow_mut_lamports()? += proceeds;
 
-        emit!(ProfileCreated {
-            wallet: profile.wallet,
-            reputation_score: profile.reputation_score,
+        emit!(SponsorProceedsClaimed {
+            bounty_id: ctx.accounts.bounty_pool.bounty_id,
+            sponsor: record.sponsor,
+            proceeds,
         });
 
         Ok(())
     }
 
-    /// Award a badge to a contributor
-    pub fn award_badge(ctx: Context<AwardBadge>, badge_type: BadgeType) -> Result<()> {
-        let profile = &mut ctx.accounts.contributor_profile;
+    /// Opt a bounty into oracle auto-approve/auto-reject
+    pub fn set_attestation_policy(
+        ctx: Context<UpdateBounty>,
+        policy: AttestationPolicy,
+    ) -> Result<()> {
+        policy.validate()?;
 
-        // Check if badge already exists
-        for badge in &profile.badges {
-            require!(
-                badge.badge_type != badge_type,
-                ErrorCode::BadgeAlreadyEarned
-            );
-        }
+        let bounty = &mut ctx.accounts.bounty_pool;
+        bounty.attestation_policy = policy.clone();
 
-        require!(profile.badges.len() < 10, ErrorCode::TooManyBadges);
+        emit!(AttestationPolicyUpdated {
+            bounty_id: bounty.bounty_id,
+            policy,
+        });
 
-        let badge = Badge {
-            badge_type: badge_type.clone(),
-            earned_at: Clock::get()?.unix_timestamp,
-        };
+        Ok(())
+    }
 
-        profile.badges.push(badge);
+    /// Require contributors to post a refundable bond with each submission
+    pub fn set_submission_bond(ctx: Context<UpdateBounty>, bond: u64) -> Result<()> {
+        let bounty = &mut ctx.accounts.bounty_pool;
+        bounty.submission_bond = bond;
 
-        emit!(BadgeAwarded {
-            wallet: profile.wallet,
-            badge_type,
+        emit!(SubmissionBondUpdated {
+            bounty_id: bounty.bounty_id,
+            bond,
         });
 
         Ok(())
     }
 
-    // ============================================================================
-    // NFT/DATASET PROGRAM INSTRUCTIONS
-    // ============================================================================
-
-    /// Create a dataset NFT from approved submissions
-    pub fn create_dataset(
-        ctx: Context<CreateDataset>,
-        dataset_id: [u8; 16],
-        license_type: LicenseType,
-        price: u64,
-        royalty_percentage: u8,
-    ) -> Result<()> {
-        require!(royalty_percentage <= 100, ErrorCode::InvalidRoyalty);
+    /// Scale payouts by quality score; an empty table pays the flat reward
+    pub fn set_reward_tiers(ctx: Context<UpdateBounty>, tiers: Vec<RewardTier>) -> Result<()> {
+        RewardTier::validate_table(&tiers)?;
 
-        let dataset = &mut ctx.accounts.dataset_nft;
-        dataset.dataset_id = dataset_id;
-        dataset.license_type = license_type;
-        dataset.creator = ctx.accounts.creator.key();
-        dataset.price = price;
-        dataset.royalty_percentage = royalty_percentage;
-        dataset.created_at = Clock::get()?.unix_timestamp;
-        dataset.total_sales = 0;
-        dataset.bump = ctx.bumps.dataset_nft;
+        let bounty = &mut ctx.accounts.bounty_pool;
+        bounty.reward_tiers = tiers.clone();
 
-        emit!(DatasetCreated {
-            dataset_id: dataset.dataset_id,
-            creator: dataset.creator,
-            price,
+        emit!(RewardTiersUpdated {
+            bounty_id: bounty.bounty_id,
+            tiers,
         });
 
         Ok(())
     }
 
-    /// Purchase a dataset NFT
-    pub fn purchase_dataset(ctx: Context<PurchaseDataset>) -> Result<()> {
-        let dataset = &ctx.accounts.dataset_nft;
-        let price = dataset.price;
+    /// Deposit lamports that fund tier payouts above the base reward
+    pub fn fund_bonus_pool(ctx: Context<FundBonusPool>, amount: u64) -> Result<()> {
+        require!(amount > 0, ErrorCode::InvalidAmount);
 
-        // Transfer payment from buyer to creator
         let transfer_ctx = CpiContext::new(
             ctx.accounts.system_program.to_account_info(),
             Transfer {
-                from: ctx.accounts.buyer.to_account_info(),
-                to: ctx.accounts.creator.to_account_info(),
+                from: ctx.accounts.payer.to_account_info(),
+                to: ctx.accounts.bounty_pool.to_account_info(),
             },
         );
-        transfer(transfer_ctx, price)?;
+        transfer(transfer_ctx, amount)?;
 
-        let dataset = &mut ctx.accounts.dataset_nft;
-        dataset.total_sales += 1;
+        let bounty = &mut ctx.accounts.bounty_pool;
+        bounty.bonus_pool = bounty
+            .bonus_pool
+            .checked_add(amount)
+            .ok_or(ErrorCode::Overflow)?;
 
-        emit!(DatasetPurchased {
-            dataset_id: dataset.dataset_id.clone(),
-            buyer: ctx.accounts.buyer.key(),
-            price,
+        emit!(BonusPoolFunded {
+            bounty_id: bounty.bounty_id,
+            amount,
+            bonus_pool: bounty.bonus_pool,
         });
 
         Ok(())
     }
-}
 
-// ============================================================================
-// ACCOUNT STRUCTURES
-// ============================================================================
+    /// Require a quorum of reviewers instead of the authority's single call
+    pub fn set_review_panel(
+        ctx: Context<UpdateBounty>,
+        reviewers: Vec<Pubkey>,
+        required_approvals: u8,
+    ) -> Result<()> {
+        require!(
+            reviewers.len() <= BountyPool::MAX_REVIEWERS
+                && required_approvals as usize <= reviewers.len(),
+            ErrorCode::InvalidReviewPanel
+        );
+        for (i, reviewer) in reviewers.iter().enumerate() {
+            require!(
+                !reviewers[..i].contains(reviewer),
+                ErrorCode::InvalidReviewPanel
+            );
+        }
 
-#[derive(Accounts)]
-#[instruction(bounty_id: [u8; 16])]
-pub struct CreateBounty<'info> {
-    #[account(
-        init,
-        payer = authority,
-        space = 8 + BountyPool::INIT_SPACE,
-        seeds = [b"bounty".as_ref(), bounty_id.as_ref()],
-        bump
-    )]
-    pub bounty_pool: Account<'info, BountyPool>,
+        // Votes in flight were cast against the current panel and quorum
+        let bounty = &mut ctx.accounts.bounty_pool;
+        require!(
+            bounty.pending_submissions == 0,
+            ErrorCode::SubmissionsPending
+        );
+        bounty.reviewers = reviewers.clone();
+        bounty.required_approvals = required_approvals;
 
-    #[account(mut)]
-    pub authority: Signer<'info>,
+        emit!(ReviewPanelUpdated {
+            bounty_id: bounty.bounty_id,
+            reviewers,
+            required_approvals,
+        });
 
-    pub system_program: Program<'info, System>,
-}
+        Ok(())
+    }
 
-#[derive(Accounts)]
-pub struct UpdateBounty<'info> {
-    #[account(
-        mut,
-        seeds = [b"bounty".as_ref(), bounty_pool.bounty_id.as_ref()],
-        bump,
-        has_one = authority
-    )]
-    pub bounty_pool: Account<'info, BountyPool>,
+    /// Allow content already submitted to other bounties to be submitted here
+    pub fn set_content_reuse(ctx: Context<UpdateBounty>, allow: bool) -> Result<()> {
+        let bounty = &mut ctx.accounts.bounty_pool;
+        bounty.allow_content_reuse = allow;
 
-    pub authority: Signer<'info>,
-}
+        emit!(ContentReuseUpdated {
+            bounty_id: bounty.bounty_id,
+            allow,
+        });
 
-#[derive(Accounts)]
-pub struct CancelBounty<'info> {
-    #[account(
-        mut,
+        Ok(())
+    }
+
+    /// Only accept contributors whose reputation in this bounty's category is high enough
+    pub fn set_reputation_gate(
+        ctx: Context<UpdateBounty>,
+        min_category_reputation: u16,
+    ) -> Result<()> {
+        require!(
+            min_category_reputation <= 1000,
+            ErrorCode::InvalidReputationGate
+        );
+
+        let bounty = &mut ctx.accounts.bounty_pool;
+        bounty.min_category_reputation = min_category_reputation;
+
+        emit!(ReputationGateUpdated {
+            bounty_id: bounty.bounty_id,
+            min_category_reputation,
+        });
+
+        Ok(())
+    }
+
+    /// Start a two-step handover of the bounty to a new authority
+    pub fn propose_bounty_authority(
+        ctx: Context<UpdateBounty>,
+        new_authority: Pubkey,
+    ) -> Result<()> {
+        let bounty = &mut ctx.accounts.bounty_pool;
+        require!(
+            new_authority != bounty.authority && new_authority != Pubkey::default(),
+            ErrorCode::InvalidTransferTarget
+        );
+        bounty.pending_authority = Some(new_authority);
+
+        emit!(BountyAuthorityTransferProposed {
+            bounty_id: bounty.bounty_id,
+            authority: bounty.authority,
+            proposed: new_authority,
+        });
+
+        Ok(())
+    }
+
+    /// Withdraw a pending authority handover
+    pub fn cancel_bounty_authority_transfer(ctx: Context<UpdateBounty>) -> Result<()> {
+        let bounty = &mut ctx.accounts.bounty_pool;
+        let proposed = bounty
+            .pending_authority
+            .take()
+            .ok_or(ErrorCode::NoPendingTransfer)?;
+
+        emit!(BountyAuthorityTransferCancelled {
+            bounty_id: bounty.bounty_id,
+            proposed,
+        });
+
+        Ok(())
+    }
+
+    /// Complete a handover; signed by the proposed authority
+    pub fn accept_bounty_authority(ctx: Context<AcceptBountyAuthority>) -> Result<()> {
+        let bounty = &mut ctx.accounts.bounty_pool;
+        let previous = bounty.authority;
+        bounty.authority = ctx.accounts.new_authority.key();
+        bounty.pending_authority = None;
+
+        // Later reviews are recorded against the new authority's buyer profile
+        ctx.accounts.buyer_profile.ensure_initialized(
+            bounty.authority,
+            Clock::get()?.unix_timestamp,
+            ctx.bumps.buyer_profile,
+        );
+
+        emit!(BountyAuthorityTransferred {
+            bounty_id: bounty.bounty_id,
+            previous,
+            authority: bounty.authority,
+        });
+
+        Ok(())
+    }
+
+    // ============================================================================
+    // ESCROW PROGRAM INSTRUCTIONS
+    // ============================================================================
+
+    /// Submit a video for review (creates escrow)
+    pub fn submit_video(
+        ctx: Context<SubmitVideo>,
+        submission_id: [u8; 16],
+        content_hash: [u8; 32],
+        ipfs_hash: String,
+        arweave_tx: String,
+        metadata_uri: String,
+        spec: VideoSpec,
+    ) -> Result<()> {
+        let bounty = &mut ctx.accounts.bounty_pool;
+
+        require!(
+            bounty.status == BountyStatus::Active,
+            ErrorCode::BountyNotActive
+        );
+        // Pending submissions hold their slot, so sponsor deposits can't escrow more
+        // submissions than the bounty can approve
+        require!(bounty.has_open_slot(), ErrorCode::BountyFull);
+        require!(
+            bounty.remaining_pool >= bounty.reward_per_video,
+            ErrorCode::InsufficientPool
+        );
+        let now = Clock::get()?.unix_timestamp;
+        require!(now < bounty.expires_at, ErrorCode::BountyExpired);
+        bounty.requirements.check(&spec)?;
+
+        let category_rep = &mut ctx.accounts.category_reputation;
+        category_rep.ensure_initialized(
+            ctx.accounts.contributor.key(),
+            &bounty.requirements.category,
+            ctx.bumps.category_reputation,
+        );
+        category_rep.refresh_reputation(now);
+        require!(
+            category_rep.reputation_score >= bounty.min_category_reputation,
+            ErrorCode::ReputationTooLow
+        );
+
+        // Profiles are created on first submission so review never hits a missing account
+        let profile = &mut ctx.accounts.contributor_profile;
+        if profile.wallet == Pubkey::default() {
+            profile.initialize(
+                ctx.accounts.contributor.key(),
+                now,
+                ctx.bumps.contributor_profile,
+            );
+
+            emit!(ProfileCreated {
+                wallet: profile.wallet,
+                reputation_score: profile.reputation_score,
+            });
+        }
+        require!(!profile.is_suspended(now), ErrorCode::ContributorSuspended);
+        profile.total_submissions = profile
+            .total_submissions
+            .checked_add(1)
+            .ok_or(ErrorCode::Overflow)?;
+        profile.pending_submissions = profile
+            .pending_submissions
+            .checked_add(1)
+            .ok_or(ErrorCode::Overflow)?;
+        profile.last_active = now;
+
+        // Reject content already used in this bounty, or elsewhere unless reuse is allowed
+        let contributor = ctx.accounts.contributor.key();
+        let bounty_content = &mut ctx.accounts.bounty_content;
+        require!(bounty_content.use_count == 0, ErrorCode::DuplicateContent);
+        bounty_content.record_use(content_hash, contributor, false, ctx.bumps.bounty_content);
+
+        let content_record = &mut ctx.accounts.content_record;
+        if content_record.use_count > 0 {
+            require!(
+                bounty.allow_content_reuse
+                    && !content_record.exclusive
+                    && content_record.contributor == contributor,
+                ErrorCode::DuplicateContent
+            );
+        }
+        content_record.record_use(
+            content_hash,
+            contributor,
+            !bounty.allow_content_reuse,
+            ctx.bumps.content_record,
+        );
+
+        let submission = &mut ctx.accounts.submission;
+        submission.submission_id = submission_id;
+        submission.sequence = bounty.submission_count;
+        submission.contributor = ctx.accounts.contributor.key();
+        submission.bounty_id = bounty.bounty_id;
+        submission.content_hash = content_hash;
+        submission.ipfs_hash = ipfs_hash;
+        submission.arweave_tx = arweave_tx;
+        submission.metadata_uri = metadata_uri;
+        submission.spec = spec;
+        submission.submission_timestamp = now;
+        submission.status = SubmissionStatus::Pending;
+        submission.escrow_amount = bounty.reward_per_video;
+        submission.quality_score = 0;
+        submission.approvals = 0;
+        submission.rejections = 0;
+        submission.review_scores = Vec::new();
+        submission.bond_amount = bounty.submission_bond;
+        submission.rejection_reason = None;
+        submission.feedback_uri = String::new();
+        submission.bump = ctx.bumps.submission;
+
+        // Hold the contributor's bond in the submission account until review
+        if submission.bond_amount > 0 {
+            let transfer_ctx = CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.contributor.to_account_info(),
+                    to: submission.to_account_info(),
+                },
+            );
+            transfer(transfer_ctx, submission.bond_amount)?;
+        }
+
+        // Reserve funds in the bounty pool
+        bounty.remaining_pool = bounty
+            .remaining_pool
+            .checked_sub(bounty.reward_per_video)
+            .ok_or(ErrorCode::InsufficientPool)?;
+        bounty.submission_count = bounty
+            .submission_count
+            .checked_add(1)
+            .ok_or(ErrorCode::Overflow)?;
+        bounty.pending_submissions += 1;
+
+        emit!(VideoSubmitted {
+            submission_id: submission.submission_id,
+            bounty_id: bounty.bounty_id,
+            sequence: submission.sequence,
+            contributor: submission.contributor,
+            content_hash,
+            escrow_amount: submission.escrow_amount,
+            bond_amount: submission.bond_amount,
+        });
+
+        Ok(())
+    }
+
+    /// Approve a submission and release payment
+    pub fn approve_submission(ctx: Context<ReviewSubmission>, quality_score: u8) -> Result<()> {
+        require!(
+            ctx.accounts.bounty_pool.required_approvals == 0,
+            ErrorCode::ConsensusRequired
+        );
+        settle_approval(
+            &mut ctx.accounts.bounty_pool,
+            &mut ctx.accounts.submission,
+            &mut ctx.accounts.contributor_profile,
+            &mut ctx.accounts.category_reputation,
+            &mut ctx.accounts.buyer_profile,
+            &ctx.accounts.contributor,
+            quality_score,
+        )
+    }
+
+    /// Reject a submission and return funds to pool
+    pub fn reject_submission(
+        ctx: Context<ReviewSubmission>,
+        reason: RejectionReason,
+        feedback_uri: String,
+    ) -> Result<()> {
+        require!(
+            ctx.accounts.bounty_pool.required_approvals == 0,
+            ErrorCode::ConsensusRequired
+        );
+        require!(
+            feedback_uri.len() <= VideoSubmission::MAX_FEEDBACK_URI_LEN,
+            ErrorCode::FeedbackTooLong
+        );
+        ctx.accounts.submission.feedback_uri = feedback_uri;
+
+        // Spam and fraud forfeit the contributor's bond to the bounty authority
+        let bond_recipient = if reason.forfeits_bond() {
+            ctx.accounts.authority.to_account_info()
+        } else {
+            ctx.accounts.contributor.clone()
+        };
+        settle_rejection(
+            &mut ctx.accounts.bounty_pool,
+            &mut ctx.accounts.submission,
+            &mut ctx.accounts.contributor_profile,
+            &mut ctx.accounts.category_reputation,
+            &mut ctx.accounts.buyer_profile,
+            &mut ctx.accounts.content_record,
+            &bond_recipient,
+            reason,
+        )
+    }
+
+    /// Record a panel reviewer's vote and finalize once quorum is reached
+    pub fn cast_review_vote(
+        ctx: Context<CastReviewVote>,
+        approve: bool,
+        quality_score: u8,
+    ) -> Result<()> {
+        require!(quality_score <= 100, ErrorCode::InvalidQualityScore);
+
+        let bounty = &ctx.accounts.bounty_pool;
+        require!(
+            bounty.required_approvals > 0,
+            ErrorCode::ConsensusNotConfigured
+        );
+        require!(
+            bounty.reviewers.contains(&ctx.accounts.reviewer.key()),
+            ErrorCode::NotAReviewer
+        );
+        let required = bounty.required_approvals;
+
+        let submission = &mut ctx.accounts.submission;
+        require!(
+            submission.status == SubmissionStatus::Pending,
+            ErrorCode::InvalidStatus
+        );
+        require!(
+            submission.review_scores.len() < BountyPool::MAX_REVIEWERS,
+            ErrorCode::InvalidReviewPanel
+        );
+        if approve {
+            submission.approvals += 1;
+        } else {
+            submission.rejections += 1;
+        }
+        submission.review_scores.push(quality_score);
+
+        let vote = &mut ctx.accounts.review_vote;
+        vote.submission = submission.key();
+        vote.reviewer = ctx.accounts.reviewer.key();
+        vote.approve = approve;
+        vote.quality_score = quality_score;
+        vote.voted_at = Clock::get()?.unix_timestamp;
+        vote.bump = ctx.bumps.review_vote;
+
+        emit!(ReviewVoteCast {
+            submission_id: submission.submission_id,
+            reviewer: vote.reviewer,
+            approve,
+            quality_score,
+            approvals: submission.approvals,
+            rejections: submission.rejections,
+        });
+
+        // Any disagreement parks the escrow until an arbiter resolves it
+        if submission.approvals > 0 && submission.rejections > 0 {
+            submission.status = SubmissionStatus::Disputed;
+
+            emit!(SubmissionDisputed {
+                submission_id: submission.submission_id,
+                approvals: submission.approvals,
+                rejections: submission.rejections,
+            });
+
+            return Ok(());
+        }
+
+        if submission.approvals >= required {
+            let quality_score = median_score(&submission.review_scores);
+            settle_approval(
+                &mut ctx.accounts.bounty_pool,
+                &mut ctx.accounts.submission,
+                &mut ctx.accounts.contributor_profile,
+                &mut ctx.accounts.category_reputation,
+                &mut ctx.accounts.buyer_profile,
+                &ctx.accounts.contributor,
+                quality_score,
+            )?;
+        } else if submission.rejections >= required {
+            settle_rejection(
+                &mut ctx.accounts.bounty_pool,
+                &mut ctx.accounts.submission,
+                &mut ctx.accounts.contributor_profile,
+                &mut ctx.accounts.category_reputation,
+                &mut ctx.accounts.buyer_profile,
+                &mut ctx.accounts.content_record,
+                &ctx.accounts.contributor,
+                RejectionReason::Other,
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Settle a disputed submission (admin acts as arbiter)
+    pub fn resolve_dispute(
+        ctx: Context<ResolveDispute>,
+        approve: bool,
+        quality_score: u8,
+    ) -> Result<()> {
+        require!(quality_score <= 100, ErrorCode::InvalidQualityScore);
+
+        let submission = &mut ctx.accounts.submission;
+        require!(
+            submission.status == SubmissionStatus::Disputed,
+            ErrorCode::InvalidStatus
+        );
+        submission.status = SubmissionStatus::Pending;
+
+        emit!(DisputeResolved {
+            submission_id: submission.submission_id,
+            approved: approve,
+        });
+
+        if approve {
+            // The arbiter sided with the contributor against the buyer's panel
+            let buyer = &mut ctx.accounts.buyer_profile;
+            buyer.disputes_lost = buyer
+                .disputes_lost
+                .checked_add(1)
+                .ok_or(ErrorCode::Overflow)?;
+
+            settle_approval(
+                &mut ctx.accounts.bounty_pool,
+                &mut ctx.accounts.submission,
+                &mut ctx.accounts.contributor_profile,
+                &mut ctx.accounts.category_reputation,
+                &mut ctx.accounts.buyer_profile,
+                &ctx.accounts.contributor,
+                quality_score,
+            )
+        } else {
+            settle_rejection(
+                &mut ctx.accounts.bounty_pool,
+                &mut ctx.accounts.submission,
+                &mut ctx.accounts.contributor_profile,
+                &mut ctx.accounts.category_reputation,
+                &mut ctx.accounts.buyer_profile,
+                &mut ctx.accounts.content_record,
+                &ctx.accounts.contributor,
+                RejectionReason::Other,
+            )
+        }
+    }
+
+    /// Approve or reject many submissions of one bounty in a single instruction.
+    ///
+    /// `remaining_accounts` holds, per decision: submission, contributor, contributor
+    /// profile, category reputation and content record, all writable. With `atomic` set, any invalid
+    /// item fails the whole batch; otherwise it is skipped with `BatchItemSkipped`.
+    pub fn batch_review<'info>(
+        ctx: Context<'_, '_, 'info, 'info, BatchReview<'info>>,
+        decisions: Vec<ReviewDecision>,
+        atomic: bool,
+    ) -> Result<()> {
+        require!(
+            ctx.accounts.bounty_pool.required_approvals == 0,
+            ErrorCode::ConsensusRequired
+        );
+        require!(
+            !decisions.is_empty()
+                && decisions.len() <= MAX_BATCH_REVIEWS
+                && ctx.remaining_accounts.len() == decisions.len() * BATCH_ACCOUNTS_PER_ITEM,
+            ErrorCode::InvalidBatch
+        );
+
+        let authority = ctx.accounts.authority.to_account_info();
+        let (mut approved, mut rejected, mut skipped) = (0u8, 0u8, 0u8);
+
+        let items = ctx
+            .remaining_accounts
+            .chunks(BATCH_ACCOUNTS_PER_ITEM)
+            .zip(decisions);
+        for (index, (accounts, decision)) in items.enumerate() {
+            // Items are loaded one at a time, after earlier items were written back,
+            // so a submission or profile repeated in the batch is seen up to date
+            let mut item = match load_batch_item(&ctx.accounts.bounty_pool, accounts, &decision) {
+                Ok(item) => item,
+                Err(err) if !atomic => {
+                    emit!(BatchItemSkipped {
+                        index: index as u8,
+                        submission: accounts[0].key(),
+                        error_code: batch_error_code(&err),
+                    });
+                    skipped += 1;
+                    continue;
+                }
+                Err(err) => return Err(err),
+            };
+
+            match decision {
+                ReviewDecision::Approve { quality_score } => {
+                    settle_approval(
+                        &mut ctx.accounts.bounty_pool,
+                        &mut item.submission,
+                        &mut item.profile,
+                        &mut item.category_rep,
+                        &mut ctx.accounts.buyer_profile,
+                        item.contributor,
+                        quality_score,
+                    )?;
+                    approved += 1;
+                }
+                ReviewDecision::Reject { reason } => {
+                    let bond_recipient = if reason.forfeits_bond() {
+                        &authority
+                    } else {
+                        item.contributor
+                    };
+                    settle_rejection(
+                        &mut ctx.accounts.bounty_pool,
+                        &mut item.submission,
+                        &mut item.profile,
+                        &mut item.category_rep,
+                        &mut ctx.accounts.buyer_profile,
+                        &mut item.content,
+                        bond_recipient,
+                        reason,
+                    )?;
+                    rejected += 1;
+                }
+            }
+
+            item.submission.exit(&crate::ID)?;
+            item.profile.exit(&crate::ID)?;
+            item.category_rep.exit(&crate::ID)?;
+            item.content.exit(&crate::ID)?;
+        }
+
+        emit!(BatchReviewed {
+            bounty_id: ctx.accounts.bounty_pool.bounty_id,
+            approved,
+            rejected,
+            skipped,
+        });
+
+        Ok(())
+    }
+
+    // ============================================================================
+    // REPUTATION PROGRAM INSTRUCTIONS
+    // ============================================================================
+
+    /// Initialize a contributor profile
+    pub fn initialize_profile(ctx: Context<InitializeProfile>) -> Result<()> {
+        let profile = &mut ctx.accounts.contributor_profile;
+        profile.initialize(
+            ctx.accounts.contributor.key(),
+            Clock::get()?.unix_timestamp,
+            ctx.bumps.contributor_profile,
+        );
+
+        emit!(ProfileCreated {
+            wallet: profile.wallet,
+            reputation_score: profile.reputation_score,
+        });
+
+        Ok(())
+    }
+
+    /// Award a non-milestone badge to a contributor (admin only)
+    pub fn award_badge(ctx: Context<AwardBadge>, badge_type: BadgeType) -> Result<()> {
+        let profile = &mut ctx.accounts.contributor_profile;
+
+        require!(!badge_type.is_milestone(), ErrorCode::MilestoneBadge);
+        require!(
+            !profile.has_badge(&badge_type),
+            ErrorCode::BadgeAlreadyEarned
+        );
+        require!(
+            profile.badges.len() < ContributorProfile::MAX_BADGES,
+            ErrorCode::TooManyBadges
+        );
+
+        let badge = Badge {
+            badge_type: badge_type.clone(),
+            earned_at: Clock::get()?.unix_timestamp,
+            token_minted: false,
+        };
+
+        profile.badges.push(badge);
+
+        emit!(BadgeAwarded {
+            wallet: profile.wallet,
+            badge_type,
+        });
+
+        Ok(())
+    }
+
+    /// Apply inactivity decay to a profile's reputation (permissionless)
+    pub fn refresh_reputation(ctx: Context<RefreshReputation>) -> Result<()> {
+        let profile = &mut ctx.accounts.contributor_profile;
+        profile.refresh_reputation(Clock::get()?.unix_timestamp);
+
+        emit!(ReputationRefreshed {
+            wallet: profile.wallet,
+            reputation_score: profile.reputation_score,
+            reputation_confidence: profile.reputation_confidence,
+        });
+
+        Ok(())
+    }
+
+    /// Create the non-transferable Token-2022 mint for a badge type (admin only)
+    pub fn initialize_badge_mint(
+        ctx: Context<InitializeBadgeMint>,
+        badge_type: BadgeType,
+        uri: String,
+    ) -> Result<()> {
+        let (name, symbol) = badge_type.token_name_and_symbol();
+        let mint = ctx.accounts.badge_mint.to_account_info();
+        let mint_key = mint.key();
+        let token_program = ctx.accounts.token_program.to_account_info();
+        let seed = [badge_type.seed()];
+        let signer_seeds: &[&[&[u8]]] = &[&[b"badge_mint", &seed, &[ctx.bumps.badge_mint]]];
+
+        // Fund the mint for its extensions plus the metadata the token program appends
+        let mint_space = ExtensionType::try_calculate_account_len::<MintState>(&[
+            ExtensionType::NonTransferable,
+            ExtensionType::MetadataPointer,
+        ])?;
+        let metadata = TokenMetadata {
+            update_authority: OptionalNonZeroPubkey(mint_key),
+            mint: mint_key,
+            name: name.to_string(),
+            symbol: symbol.to_string(),
+            uri: uri.clone(),
+            additional_metadata: vec![],
+        };
+        let lamports = Rent::get()?.minimum_balance(mint_space + metadata.tlv_size_of()?);
+
+        create_pda_account(
+            &ctx.accounts.admin.to_account_info(),
+            &mint,
+            &ctx.accounts.system_program.to_account_info(),
+            lamports,
+            mint_space,
+            &token_program.key(),
+            signer_seeds,
+        )?;
+
+        // Extensions must be initialized before the mint itself
+        non_transferable_mint_initialize(CpiContext::new(
+            token_program.clone(),
+            NonTransferableMintInitialize {
+                token_program_id: token_program.clone(),
+                mint: mint.clone(),
+            },
+        ))?;
+        metadata_pointer_initialize(
+            CpiContext::new(
+                token_program.clone(),
+                MetadataPointerInitialize {
+                    token_program_id: token_program.clone(),
+                    mint: mint.clone(),
+                },
+            ),
+            Some(mint_key),
+            Some(mint_key),
+        )?;
+
+        // The mint PDA is its own mint, freeze and metadata authority
+        initialize_mint2(
+            CpiContext::new(
+                token_program.clone(),
+                InitializeMint2 { mint: mint.clone() },
+            ),
+            0,
+            &mint_key,
+            Some(&mint_key),
+        )?;
+        token_metadata_initialize(
+            CpiContext::new_with_signer(
+                token_program.clone(),
+                TokenMetadataInitialize {
+                    program_id: token_program,
+                    metadata: mint.clone(),
+                    update_authority: mint.clone(),
+                    mint_authority: mint.clone(),
+                    mint,
+                },
+                signer_seeds,
+            ),
+            name.to_string(),
+            symbol.to_string(),
+            uri,
+        )?;
+
+        emit!(BadgeMintInitialized {
+            badge_type,
+            mint: mint_key,
+        });
+
+        Ok(())
+    }
+
+    /// Mint a soulbound token for an earned badge to the contributor's wallet
+    pub fn mint_badge_token(ctx: Context<MintBadgeToken>, badge_type: BadgeType) -> Result<()> {
+        let profile = &mut ctx.accounts.contributor_profile;
+        let badge = profile
+            .badges
+            .iter_mut()
+            .find(|badge| badge.badge_type == badge_type)
+            .ok_or(ErrorCode::BadgeNotEarned)?;
+        require!(!badge.token_minted, ErrorCode::BadgeTokenAlreadyMinted);
+        badge.token_minted = true;
+
+        let seed = [badge_type.seed()];
+        let signer_seeds: &[&[&[u8]]] = &[&[b"badge_mint", &seed, &[ctx.bumps.badge_mint]]];
+        mint_to(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                MintTo {
+                    mint: ctx.accounts.badge_mint.to_account_info(),
+                    to: ctx.accounts.contributor_token_account.to_account_info(),
+                    authority: ctx.accounts.badge_mint.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            1,
+        )?;
+
+        emit!(BadgeTokenMinted {
+            wallet: profile.wallet,
+            badge_type,
+            mint: ctx.accounts.badge_mint.key(),
+        });
+
+        Ok(())
+    }
+
+    /// Register a new badge definition (admin only)
+    pub fn create_badge_definition(
+        ctx: Context<CreateBadgeDefinition>,
+        badge_id: u8,
+        name: String,
+        category: Option<String>,
+        criteria: String,
+        rule: AutoAwardRule,
+    ) -> Result<()> {
+        require!(
+            name.len() <= BadgeDefinition::MAX_NAME_LEN
+                && category
+                    .as_ref()
+                    .is_none_or(|category| category.len() <= BadgeDefinition::MAX_NAME_LEN)
+                && criteria.len() <= BadgeDefinition::MAX_CRITERIA_LEN,
+            ErrorCode::InvalidBadgeDefinition
+        );
+
+        let definition = &mut ctx.accounts.badge_definition;
+        definition.badge_id = badge_id;
+        definition.name = name;
+        definition.category = category;
+        definition.criteria = criteria;
+        definition.rule = rule;
+        definition.created_at = Clock::get()?.unix_timestamp;
+        definition.bump = ctx.bumps.badge_definition;
+
+        emit!(BadgeDefinitionCreated {
+            badge_id,
+            name: definition.name.clone(),
+            category: definition.category.clone(),
+        });
+
+        Ok(())
+    }
+
+    /// Award a defined badge by hand (admin only)
+    pub fn award_defined_badge(ctx: Context<AwardDefinedBadge>) -> Result<()> {
+        let badge_id = ctx.accounts.badge_definition.badge_id;
+        let profile = &mut ctx.accounts.contributor_profile;
+        require!(
+            !profile.has_defined_badge(badge_id),
+            ErrorCode::BadgeAlreadyEarned
+        );
+        profile.set_defined_badge(badge_id);
+
+        emit!(DefinedBadgeAwarded {
+            wallet: profile.wallet,
+            badge_id,
+        });
+
+        Ok(())
+    }
+
+    /// Claim a defined badge whose auto-award rule the profile meets
+    pub fn claim_defined_badge(ctx: Context<ClaimDefinedBadge>) -> Result<()> {
+        let definition = &ctx.accounts.badge_definition;
+        let profile = &mut ctx.accounts.contributor_profile;
+        require!(
+            !profile.has_defined_badge(definition.badge_id),
+            ErrorCode::BadgeAlreadyEarned
+        );
+        require!(
+            definition.rule.is_met_by(profile),
+            ErrorCode::BadgeCriteriaNotMet
+        );
+        profile.set_defined_badge(definition.badge_id);
+
+        emit!(DefinedBadgeAwarded {
+            wallet: profile.wallet,
+            badge_id: definition.badge_id,
+        });
+
+        Ok(())
+    }
+
+    // ============================================================================
+    // ADMIN INSTRUCTIONS
+    // ============================================================================
+
+    /// Initialize the program config (upgrade authority only)
+    pub fn initialize_config(ctx: Context<InitializeConfig>, admin: Pubkey) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+        config.admin = admin;
+        config.bump = ctx.bumps.config;
+
+        emit!(ConfigInitialized { admin });
+
+        Ok(())
+    }
+
+    /// Block a contributor from submitting (admin acts as arbiter).
+    /// A `duration_secs` of 0 bans the contributor until the suspension is lifted.
+    pub fn suspend_contributor(
+        ctx: Context<SuspendContributor>,
+        reason: SuspensionReason,
+        duration_secs: u64,
+    ) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+
+        // Suspending a wallet with no profile yet creates one, so it can't dodge the flag
+        let profile = &mut ctx.accounts.contributor_profile;
+        if profile.wallet == Pubkey::default() {
+            profile.initialize(
+                ctx.accounts.contributor.key(),
+                now,
+                ctx.bumps.contributor_profile,
+            );
+        }
+
+        profile.suspended_until = match duration_secs {
+            0 => i64::MAX,
+            secs => i64::try_from(secs).map_or(i64::MAX, |secs| now.saturating_add(secs)),
+        };
+        profile.suspension_reason = Some(reason.clone());
+
+        emit!(ContributorSuspended {
+            wallet: profile.wallet,
+            reason,
+            suspended_until: profile.suspended_until,
+        });
+
+        Ok(())
+    }
+
+    /// Lift a contributor's suspension or ban early
+    pub fn lift_suspension(ctx: Context<LiftSuspension>) -> Result<()> {
+        let profile = &mut ctx.accounts.contributor_profile;
+        profile.suspended_until = 0;
+        profile.suspension_reason = None;
+
+        emit!(SuspensionLifted {
+            wallet: profile.wallet,
+        });
+
+        Ok(())
+    }
+
+    // ============================================================================
+    // VALIDATOR ORACLE INSTRUCTIONS
+    // ============================================================================
+
+    /// Register a validator oracle and lock its stake (admin approved)
+    pub fn register_validator(ctx: Context<RegisterValidator>, stake: u64) -> Result<()> {
+        require!(stake > 0, ErrorCode::InvalidAmount);
+
+        // Lock stake in the validator PDA
+        let transfer_ctx = CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.validator.to_account_info(),
+                to: ctx.accounts.validator_oracle.to_account_info(),
+            },
+        );
+        transfer(transfer_ctx, stake)?;
+
+        let oracle = &mut ctx.accounts.validator_oracle;
+        oracle.validator = ctx.accounts.validator.key();
+        oracle.stake = stake;
+        oracle.active = true;
+        oracle.attestations_posted = 0;
+        oracle.registered_at = Clock::get()?.unix_timestamp;
+        oracle.bump = ctx.bumps.validator_oracle;
+
+        emit!(ValidatorRegistered {
+            validator: oracle.validator,
+            stake,
+        });
+
+        Ok(())
+    }
+
+    /// Activate or deactivate a validator oracle
+    pub fn set_validator_active(ctx: Context<SetValidatorActive>, active: bool) -> Result<()> {
+        let oracle = &mut ctx.accounts.validator_oracle;
+        oracle.active = active;
+
+        emit!(ValidatorStatusChanged {
+            validator: oracle.validator,
+            active,
+        });
+
+        Ok(())
+    }
+
+    /// Close a validator oracle and return its stake
+    pub fn deregister_validator(ctx: Context<DeregisterValidator>) -> Result<()> {
+        emit!(ValidatorDeregistered {
+            validator: ctx.accounts.validator_oracle.validator,
+            stake: ctx.accounts.validator_oracle.stake,
+        });
+
+        Ok(())
+    }
+
+    /// Post a quality attestation and auto-settle per the bounty's policy
+    pub fn attest_submission(
+        ctx: Context<AttestSubmission>,
+        score: u8,
+        checks_passed: u16,
+        model_version: String,
+    ) -> Result<()> {
+        require!(score <= 100, ErrorCode::InvalidQualityScore);
+        require!(
+            ctx.accounts.submission.status == SubmissionStatus::Pending,
+            ErrorCode::InvalidStatus
+        );
+
+        let oracle = &mut ctx.accounts.validator_oracle;
+        require!(oracle.active, ErrorCode::ValidatorInactive);
+        require!(
+            oracle.stake
+                >= ctx
+                    .accounts
+                    .bounty_pool
+                    .attestation_policy
+                    .min_validator_stake,
+            ErrorCode::InsufficientValidatorStake
+        );
+        oracle.attestations_posted = oracle
+            .attestations_posted
+            .checked_add(1)
+            .ok_or(ErrorCode::Overflow)?;
+
+        let attestation = &mut ctx.accounts.attestation;
+        attestation.submission = ctx.accounts.submission.key();
+        attestation.validator = oracle.validator;
+        attestation.score = score;
+        attestation.checks_passed = checks_passed;
+        attestation.model_version = model_version;
+        attestation.attested_at = Clock::get()?.unix_timestamp;
+        attestation.bump = ctx.bumps.attestation;
+
+        emit!(QualityAttested {
+            submission_id: ctx.accounts.submission.submission_id,
+            validator: attestation.validator,
+            score,
+            checks_passed,
+            model_version: attestation.model_version.clone(),
+        });
+
+        // One validator can't settle around a review panel's quorum; with a panel set the
+        // attestation is advisory
+        if ctx.accounts.bounty_pool.required_approvals > 0 {
+            return Ok(());
+        }
+
+        match ctx.accounts.bounty_pool.attestation_policy.decide(score) {
+            Some(AttestationDecision::Approve) => settle_approval(
+                &mut ctx.accounts.bounty_pool,
+                &mut ctx.accounts.submission,
+                &mut ctx.accounts.contributor_profile,
+                &mut ctx.accounts.category_reputation,
+                &mut ctx.accounts.buyer_profile,
+                &ctx.accounts.contributor,
+                score,
+            ),
+            Some(AttestationDecision::Reject) => settle_rejection(
+                &mut ctx.accounts.bounty_pool,
+                &mut ctx.accounts.submission,
+                &mut ctx.accounts.contributor_profile,
+                &mut ctx.accounts.category_reputation,
+                &mut ctx.accounts.buyer_profile,
+                &mut ctx.accounts.content_record,
+                &ctx.accounts.contributor,
+                RejectionReason::Other,
+            ),
+            None => Ok(()),
+        }
+    }
+
+    // ============================================================================
+    // NFT/DATASET PROGRAM INSTRUCTIONS
+    // ============================================================================
+
+    /// Create a dataset NFT from a completed bounty's approved submissions
+    pub fn create_dataset(
+        ctx: Context<CreateDataset>,
+        dataset_id: [u8; 16],
+        license_type: LicenseType,
+        price: u64,
+        royalty_percentage: u8,
+    ) -> Result<()> {
+        require!(royalty_percentage <= 100, ErrorCode::InvalidRoyalty);
+
+        // The dataset shares its sales with the source bounty's sponsors, in
+        // proportion to their part of the funding
+        let bounty = &ctx.accounts.bounty_pool;
+        require!(
+            bounty.status == BountyStatus::Completed,
+            ErrorCode::InvalidStatus
+        );
+        let sponsor_bps = pro_rata_share(10_000, bounty.sponsored_amount, bounty.total_pool);
+
+        let dataset = &mut ctx.accounts.dataset_nft;
+        dataset.dataset_id = dataset_id;
+        dataset.license_type = license_type;
+        dataset.creator = ctx.accounts.creator.key();
+        dataset.price = price;
+        dataset.royalty_percentage = royalty_percentage;
+        dataset.created_at = Clock::get()?.unix_timestamp;
+        dataset.total_sales = 0;
+        dataset.pending_creator = None;
+        dataset.bounty = bounty.key();
+        dataset.sponsor_bps = sponsor_bps as u16;
+        dataset.bump = ctx.bumps.dataset_nft;
+
+        emit!(DatasetCreated {
+            dataset_id: dataset.dataset_id,
+            creator: dataset.creator,
+            price,
+        });
+
+        Ok(())
+    }
+
+    /// Purchase a dataset NFT
+    pub fn purchase_dataset(ctx: Context<PurchaseDataset>) -> Result<()> {
+        let dataset = &ctx.accounts.dataset_nft;
+        let price = dataset.price;
+        let sponsor_share = pro_rata_share(price, dataset.sponsor_bps as u64, 10_000);
+
+        // The sponsors' share is held in the bounty until each sponsor claims it
+        if sponsor_share > 0 {
+            let bounty = &mut ctx.accounts.bounty_pool;
+            let transfer_ctx = CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.buyer.to_account_info(),
+                    to: bounty.to_account_info(),
+                },
+            );
+            transfer(transfer_ctx, sponsor_share)?;
+            bounty.sponsor_proceeds = bounty
+                .sponsor_proceeds
+                .checked_add(sponsor_share)
+                .ok_or(ErrorCode::Overflow)?;
+        }
+
+        // Transfer the rest of the payment from buyer to creator
+        let transfer_ctx = CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.buyer.to_account_info(),
+                to: ctx.accounts.creator.to_account_info(),
+            },
+        );
+        transfer(transfer_ctx, price - sponsor_share)?;
+
+        let dataset = &mut ctx.accounts.dataset_nft;
+        dataset.total_sales += 1;
+
+        let now = Clock::get()?.unix_timestamp;
+        let license = &mut ctx.accounts.license;
+        if license.holder == Pubkey::default() {
+            license.dataset = dataset.key();
+            license.holder = ctx.accounts.buyer.key();
+            license.first_purchased_at = now;
+            license.bump = ctx.bumps.license;
+        }
+        license.license_type = dataset.license_type.clone();
+        license.purchases = license
+            .purchases
+            .checked_add(1)
+            .ok_or(ErrorCode::Overflow)?;
+        license.total_paid = license
+            .total_paid
+            .checked_add(price)
+            .ok_or(ErrorCode::Overflow)?;
+        license.last_purchased_at = now;
+
+        emit!(DatasetPurchased {
+            dataset_id: dataset.dataset_id,
+            buyer: ctx.accounts.buyer.key(),
+            price,
+            sponsor_share,
+        });
+
+        Ok(())
+    }
+
+    /// Start a two-step handover of the dataset (and its sale proceeds) to a new creator
+    pub fn propose_dataset_creator(ctx: Context<UpdateDataset>, new_creator: Pubkey) -> Result<()> {
+        let dataset = &mut ctx.accounts.dataset_nft;
+        require!(
+            new_creator != dataset.creator && new_creator != Pubkey::default(),
+            ErrorCode::InvalidTransferTarget
+        );
+        dataset.pending_creator = Some(new_creator);
+
+        emit!(DatasetCreatorTransferProposed {
+            dataset_id: dataset.dataset_id,
+            creator: dataset.creator,
+            proposed: new_creator,
+        });
+
+        Ok(())
+    }
+
+    /// Withdraw a pending creator handover
+    pub fn cancel_dataset_creator_transfer(ctx: Context<UpdateDataset>) -> Result<()> {
+        let dataset = &mut ctx.accounts.dataset_nft;
+        let proposed = dataset
+            .pending_creator
+            .take()
+            .ok_or(ErrorCode::NoPendingTransfer)?;
+
+        emit!(DatasetCreatorTransferCancelled {
+            dataset_id: dataset.dataset_id,
+            proposed,
+        });
+
+        Ok(())
+    }
+
+    /// Complete a handover; signed by the proposed creator
+    pub fn accept_dataset_creator(ctx: Context<AcceptDatasetCreator>) -> Result<()> {
+        let dataset = &mut ctx.accounts.dataset_nft;
+        let previous = dataset.creator;
+        dataset.creator = ctx.accounts.new_creator.key();
+        dataset.pending_creator = None;
+
+        emit!(DatasetCreatorTransferred {
+            dataset_id: dataset.dataset_id,
+            previous,
+            creator: dataset.creator,
+        });
+
+        Ok(())
+    }
+}
+
+// ============================================================================
+// CPI INTERFACE
+// ============================================================================
+
+/// Read-only checks for programs that compose with TerraTrain (build against the `cpi`
+/// feature). Each function verifies the account's owner and PDA address before
+/// trusting its data, and never writes to it.
+pub mod interface {
+    use super::*;
+    use anchor_lang::error::ErrorCode as AnchorError;
+
+    /// A contributor's reputation as of their last settlement or `refresh_reputation`
+    #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+    pub struct ContributorStanding {
+        pub reputation_score: u16,
+        pub reputation_confidence: u16,
+        pub accepted_submissions: u32,
+        pub suspended: bool,
+    }
+
+    /// Read `wallet`'s reputation from its `ContributorProfile`
+    pub fn contributor_standing(
+        profile: &AccountInfo,
+        wallet: &Pubkey,
+        now: i64,
+    ) -> Result<ContributorStanding> {
+        expect_address(profile, &[b"profile", wallet.as_ref()])?;
+        let profile: ContributorProfile = load(profile)?;
+        Ok(ContributorStanding {
+            reputation_score: profile.reputation_score,
+            reputation_confidence: profile.reputation_confidence,
+            accepted_submissions: profile.accepted_submissions,
+            suspended: profile.is_suspended(now),
+        })
+    }
+
+    /// Whether `holder` has bought `dataset`. `license` is the `["license", dataset,
+    /// holder]` PDA, which may not exist yet.
+    pub fn holds_license(license: &AccountInfo, dataset: &Pubkey, holder: &Pubkey) -> Result<bool> {
+        expect_address(license, &[b"license", dataset.as_ref(), holder.as_ref()])?;
+        if license.data_is_empty() {
+            return Ok(false);
+        }
+        let license: DatasetLicense = load(license)?;
+        Ok(license.dataset == *dataset && license.holder == *holder && license.purchases > 0)
+    }
+
+    /// Whether a bounty is active, unexpired and has a slot not taken by approved or
+    /// pending submissions
+    pub fn bounty_accepts_submissions(bounty: &AccountInfo, now: i64) -> Result<bool> {
+        let pool: BountyPool = load(bounty)?;
+        expect_address(bounty, &[b"bounty", pool.bounty_id.as_ref()])?;
+        Ok(pool.status == BountyStatus::Active && now < pool.expires_at && pool.has_open_slot())
+    }
+
+    fn expect_address(info: &AccountInfo, seeds: &[&[u8]]) -> Result<()> {
+        let (expected, _) = Pubkey::find_program_address(seeds, &crate::ID);
+        require_keys_eq!(*info.key, expected, AnchorError::ConstraintSeeds);
+        Ok(())
+    }
+
+    fn load<T: AccountDeserialize>(info: &AccountInfo) -> Result<T> {
+        require_keys_eq!(
+            *info.owner,
+            crate::ID,
+            AnchorError::AccountOwnedByWrongProgram
+        );
+        let data = info.try_borrow_data()?;
+        T::try_deserialize(&mut &data[..])
+    }
+}
+
+// ============================================================================
+// SETTLEMENT HELPERS
+// ============================================================================
+
+/// Mark a pending submission approved, release its escrow and update the profile
+fn settle_approval<'info>(
+    bounty: &mut Account<'info, BountyPool>,
+    submission: &mut Account<'info, VideoSubmission>,
+    profile: &mut Account<'info, ContributorProfile>,
+    category_rep: &mut Account<'info, CategoryReputation>,
+    buyer: &mut Account<'info, BuyerProfile>,
+    contributor: &AccountInfo<'info>,
+    quality_score: u8,
+) -> Result<()> {
+    require!(quality_score <= 100, ErrorCode::InvalidQualityScore);
+    require!(
+        submission.status == SubmissionStatus::Pending,
+        ErrorCode::InvalidStatus
+    );
+    submission.status = SubmissionStatus::Approved;
+    submission.quality_score = quality_score;
+
+    // Scale the escrow by the bounty's quality tier. A shortfall goes back to the pool;
+    // a bonus draws on the bonus pool and is capped by what is left in it.
+    let escrow = submission.escrow_amount;
+    let payout_bps = bounty.tier_payout_bps(quality_score);
+    let tier_payout =
+        (escrow as u128 * payout_bps as u128 / RewardTier::BASE_PAYOUT_BPS as u128) as u64;
+    let (reward, bonus, refunded) = if tier_payout >= escrow {
+        let bonus = (tier_payout - escrow).min(bounty.bonus_pool);
+        (escrow + bonus, bonus, 0)
+    } else {
+        (tier_payout, 0, escrow - tier_payout)
+    };
+    bounty.bonus_pool -= bonus;
+    bounty.return_to_pool(refunded)?;
+
+    // Transfer reward from bounty pool to contributor
+    // Can't use system program transfer from PDA with data, must manipulate lamports directly
+    **bounty.to_account_info().try_borrow_mut_lamports()? -= reward;
+    **contributor.try_borrow_mut_lamports()? += reward;
+
+    // Update counters
+    bounty.videos_collected += 1;
+    bounty.pending_submissions = bounty.pending_submissions.saturating_sub(1);
+
+    // Update contributor reputation
+    profile.pending_submissions = profile.pending_submissions.saturating_sub(1);
+    profile.accepted_submissions += 1;
+    profile.total_earnings = profile
+        .total_earnings
+        .checked_add(reward)
+        .ok_or(ErrorCode::Overflow)?;
+
+    // Recalculate reputation score
+    let now = Clock::get()?.unix_timestamp;
+    profile.last_active = now;
+    profile.recalculate_reputation(Some(quality_score));
+    category_rep.record_approval(quality_score, now);
+    buyer.record_review(
+        Some(reward),
+        now.saturating_sub(submission.submission_timestamp),
+    )?;
+
+    release_bond(submission, contributor)?;
+
+    emit!(RewardTierApplied {
+        submission_id: submission.submission_id,
+        quality_score,
+        payout_bps,
+        escrow_amount: escrow,
+        reward,
+        bonus,
+        refunded_to_pool: refunded,
+    });
+
+    emit!(SubmissionApproved {
+        submission_id: submission.submission_id,
+        contributor: submission.contributor,
+        reward,
+        quality_score,
+    });
+
+    for badge_type in profile.award_milestone_badges(now) {
+        emit!(BadgeAwarded {
+            wallet: profile.wallet,
+            badge_type,
+        });
+    }
+
+    Ok(())
+}
+
+/// Mark a pending submission rejected, return its escrow to the pool, release its use of
+/// the content record and pay its bond to `bond_recipient` (the contributor, or the
+/// bounty authority when the bond is forfeited)
+#[allow(clippy::too_many_arguments)]
+fn settle_rejection<'info>(
+    bounty: &mut Account<'info, BountyPool>,
+    submission: &mut Account<'info, VideoSubmission>,
+    profile: &mut Account<'info, ContributorProfile>,
+    category_rep: &mut Account<'info, CategoryReputation>,
+    buyer: &mut Account<'info, BuyerProfile>,
+    content: &mut Account<'info, ContentRecord>,
+    bond_recipient: &AccountInfo<'info>,
+    reason: RejectionReason,
+) -> Result<()> {
+    require!(
+        submission.status == SubmissionStatus::Pending,
+        ErrorCode::InvalidStatus
+    );
+
+    submission.status = SubmissionStatus::Rejected;
+    submission.rejection_reason = Some(reason);
+
+    // Return funds to bounty pool available balance and free the slot
+    bounty.return_to_pool(submission.escrow_amount)?;
+    bounty.pending_submissions = bounty.pending_submissions.saturating_sub(1);
+
+    // Update contributor reputation
+    profile.pending_submissions = profile.pending_submissions.saturating_sub(1);
+    profile.rejected_submissions += 1;
+    profile.rejection_weight = profile
+        .rejection_weight
+        .saturating_add(reason.reputation_weight());
+    let now = Clock::get()?.unix_timestamp;
+    profile.last_active = now;
+    profile.recalculate_reputation(None);
+    category_rep.record_rejection(reason, now);
+    buyer.record_review(None, now.saturating_sub(submission.submission_timestamp))?;
+
+    // Rejected content stops counting as used outside this bounty, so it no longer
+    // blocks an exclusive submission elsewhere. The per-bounty record stays, so it
+    // can't be resubmitted here.
+    content.release_use();
+
+    let bond = release_bond(submission, bond_recipient)?;
+
+    emit!(SubmissionRejected {
+        submission_id: submission.submission_id,
+        contributor: submission.contributor,
+        bond_forfeited: reason.forfeits_bond(),
+        bond,
+        reason,
+        feedback_uri: submission.feedback_uri.clone(),
+    });
+
+    Ok(())
+}
+
+/// Freeze the unspent pool for pro-rata sponsor refunds, then pay the authority its
+/// share of it plus the whole bonus pool, which only the authority funds
+fn close_bounty_funds<'info>(
+    bounty: &mut Account<'info, BountyPool>,
+    authority: &AccountInfo<'info>,
+) -> Result<()> {
+    bounty.refund_pool = bounty.remaining_pool;
+    let authority_share = bounty.refund_share(bounty.authority_deposit());
+    bounty.authority_refunded = authority_share;
+    let authority_refund = authority_share
+        .checked_add(bounty.bonus_pool)
+        .ok_or(ErrorCode::Overflow)?;
+
+    // Can't use system program transfer from PDA with data, must manipulate lamports directly
+    **bounty.to_account_info().try_borrow_mut_lamports()? -= authority_refund;
+    **authority.try_borrow_mut_lamports()? += authority_refund;
+
+    bounty.remaining_pool = 0;
+    bounty.bonus_pool = 0;
+    Ok(())
+}
+
+/// `pool * part / whole`, rounded down; remainders stay in escrow
+fn pro_rata_share(pool: u64, part: u64, whole: u64) -> u64 {
+    if whole == 0 {
+        return 0;
+    }
+    (pool as u128 * part as u128 / whole as u128) as u64
+}
+
+/// Most submissions `batch_review` settles in one instruction. Each item adds five
+/// accounts, so six no longer fit a legacy transaction's 1232-byte packet.
+pub const MAX_BATCH_REVIEWS: usize = 5;
+/// Remaining accounts per batch item: submission, contributor, profile, category
+/// reputation, content record
+pub const BATCH_ACCOUNTS_PER_ITEM: usize = 5;
+
+/// One reviewer decision in a `batch_review`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum ReviewDecision {
+    Approve { quality_score: u8 },
+    Reject { reason: RejectionReason },
+}
+
+/// A batch item's accounts, checked against the bounty in place of Anchor's seed constraints
+struct BatchItem<'info> {
+    submission: Account<'info, VideoSubmission>,
+    profile: Account<'info, ContributorProfile>,
+    category_rep: Account<'info, CategoryReputation>,
+    content: Account<'info, ContentRecord>,
+    contributor: &'info AccountInfo<'info>,
+}
+
+/// Deserialize and validate one batch item without modifying anything, so an invalid
+/// item can be skipped safely
+fn load_batch_item<'info>(
+    bounty: &Account<'info, BountyPool>,
+    accounts: &'info [AccountInfo<'info>],
+    decision: &ReviewDecision,
+) -> Result<BatchItem<'info>> {
+    require!(
+        accounts.iter().all(|account| account.is_writable),
+        ErrorCode::InvalidBatch
+    );
+    if let ReviewDecision::Approve { quality_score } = decision {
+        require!(*quality_score <= 100, ErrorCode::InvalidQualityScore);
+    }
+
+    // Ownership and discriminators are checked by `try_from`; the program's own
+    // fields then tie each account to this bounty and contributor
+    let submission = Account::<VideoSubmission>::try_from(&accounts[0])?;
+    let contributor = &accounts[1];
+    let profile = Account::<ContributorProfile>::try_from(&accounts[2])?;
+    let category_rep = Account::<CategoryReputation>::try_from(&accounts[3])?;
+    let content = Account::<ContentRecord>::try_from(&accounts[4])?;
+
+    // Per-bounty records hold the same hash, so the program-wide one is told apart by
+    // its address
+    let content_address = Pubkey::create_program_address(
+        &[
+            b"content",
+            submission.content_hash.as_ref(),
+            &[content.bump],
+        ],
+        &crate::ID,
+    )
+    .map_err(|_| ErrorCode::SubmissionMismatch)?;
+    require!(
+        submission.bounty_id == bounty.bounty_id
+            && submission.contributor == contributor.key()
+            && profile.wallet == contributor.key()
+            && category_rep.wallet == contributor.key()
+            && category_rep.category == bounty.requirements.category
+            && content.key() == content_address,
+        ErrorCode::SubmissionMismatch
+    );
+    require!(
+        submission.status == SubmissionStatus::Pending,
+        ErrorCode::InvalidStatus
+    );
+    Ok(BatchItem {
+        submission,
+        profile,
+        category_rep,
+        content,
+        contributor,
+    })
+}
+
+/// Custom error number reported for a skipped batch item (0 for non-Anchor errors)
+fn batch_error_code(err: &Error) -> u32 {
+    match err {
+        Error::AnchorError(err) => err.error_code_number,
+        Error::ProgramError(_) => 0,
+    }
+}
+
+/// Pay a submission's bond out of the submission account; returns the amount moved
+fn release_bond<'info>(
+    submission: &mut Account<'info, VideoSubmission>,
+    recipient: &AccountInfo<'info>,
+) -> Result<u64> {
+    let bond = submission.bond_amount;
+    if bond > 0 {
+        **submission.to_account_info().try_borrow_mut_lamports()? -= bond;
+        **recipient.try_borrow_mut_lamports()? += bond;
+        submission.bond_amount = 0;
+    }
+    Ok(bond)
+}
+
+/// Create a PDA owned by `owner`. Lamports anyone sent to the address beforehand would
+/// make `create_account` fail, so a funded address is topped up, allocated and assigned
+/// instead.
+fn create_pda_account<'info>(
+    payer: &AccountInfo<'info>,
+    account: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    lamports: u64,
+    space: usize,
+    owner: &Pubkey,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let current = account.lamports();
+    if current == 0 {
+        return create_account(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                CreateAccount {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+                signer_seeds,
+            ),
+            lamports,
+            space as u64,
+            owner,
+        );
+    }
+
+    if lamports > current {
+        transfer(
+            CpiContext::new(
+                system_program.clone(),
+                Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            lamports - current,
+        )?;
+    }
+    allocate(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            Allocate {
+                account_to_allocate: account.clone(),
+            },
+            signer_seeds,
+        ),
+        space as u64,
+    )?;
+    assign(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            Assign {
+                account_to_assign: account.clone(),
+            },
+            signer_seeds,
+        ),
+        owner,
+    )
+}
+
+/// Median of reviewer scores; averages the two middle values for an even count
+fn median_score(scores: &[u8]) -> u8 {
+    if scores.is_empty() {
+        return 0;
+    }
+    let mut sorted = scores.to_vec();
+    sorted.sort_unstable();
+    let mid = sorted.len() / 2;
+    if sorted.len() % 2 == 0 {
+        ((sorted[mid - 1] as u16 + sorted[mid] as u16) / 2) as u8
+    } else {
+        sorted[mid]
+    }
+}
+
+// ============================================================================
+// ACCOUNT STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+#[instruction(bounty_id: [u8; 16])]
+pub struct CreateBounty<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + BountyPool::INIT_SPACE,
+        seeds = [b"bounty".as_ref(), bounty_id.as_ref()],
+        bump
+    )]
+    pub bounty_pool: Account<'info, BountyPool>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + BuyerProfile::INIT_SPACE,
+        seeds = [b"buyer".as_ref(), authority.key().as_ref()],
+        bump
+    )]
+    pub buyer_profile: Account<'info, BuyerProfile>,
+
+    /// May be a PDA signing through CPI; it never pays
+    pub authority: Signer<'info>,
+
+    /// Funds the pool and rent; may be the authority itself
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateBounty<'info> {
+    #[account(
+        mut,
+        seeds = [b"bounty".as_ref(), bounty_pool.bounty_id.as_ref()],
+        bump,
+        has_one = authority
+    )]
+    pub bounty_pool: Account<'info, BountyPool>,
+
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct AcceptBountyAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [b"bounty".as_ref(), bounty_pool.bounty_id.as_ref()],
+        bump,
+        constraint = bounty_pool.pending_authority == Some(new_authority.key())
+            @ ErrorCode::NotPendingAuthority
+    )]
+    pub bounty_pool: Account<'info, BountyPool>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + BuyerProfile::INIT_SPACE,
+        seeds = [b"buyer".as_ref(), new_authority.key().as_ref()],
+        bump
+    )]
+    pub buyer_profile: Account<'info, BuyerProfile>,
+
+    pub new_authority: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct FundBonusPool<'info> {
+    #[account(
+        mut,
+        seeds = [b"bounty".as_ref(), bounty_pool.bounty_id.as_ref()],
+        bump,
+        has_one = authority
+    )]
+    pub bounty_pool: Account<'info, BountyPool>,
+
+    pub authority: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CloseExpiredBounty<'info> {
+    #[account(
+        mut,
+        seeds = [b"bounty".as_ref(), bounty_pool.bounty_id.as_ref()],
+        bump,
+        has_one = authority
+    )]
+    pub bounty_pool: Account<'info, BountyPool>,
+
+    /// CHECK: Bounty authority receives its refund share; matched by has_one
+    #[account(mut)]
+    pub authority: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ContributeToBounty<'info> {
+    #[account(
+        mut,
+        seeds = [b"bounty".as_ref(), bounty_pool.bounty_id.as_ref()],
+        bump
+    )]
+    pub bounty_pool: Account<'info, BountyPool>,
+
+    #[account(
+        init_if_needed,
+        payer = sponsor,
+        space = 8 + BountySponsor::INIT_SPACE,
+        seeds = [b"sponsor".as_ref(), bounty_pool.key().as_ref(), sponsor.key().as_ref()],
+        bump
+    )]
+    pub sponsor_record: Account<'info, BountySponsor>,
+
+    #[account(mut)]
+    pub sponsor: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimSponsorRefund<'info> {
+    #[account(
+        mut,
+        seeds = [b"bounty".as_ref(), bounty_pool.bounty_id.as_ref()],
+        bump
+    )]
+    pub bounty_pool: Account<'info, BountyPool>,
+
+    #[account(
+        mut,
+        seeds = [b"sponsor".as_ref(), bounty_pool.key().as_ref(), sponsor.key().as_ref()],
+        bump = sponsor_record.bump,
+        has_one = sponsor
+    )]
+    pub sponsor_record: Account<'info, BountySponsor>,
+
+    #[account(mut)]
+    pub sponsor: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CloseBounty<'info> {
+    #[account(
+        mut,
+        seeds = [b"bounty".as_ref(), bounty_pool.bounty_id.as_ref()],
+        bump,
+        has_one = authority
+    )]
+    pub bounty_pool: Account<'info, BountyPool>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(_submission_id: [u8; 16], content_hash: [u8; 32])]
+pub struct SubmitVideo<'info> {
+    #[account(
+        init,
+        payer = contributor,
+        space = 8 + VideoSubmission::INIT_SPACE,
+        seeds = [
+            b"submission".as_ref(),
+            bounty_pool.key().as_ref(),
+            bounty_pool.submission_count.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub submission: Account<'info, VideoSubmission>,
+
+    // Program-wide record of the content hash
+    #[account(
+        init_if_needed,
+        payer = contributor,
+        space = 8 + ContentRecord::INIT_SPACE,
+        seeds = [b"content".as_ref(), content_hash.as_ref()],
+        bump
+    )]
+    pub content_record: Account<'info, ContentRecord>,
+
+    // Per-bounty record, so reuse-enabled bounties still reject duplicates
+    #[account(
+        init_if_needed,
+        payer = contributor,
+        space = 8 + ContentRecord::INIT_SPACE,
+        seeds = [b"content".as_ref(), bounty_pool.key().as_ref(), content_hash.as_ref()],
+        bump
+    )]
+    pub bounty_content: Account<'info, ContentRecord>,
+
+    #[account(
+        mut,
+        seeds = [b"bounty".as_ref(), bounty_pool.bounty_id.as_ref()],
+        bump
+    )]
+    pub bounty_pool: Account<'info, BountyPool>,
+
+    #[account(
+        init_if_needed,
+        payer = contributor,
+        space = 8 + ContributorProfile::INIT_SPACE,
+        seeds = [b"profile", contributor.key().as_ref()],
+        bump
+    )]
+    pub contributor_profile: Account<'info, ContributorProfile>,
+
+    // Created on the contributor's first submission in this category
+    #[account(
+        init_if_needed,
+        payer = contributor,
+        space = 8 + CategoryReputation::INIT_SPACE,
+        seeds = [
+            b"category_rep".as_ref(),
+            contributor.key().as_ref(),
+            bounty_pool.requirements.category.as_bytes()
+        ],
+        bump
+    )]
+    pub category_reputation: Account<'info, CategoryReputation>,
+
+    #[account(mut)]
+    pub contributor: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ReviewSubmission<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"submission".as_ref(),
+            bounty_pool.key().as_ref(),
+            submission.sequence.to_le_bytes().as_ref()
+        ],
+        bump = submission.bump,
+        constraint = submission.contributor == contributor.key() @ ErrorCode::SubmissionMismatch
+    )]
+    pub submission: Account<'info, VideoSubmission>,
+
+    // Program-wide record of the submitted content, released on rejection
+    #[account(
+        mut,
+        seeds = [b"content".as_ref(), submission.content_hash.as_ref()],
+        bump = content_record.bump
+    )]
+    pub content_record: Account<'info, ContentRecord>,
+
+    #[account(
+        mut,
+        seeds = [b"bounty".as_ref(), bounty_pool.bounty_id.as_ref()],
+        bump,
+        has_one = authority
+    )]
+    pub bounty_pool: Account<'info, BountyPool>,
+
+    #[account(
+        mut,
+        seeds = [b"profile", contributor.key().as_ref()],
+        bump = contributor_profile.bump
+    )]
+    pub contributor_profile: Account<'info, ContributorProfile>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"category_rep".as_ref(),
+            contributor.key().as_ref(),
+            bounty_pool.requirements.category.as_bytes()
+        ],
+        bump = category_reputation.bump
+    )]
+    pub category_reputation: Account<'info, CategoryReputation>,
+
+    #[account(
+        mut,
+        seeds = [b"buyer".as_ref(), bounty_pool.authority.as_ref()],
+        bump = buyer_profile.bump
+    )]
+    pub buyer_profile: Account<'info, BuyerProfile>,
+
+    /// CHECK: Contributor receives payment
+    #[account(mut)]
+    pub contributor: AccountInfo<'info>,
+
+    // Receives forfeited submission bonds
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct BatchReview<'info> {
+    #[account(
+        mut,
+        seeds = [b"bounty".as_ref(), bounty_pool.bounty_id.as_ref()],
+        bump,
+        has_one = authority
+    )]
+    pub bounty_pool: Account<'info, BountyPool>,
+
+    #[account(
+        mut,
+        seeds = [b"buyer".as_ref(), bounty_pool.authority.as_ref()],
+        bump = buyer_profile.bump
+    )]
+    pub buyer_profile: Account<'info, BuyerProfile>,
+
+    // Receives forfeited submission bonds
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CastReviewVote<'info> {
+    #[account(
+        init,
+        payer = reviewer,
+        space = 8 + ReviewVote::INIT_SPACE,
+        seeds = [b"vote".as_ref(), submission.key().as_ref(), reviewer.key().as_ref()],
+        bump
+    )]
+    pub review_vote: Account<'info, ReviewVote>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"submission".as_ref(),
+            bounty_pool.key().as_ref(),
+            submission.sequence.to_le_bytes().as_ref()
+        ],
+        bump = submission.bump,
+        constraint = submission.contributor == contributor.key() @ ErrorCode::SubmissionMismatch,
+        constraint = submission.contributor != reviewer.key() @ ErrorCode::SelfReview
+    )]
+    pub submission: Account<'info, VideoSubmission>,
+
+    // Program-wide record of the submitted content, released on rejection
+    #[account(
+        mut,
+        seeds = [b"content".as_ref(), submission.content_hash.as_ref()],
+        bump = content_record.bump
+    )]
+    pub content_record: Account<'info, ContentRecord>,
+
+    #[account(
+        mut,
+        seeds = [b"bounty".as_ref(), bounty_pool.bounty_id.as_ref()],
+        bump
+    )]
+    pub bounty_pool: Account<'info, BountyPool>,
+
+    #[account(
+        mut,
+        seeds = [b"profile", contributor.key().as_ref()],
+        bump = contributor_profile.bump
+    )]
+    pub contributor_profile: Account<'info, ContributorProfile>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"category_rep".as_ref(),
+            contributor.key().as_ref(),
+            bounty_pool.requirements.category.as_bytes()
+        ],
+        bump = category_reputation.bump
+    )]
+    pub category_reputation: Account<'info, CategoryReputation>,
+
+    #[account(
+        mut,
+        seeds = [b"buyer".as_ref(), bounty_pool.authority.as_ref()],
+        bump = buyer_profile.bump
+    )]
+    pub buyer_profile: Account<'info, BuyerProfile>,
+
+    /// CHECK: Contributor receives payment on quorum; matched against submission
+    #[account(mut)]
+    pub contributor: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub reviewer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ResolveDispute<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"submission".as_ref(),
+            bounty_pool.key().as_ref(),
+            submission.sequence.to_le_bytes().as_ref()
+        ],
+        bump = submission.bump,
+        constraint = submission.contributor == contributor.key() @ ErrorCode::SubmissionMismatch
+    )]
+    pub submission: Account<'info, VideoSubmission>,
+
+    // Program-wide record of the submitted content, released on rejection
+    #[account(
+        mut,
+        seeds = [b"content".as_ref(), submission.content_hash.as_ref()],
+        bump = content_record.bump
+    )]
+    pub content_record: Account<'info, ContentRecord>,
+
+    #[account(
+        mut,
         seeds = [b"bounty".as_ref(), bounty_pool.bounty_id.as_ref()],
-        bump,
-        has_one = authority
+        bump
     )]
     pub bounty_pool: Account<'info, BountyPool>,
 
+    #[account(
+        mut,
+        seeds = [b"profile", contributor.key().as_ref()],
+        bump = contributor_profile.bump
+    )]
+    pub contributor_profile: Account<'info, ContributorProfile>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"category_rep".as_ref(),
+            contributor.key().as_ref(),
+            bounty_pool.requirements.category.as_bytes()
+        ],
+        bump = category_reputation.bump
+    )]
+    pub category_reputation: Account<'info, CategoryReputation>,
+
+    #[account(
+        mut,
+        seeds = [b"buyer".as_ref(), bounty_pool.authority.as_ref()],
+        bump = buyer_profile.bump
+    )]
+    pub buyer_profile: Account<'info, BuyerProfile>,
+
+    /// CHECK: Contributor receives payment if the dispute is resolved in their favour
     #[account(mut)]
-    pub authority: Signer<'info>,
+    pub contributor: AccountInfo<'info>,
 
-    pub system_program: Program<'info, System>,
+    #[account(seeds = [b"config".as_ref()], bump = config.bump, has_one = admin)]
+    pub config: Account<'info, ProgramConfig>,
+
+    pub admin: Signer<'info>,
 }
 
 #[derive(Accounts)]
-#[instruction(submission_id: [u8; 16])]
-pub struct SubmitVideo<'info> {
+pub struct InitializeProfile<'info> {
     #[account(
         init,
         payer = contributor,
-        space = 8 + VideoSubmission::INIT_SPACE,
-        seeds = [b"submission".as_ref(), submission_id.as_ref()],
+        space = 8 + ContributorProfile::INIT_SPACE,
+        seeds = [b"profile", contributor.key().as_ref()],
         bump
     )]
-    pub submission: Account<'info, VideoSubmission>,
+    pub contributor_profile: Account<'info, ContributorProfile>,
 
+    #[account(mut)]
+    pub contributor: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RefreshReputation<'info> {
     #[account(
         mut,
-        seeds = [b"bounty".as_ref(), bounty_pool.bounty_id.as_ref()],
+        seeds = [b"profile", contributor_profile.wallet.as_ref()],
+        bump = contributor_profile.bump
+    )]
+    pub contributor_profile: Account<'info, ContributorProfile>,
+}
+
+#[derive(Accounts)]
+pub struct AwardBadge<'info> {
+    #[account(
+        mut,
+        seeds = [b"profile", contributor_profile.wallet.as_ref()],
+        bump = contributor_profile.bump
+    )]
+    pub contributor_profile: Account<'info, ContributorProfile>,
+
+    #[account(seeds = [b"config".as_ref()], bump = config.bump, has_one = admin)]
+    pub config: Account<'info, ProgramConfig>,
+
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(badge_id: u8)]
+pub struct CreateBadgeDefinition<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + BadgeDefinition::INIT_SPACE,
+        seeds = [b"badge_def".as_ref(), &[badge_id]],
         bump
     )]
-    pub bounty_pool: Account<'info, BountyPool>,
+    pub badge_definition: Account<'info, BadgeDefinition>,
+
+    #[account(seeds = [b"config".as_ref()], bump = config.bump, has_one = admin)]
+    pub config: Account<'info, ProgramConfig>,
 
     #[account(mut)]
-    pub contributor: Signer<'info>,
+    pub admin: Signer<'info>,
 
     pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct ReviewSubmission<'info> {
+pub struct AwardDefinedBadge<'info> {
     #[account(
         mut,
-        seeds = [b"submission".as_ref(), submission.submission_id.as_ref()],
-        bump = submission.bump
+        seeds = [b"profile", contributor_profile.wallet.as_ref()],
+        bump = contributor_profile.bump
     )]
-    pub submission: Account<'info, VideoSubmission>,
+    pub contributor_profile: Account<'info, ContributorProfile>,
+
+    #[account(
+        seeds = [b"badge_def".as_ref(), &[badge_definition.badge_id]],
+        bump = badge_definition.bump
+    )]
+    pub badge_definition: Account<'info, BadgeDefinition>,
 
+    #[account(seeds = [b"config".as_ref()], bump = config.bump, has_one = admin)]
+    pub config: Account<'info, ProgramConfig>,
+
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimDefinedBadge<'info> {
     #[account(
         mut,
-        seeds = [b"bounty".as_ref(), bounty_pool.bounty_id.as_ref()],
-        bump,
-        has_one = authority
+        seeds = [b"profile", contributor_profile.wallet.as_ref()],
+        bump = contributor_profile.bump
     )]
-    pub bounty_pool: Account<'info, BountyPool>,
+    pub contributor_profile: Account<'info, ContributorProfile>,
+
+    #[account(
+        seeds = [b"badge_def".as_ref(), &[badge_definition.badge_id]],
+        bump = badge_definition.bump
+    )]
+    pub badge_definition: Account<'info, BadgeDefinition>,
+}
+
+#[derive(Accounts)]
+#[instruction(badge_type: BadgeType)]
+pub struct InitializeBadgeMint<'info> {
+    /// CHECK: Created and initialized as a Token-2022 mint by the instruction
+    #[account(
+        mut,
+        seeds = [b"badge_mint".as_ref(), &[badge_type.seed()]],
+        bump
+    )]
+    pub badge_mint: AccountInfo<'info>,
+
+    #[account(seeds = [b"config".as_ref()], bump = config.bump, has_one = admin)]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
 
+    pub token_program: Program<'info, Token2022>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(badge_type: BadgeType)]
+pub struct MintBadgeToken<'info> {
     #[account(
         mut,
         seeds = [b"profile", contributor.key().as_ref()],
@@ -513,34 +2659,87 @@ pub struct ReviewSubmission<'info> {
     )]
     pub contributor_profile: Account<'info, ContributorProfile>,
 
-    /// CHECK: Contributor receives payment
-    #[account(mut)]
+    #[account(
+        mut,
+        seeds = [b"badge_mint".as_ref(), &[badge_type.seed()]],
+        bump,
+        mint::token_program = token_program
+    )]
+    pub badge_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = badge_mint,
+        associated_token::authority = contributor,
+        associated_token::token_program = token_program
+    )]
+    pub contributor_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: Badge holder, bound to the profile through its seeds
     pub contributor: AccountInfo<'info>,
 
-    pub authority: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub token_program: Program<'info, Token2022>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
 
     pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct InitializeProfile<'info> {
+pub struct InitializeConfig<'info> {
     #[account(
         init,
-        payer = contributor,
+        payer = authority,
+        space = 8 + ProgramConfig::INIT_SPACE,
+        seeds = [b"config".as_ref()],
+        bump
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, crate::program::UnimakeBackend>,
+
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(authority.key())
+            @ ErrorCode::Unauthorized
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SuspendContributor<'info> {
+    #[account(
+        init_if_needed,
+        payer = admin,
         space = 8 + ContributorProfile::INIT_SPACE,
         seeds = [b"profile", contributor.key().as_ref()],
         bump
     )]
     pub contributor_profile: Account<'info, ContributorProfile>,
 
+    /// CHECK: Wallet being suspended; only used to derive the profile
+    pub contributor: AccountInfo<'info>,
+
+    #[account(seeds = [b"config".as_ref()], bump = config.bump, has_one = admin)]
+    pub config: Account<'info, ProgramConfig>,
+
     #[account(mut)]
-    pub contributor: Signer<'info>,
+    pub admin: Signer<'info>,
 
     pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct AwardBadge<'info> {
+pub struct LiftSuspension<'info> {
     #[account(
         mut,
         seeds = [b"profile", contributor_profile.wallet.as_ref()],
@@ -548,8 +2747,142 @@ pub struct AwardBadge<'info> {
     )]
     pub contributor_profile: Account<'info, ContributorProfile>,
 
-    /// CHECK: Admin or automated system
-    pub authority: Signer<'info>,
+    #[account(seeds = [b"config".as_ref()], bump = config.bump, has_one = admin)]
+    pub config: Account<'info, ProgramConfig>,
+
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct RegisterValidator<'info> {
+    #[account(
+        init,
+        payer = validator,
+        space = 8 + ValidatorOracle::INIT_SPACE,
+        seeds = [b"validator".as_ref(), validator.key().as_ref()],
+        bump
+    )]
+    pub validator_oracle: Account<'info, ValidatorOracle>,
+
+    #[account(seeds = [b"config".as_ref()], bump = config.bump, has_one = admin)]
+    pub config: Account<'info, ProgramConfig>,
+
+    pub admin: Signer<'info>,
+
+    #[account(mut)]
+    pub validator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetValidatorActive<'info> {
+    #[account(
+        mut,
+        seeds = [b"validator".as_ref(), validator_oracle.validator.as_ref()],
+        bump = validator_oracle.bump
+    )]
+    pub validator_oracle: Account<'info, ValidatorOracle>,
+
+    #[account(seeds = [b"config".as_ref()], bump = config.bump, has_one = admin)]
+    pub config: Account<'info, ProgramConfig>,
+
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct DeregisterValidator<'info> {
+    #[account(
+        mut,
+        close = validator,
+        seeds = [b"validator".as_ref(), validator.key().as_ref()],
+        bump = validator_oracle.bump
+    )]
+    pub validator_oracle: Account<'info, ValidatorOracle>,
+
+    #[account(mut)]
+    pub validator: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct AttestSubmission<'info> {
+    #[account(
+        init,
+        payer = validator,
+        space = 8 + QualityAttestation::INIT_SPACE,
+        seeds = [b"attestation".as_ref(), submission.key().as_ref(), validator.key().as_ref()],
+        bump
+    )]
+    pub attestation: Account<'info, QualityAttestation>,
+
+    #[account(
+        mut,
+        seeds = [b"validator".as_ref(), validator.key().as_ref()],
+        bump = validator_oracle.bump
+    )]
+    pub validator_oracle: Account<'info, ValidatorOracle>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"submission".as_ref(),
+            bounty_pool.key().as_ref(),
+            submission.sequence.to_le_bytes().as_ref()
+        ],
+        bump = submission.bump,
+        constraint = submission.contributor == contributor.key() @ ErrorCode::SubmissionMismatch,
+        constraint = submission.contributor != validator.key() @ ErrorCode::SelfAttestation
+    )]
+    pub submission: Account<'info, VideoSubmission>,
+
+    // Program-wide record of the submitted content, released on rejection
+    #[account(
+        mut,
+        seeds = [b"content".as_ref(), submission.content_hash.as_ref()],
+        bump = content_record.bump
+    )]
+    pub content_record: Account<'info, ContentRecord>,
+
+    #[account(
+        mut,
+        seeds = [b"bounty".as_ref(), bounty_pool.bounty_id.as_ref()],
+        bump
+    )]
+    pub bounty_pool: Account<'info, BountyPool>,
+
+    #[account(
+        mut,
+        seeds = [b"profile", contributor.key().as_ref()],
+        bump = contributor_profile.bump
+    )]
+    pub contributor_profile: Account<'info, ContributorProfile>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"category_rep".as_ref(),
+            contributor.key().as_ref(),
+            bounty_pool.requirements.category.as_bytes()
+        ],
+        bump = category_reputation.bump
+    )]
+    pub category_reputation: Account<'info, CategoryReputation>,
+
+    #[account(
+        mut,
+        seeds = [b"buyer".as_ref(), bounty_pool.authority.as_ref()],
+        bump = buyer_profile.bump
+    )]
+    pub buyer_profile: Account<'info, BuyerProfile>,
+
+    /// CHECK: Contributor receives payment on auto-approve; matched against submission
+    #[account(mut)]
+    pub contributor: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub validator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
@@ -562,31 +2895,79 @@ pub struct CreateDataset<'info> {
         seeds = [b"dataset".as_ref(), dataset_id.as_ref()],
         bump
     )]
-    pub dataset_nft: Account<'info, DatasetNFT>,
+    pub dataset_nft: Account<'info, DatasetNFT>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    /// Completed bounty the dataset was built from, whose sponsors share in its sales
+    #[account(
+        seeds = [b"bounty".as_ref(), bounty_pool.bounty_id.as_ref()],
+        bump,
+        constraint = bounty_pool.authority == creator.key() @ ErrorCode::Unauthorized
+    )]
+    pub bounty_pool: Account<'info, BountyPool>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct PurchaseDataset<'info> {
+    #[account(
+        mut,
+        seeds = [b"dataset".as_ref(), dataset_nft.dataset_id.as_ref()],
+        bump = dataset_nft.bump
+    )]
+    pub dataset_nft: Account<'info, DatasetNFT>,
+
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+
+    /// CHECK: Creator receives payment; must be the dataset's current creator
+    #[account(mut, address = dataset_nft.creator)]
+    pub creator: AccountInfo<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        space = 8 + DatasetLicense::INIT_SPACE,
+        seeds = [b"license".as_ref(), dataset_nft.key().as_ref(), buyer.key().as_ref()],
+        bump
+    )]
+    pub license: Account<'info, DatasetLicense>,
 
-    #[account(mut)]
-    pub creator: Signer<'info>,
+    /// The dataset's source bounty, which holds the sponsors' share
+    #[account(mut, address = dataset_nft.bounty @ ErrorCode::DatasetBountyMismatch)]
+    pub bounty_pool: Account<'info, BountyPool>,
 
     pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct PurchaseDataset<'info> {
+pub struct UpdateDataset<'info> {
     #[account(
         mut,
         seeds = [b"dataset".as_ref(), dataset_nft.dataset_id.as_ref()],
-        bump = dataset_nft.bump
+        bump = dataset_nft.bump,
+        has_one = creator
     )]
     pub dataset_nft: Account<'info, DatasetNFT>,
 
-    #[account(mut)]
-    pub buyer: Signer<'info>,
+    pub creator: Signer<'info>,
+}
 
-    /// CHECK: Creator receives payment
-    #[account(mut)]
-    pub creator: AccountInfo<'info>,
+#[derive(Accounts)]
+pub struct AcceptDatasetCreator<'info> {
+    #[account(
+        mut,
+        seeds = [b"dataset".as_ref(), dataset_nft.dataset_id.as_ref()],
+        bump = dataset_nft.bump,
+        constraint = dataset_nft.pending_creator == Some(new_creator.key())
+            @ ErrorCode::NotPendingAuthority
+    )]
+    pub dataset_nft: Account<'info, DatasetNFT>,
 
-    pub system_program: Program<'info, System>,
+    pub new_creator: Signer<'info>,
 }
 
 // ============================================================================
@@ -606,17 +2987,322 @@ pub struct BountyPool {
     pub remaining_pool: u64,
     pub videos_target: u32,
     pub videos_collected: u32,
+    pub pending_submissions: u32, // Escrowed submissions awaiting review; each holds a slot
+    pub submission_count: u32,    // Next submission sequence number
     pub status: BountyStatus,
     pub created_at: i64,
     pub expires_at: i64,
+    pub attestation_policy: AttestationPolicy,
+    pub required_approvals: u8, // 0 = authority reviews alone
+    #[max_len(5)]
+    pub reviewers: Vec<Pubkey>,
+    pub allow_content_reuse: bool,
+    pub min_category_reputation: u16, // 0 = open to everyone
+    pub submission_bond: u64,         // Lamports each submission must post, 0 = none
+    #[max_len(5)]
+    pub reward_tiers: Vec<RewardTier>, // Empty = flat reward_per_video
+    pub bonus_pool: u64,              // Held for tier payouts above the base reward
+    pub sponsored_amount: u64,        // Part of total_pool deposited by co-sponsors
+    pub refund_pool: u64,             // Unspent pool at close, plus escrow returned after it
+    pub authority_refunded: u64,      // Authority's share of refund_pool paid out so far
+    pub sponsor_proceeds: u64,        // Sponsors' cut of dataset sales, held for claims
+    pub pending_authority: Option<Pubkey>, // Proposed new authority awaiting acceptance
+}
+
+impl BountyPool {
+    pub const MAX_REVIEWERS: usize = 5;
+    pub const MAX_REWARD_TIERS: usize = 5;
+
+    /// A deposit's pro-rata slice of the refund pool
+    pub fn refund_share(&self, deposit: u64) -> u64 {
+        pro_rata_share(self.refund_pool, deposit, self.total_pool)
+    }
+
+    /// Whether a new submission fits beside the approved and pending ones
+    pub fn has_open_slot(&self) -> bool {
+        (self.videos_collected as u64 + self.pending_submissions as u64) < self.videos_target as u64
+    }
+
+    /// A sponsor deposit's pro-rata slice of the dataset sale proceeds
+    pub fn proceeds_share(&self, deposit: u64) -> u64 {
+        pro_rata_share(self.sponsor_proceeds, deposit, self.sponsored_amount)
+    }
+
+    /// Part of `total_pool` the authority deposited itself
+    pub fn authority_deposit(&self) -> u64 {
+        self.total_pool.saturating_sub(self.sponsored_amount)
+    }
+
+    /// Completed, cancelled or expired: the pool is frozen and left to refund claims
+    pub fn is_closed(&self) -> bool {
+        matches!(
+            self.status,
+            BountyStatus::Completed | BountyStatus::Cancelled | BountyStatus::Expired
+        )
+    }
+
+    /// Return unspent escrow. Once the bounty is closed it goes to the refund pool,
+    /// where sponsors and the authority claim it.
+    pub fn return_to_pool(&mut self, amount: u64) -> Result<()> {
+        let pool = if self.is_closed() {
+            &mut self.refund_pool
+        } else {
+            &mut self.remaining_pool
+        };
+        *pool = pool.checked_add(amount).ok_or(ErrorCode::Overflow)?;
+        Ok(())
+    }
+
+    /// Payout for a quality score, in basis points of the escrowed reward
+    pub fn tier_payout_bps(&self, quality_score: u8) -> u16 {
+        RewardTier::payout_bps_for(&self.reward_tiers, quality_score)
+    }
+}
+
+/// A co-sponsor's deposits into one bounty. The deposit's share of `total_pool` sets
+/// its refund once the bounty closes; its share of `sponsored_amount` sets its cut of
+/// sales of datasets built from the bounty.
+#[account]
+#[derive(InitSpace, Default)]
+pub struct BountySponsor {
+    pub bounty: Pubkey,
+    pub sponsor: Pubkey,
+    pub amount: u64,
+    pub refunded: u64,         // Lamports claimed so far
+    pub proceeds_claimed: u64, // Dataset sale proceeds claimed so far
+    pub first_contributed_at: i64,
+    pub bump: u8,
+}
+
+impl BountySponsor {
+    /// Share of the bounty's funding in basis points
+    pub fn share_bps(&self, total_pool: u64) -> u16 {
+        if total_pool == 0 {
+            return 0;
+        }
+        (self.amount as u128 * 10_000 / total_pool as u128) as u16
+    }
+}
+
+/// Pays `payout_bps` of the base reward for quality scores from `min_score` up to the
+/// next tier's `min_score`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
+pub struct RewardTier {
+    pub min_score: u8,
+    pub payout_bps: u16, // 10_000 = base reward, 20_000 = 100% bonus, 5_000 = half
+}
+
+impl RewardTier {
+    pub const BASE_PAYOUT_BPS: u16 = 10_000;
+    pub const MAX_PAYOUT_BPS: u16 = 20_000;
+
+    /// Payout of the highest tier `quality_score` reaches; no tiers pays the base reward
+    pub fn payout_bps_for(tiers: &[RewardTier], quality_score: u8) -> u16 {
+        tiers
+            .iter()
+            .rev()
+            .find(|tier| quality_score >= tier.min_score)
+            .map_or(Self::BASE_PAYOUT_BPS, |tier| tier.payout_bps)
+    }
+
+    /// Tiers must start at score 0 and ascend strictly, so every score maps to one tier
+    pub fn validate_table(tiers: &[RewardTier]) -> Result<()> {
+        require!(
+            tiers.len() <= BountyPool::MAX_REWARD_TIERS,
+            ErrorCode::InvalidRewardTiers
+        );
+        if let Some(first) = tiers.first() {
+            require!(first.min_score == 0, ErrorCode::InvalidRewardTiers);
+        }
+        for (i, tier) in tiers.iter().enumerate() {
+            require!(
+                tier.min_score <= 100 && tier.payout_bps <= Self::MAX_PAYOUT_BPS,
+                ErrorCode::InvalidRewardTiers
+            );
+            if i > 0 {
+                require!(
+                    tier.min_score > tiers[i - 1].min_score,
+                    ErrorCode::InvalidRewardTiers
+                );
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Thresholds at which validator attestations settle a submission without the authority
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, InitSpace)]
+pub struct AttestationPolicy {
+    pub auto_approve_min_score: Option<u8>, // Approve when score >= this
+    pub auto_reject_max_score: Option<u8>,  // Reject when score <= this
+    pub min_validator_stake: u64,
 }
 
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum AttestationDecision {
+    Approve,
+    Reject,
+}
+
+impl AttestationPolicy {
+    pub fn validate(&self) -> Result<()> {
+        if let Some(approve) = self.auto_approve_min_score {
+            require!(approve <= 100, ErrorCode::InvalidAttestationPolicy);
+        }
+        if let Some(reject) = self.auto_reject_max_score {
+            require!(reject <= 100, ErrorCode::InvalidAttestationPolicy);
+        }
+        if let (Some(approve), Some(reject)) =
+            (self.auto_approve_min_score, self.auto_reject_max_score)
+        {
+            require!(reject < approve, ErrorCode::InvalidAttestationPolicy);
+        }
+        Ok(())
+    }
+
+    pub fn decide(&self, score: u8) -> Option<AttestationDecision> {
+        match (self.auto_approve_min_score, self.auto_reject_max_score) {
+            (Some(approve), _) if score >= approve => Some(AttestationDecision::Approve),
+            (_, Some(reject)) if score <= reject => Some(AttestationDecision::Reject),
+            _ => None,
+        }
+    }
+}
+
+/// Hard minimums a submission must meet.
+///
+/// `version` records the rules a bounty was created under and `check` dispatches on it,
+/// so an upgrade that changes only how the same fields are checked keeps judging live
+/// bounties by their original rules. There is no migration instruction: adding fields
+/// changes the `BountyPool` layout, and existing bounties stop deserializing.
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
 pub struct Requirements {
+    pub version: u8, // Rules version, set by the program on create
     pub min_duration_secs: u32,
-    #[max_len(20)]
-    pub min_resolution: String,
+    pub max_duration_secs: u32, // 0 = no upper limit
+    pub min_width: u32,
+    pub min_height: u32,
     pub min_fps: u32,
+    #[max_len(3)]
+    pub allowed_orientations: Vec<Orientation>, // Empty = any orientation
+    #[max_len(4)]
+    pub required_sensors: Vec<SensorStream>,
+    pub camera_position: CameraPosition,
+    #[max_len(32)]
+    pub category: String,
+    #[max_len(8, 32)]
+    pub tags: Vec<String>,
+}
+
+impl Requirements {
+    pub const VERSION: u8 = 1;
+    pub const MAX_TAGS: usize = 8;
+    pub const MAX_TAG_LEN: usize = 32;
+
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.max_duration_secs == 0 || self.max_duration_secs >= self.min_duration_secs,
+            ErrorCode::InvalidRequirements
+        );
+        require!(
+            self.allowed_orientations.len() <= 3 && self.required_sensors.len() <= 4,
+            ErrorCode::InvalidRequirements
+        );
+        require!(
+            self.category.len() <= Self::MAX_TAG_LEN,
+            ErrorCode::InvalidRequirements
+        );
+        require!(
+            self.tags.len() <= Self::MAX_TAGS
+                && self.tags.iter().all(|tag| tag.len() <= Self::MAX_TAG_LEN),
+            ErrorCode::InvalidRequirements
+        );
+        Ok(())
+    }
+
+    /// Check a contributor's self-declared spec against the hard minimums
+    pub fn check(&self, spec: &VideoSpec) -> Result<()> {
+        match self.version {
+            1 => self.check_v1(spec),
+            _ => err!(ErrorCode::UnsupportedRequirementsVersion),
+        }
+    }
+
+    fn check_v1(&self, spec: &VideoSpec) -> Result<()> {
+        require!(
+            spec.duration_secs >= self.min_duration_secs,
+            ErrorCode::DurationTooShort
+        );
+        require!(
+            self.max_duration_secs == 0 || spec.duration_secs <= self.max_duration_secs,
+            ErrorCode::DurationTooLong
+        );
+
+        // Compare short and long edges so a portrait 720x1280 meets a 1280x720 minimum
+        let (spec_short, spec_long) = (spec.width.min(spec.height), spec.width.max(spec.height));
+        let (min_short, min_long) = (
+            self.min_width.min(self.min_height),
+            self.min_width.max(self.min_height),
+        );
+        require!(
+            spec_short >= min_short && spec_long >= min_long,
+            ErrorCode::ResolutionTooLow
+        );
+
+        require!(spec.fps >= self.min_fps, ErrorCode::FpsTooLow);
+        require!(
+            self.allowed_orientations.is_empty()
+                || self.allowed_orientations.contains(&spec.orientation),
+            ErrorCode::OrientationNotAllowed
+        );
+        require!(
+            self.required_sensors
+                .iter()
+                .all(|sensor| spec.sensors.contains(sensor)),
+            ErrorCode::MissingSensorStream
+        );
+        require!(
+            self.camera_position == CameraPosition::Any
+                || self.camera_position == spec.camera_position,
+            ErrorCode::CameraPositionMismatch
+        );
+        Ok(())
+    }
+}
+
+/// Technical spec a contributor declares for a submitted video
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct VideoSpec {
+    pub duration_secs: u32,
+    pub width: u32,
+    pub height: u32,
+    pub fps: u32,
+    pub orientation: Orientation,
+    #[max_len(4)]
+    pub sensors: Vec<SensorStream>,
+    pub camera_position: CameraPosition,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum Orientation {
+    Landscape,
+    Portrait,
+    Square,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum SensorStream {
+    Imu,
+    Depth,
+    Audio,
+    Gps,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum CameraPosition {
+    Any,
+    Egocentric,
+    ThirdPerson,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
@@ -625,24 +3311,122 @@ pub enum BountyStatus {
     Paused,
     Completed,
     Cancelled,
+    Expired,
 }
 
 #[account]
 #[derive(InitSpace)]
 pub struct VideoSubmission {
-    pub submission_id: [u8; 16], // UUID or unique ID as 16-byte array
+    pub submission_id: [u8; 16], // Off-chain UUID as 16-byte array (not a seed)
+    pub sequence: u32,           // Index within the bounty, used as PDA seed
     pub contributor: Pubkey,
     pub bounty_id: [u8; 16], // UUID as 16-byte array
+    pub content_hash: [u8; 32],
     #[max_len(100)]
     pub ipfs_hash: String,
     #[max_len(100)]
     pub arweave_tx: String,
     #[max_len(200)]
     pub metadata_uri: String,
+    pub spec: VideoSpec,
     pub submission_timestamp: i64,
     pub status: SubmissionStatus,
     pub escrow_amount: u64,
     pub quality_score: u8,
+    pub approvals: u8,
+    pub rejections: u8,
+    #[max_len(5)]
+    pub review_scores: Vec<u8>,
+    pub bond_amount: u64, // Contributor's bond, held in this account until review
+    pub rejection_reason: Option<RejectionReason>,
+    #[max_len(200)]
+    pub feedback_uri: String, // Reviewer feedback: a URI or a hash of off-chain notes
+    pub bump: u8,
+}
+
+/// Why a submission was rejected
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum RejectionReason {
+    TooShort,
+    LowResolution,
+    WrongTask,
+    Duplicate,
+    Inappropriate,
+    Spam,
+    Fraud,
+    Other,
+}
+
+impl RejectionReason {
+    /// Spam and fraud forfeit the submission bond; honest rejections refund it
+    pub fn forfeits_bond(&self) -> bool {
+        matches!(self, RejectionReason::Spam | RejectionReason::Fraud)
+    }
+
+    /// Reputation weight in `STANDARD_REJECTION_WEIGHT` units: technical misses count
+    /// half, bad-faith submissions count four times
+    pub fn reputation_weight(&self) -> u32 {
+        match self {
+            RejectionReason::TooShort | RejectionReason::LowResolution => 2,
+            RejectionReason::WrongTask | RejectionReason::Duplicate | RejectionReason::Other => {
+                STANDARD_REJECTION_WEIGHT
+            }
+            RejectionReason::Inappropriate => 8,
+            RejectionReason::Spam | RejectionReason::Fraud => 16,
+        }
+    }
+}
+
+impl VideoSubmission {
+    pub const MAX_FEEDBACK_URI_LEN: usize = 200;
+}
+
+/// Marks a content hash as used, either program-wide or within one bounty
+#[account]
+#[derive(InitSpace)]
+pub struct ContentRecord {
+    pub content_hash: [u8; 32],
+    pub contributor: Pubkey, // First submitter
+    pub use_count: u32,
+    pub exclusive: bool, // Used by a bounty that disallows reuse
+    pub bump: u8,
+}
+
+impl ContentRecord {
+    pub fn record_use(
+        &mut self,
+        content_hash: [u8; 32],
+        contributor: Pubkey,
+        exclusive: bool,
+        bump: u8,
+    ) {
+        if self.use_count == 0 {
+            self.content_hash = content_hash;
+            self.contributor = contributor;
+            self.bump = bump;
+        }
+        self.use_count += 1;
+        self.exclusive |= exclusive;
+    }
+
+    /// Drop a rejected submission's use. An exclusive use is always the only one, so
+    /// releasing it frees the content for any bounty again.
+    pub fn release_use(&mut self) {
+        self.use_count = self.use_count.saturating_sub(1);
+        if self.use_count == 0 {
+            self.exclusive = false;
+        }
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct ReviewVote {
+    pub submission: Pubkey,
+    pub reviewer: Pubkey,
+    pub approve: bool,
+    pub quality_score: u8,
+    pub voted_at: i64,
     pub bump: u8,
 }
 
@@ -656,58 +3440,358 @@ pub enum SubmissionStatus {
 }
 
 #[account]
-#[derive(InitSpace)]
+#[derive(InitSpace, Default)]
 pub struct ContributorProfile {
     pub wallet: Pubkey,
-    pub total_submissions: u32,
+    pub total_submissions: u32, // Counted at submission time
     pub accepted_submissions: u32,
     pub rejected_submissions: u32,
+    pub rejection_weight: u32, // Sum of RejectionReason::reputation_weight
+    pub pending_submissions: u32, // Submitted but not yet approved or rejected
     pub average_quality_score: u8,
     pub total_earnings: u64,
-    pub reputation_score: u16, // 0-1000
+    pub reputation_score: u16,      // 0-1000
+    pub reputation_confidence: u16, // 0-1000, grows with reviewed volume
+    pub reputation_version: u8,     // Formula that produced reputation_score
     #[max_len(10)]
-    pub badges: Vec<Badge>,
+    pub badges: Vec<Badge>, // Built-in BadgeType badges
     pub join_date: i64,
     pub last_active: i64,
+    pub defined_badges: [u8; 32], // Bitmap of earned BadgeDefinition ids
+    pub suspended_until: i64,     // 0 = not suspended, i64::MAX = banned
+    pub suspension_reason: Option<SuspensionReason>,
     pub bump: u8,
 }
 
 impl ContributorProfile {
-    pub fn recalculate_reputation(&mut self, new_quality_score: u8) {
-        // Calculate acceptance rate (0-100)
-        let acceptance_rate = if self.total_submissions > 0 {
-            (self.accepted_submissions * 100) / self.total_submissions
-        } else {
-            0
-        };
+    pub const MAX_BADGES: usize = 10;
+    pub const HIGH_QUALITY_MIN_SCORE: u8 = 90;
+    pub const HIGH_QUALITY_MIN_VIDEOS: u32 = 10;
+
+    /// Reset every field for a new profile with neutral reputation
+    pub fn initialize(&mut self, wallet: Pubkey, now: i64, bump: u8) {
+        self.wallet = wallet;
+        self.total_submissions = 0;
+        self.accepted_submissions = 0;
+        self.rejected_submissions = 0;
+        self.rejection_weight = 0;
+        self.pending_submissions = 0;
+        self.average_quality_score = 0;
+        self.total_earnings = 0;
+        self.reputation_score = NEUTRAL_REPUTATION;
+        self.reputation_confidence = 0;
+        self.reputation_version = REPUTATION_VERSION;
+        self.badges = Vec::new();
+        self.join_date = now;
+        self.last_active = now;
+        self.defined_badges = [0; 32];
+        self.suspended_until = 0;
+        self.suspension_reason = None;
+        self.bump = bump;
+    }
+
+    pub fn is_suspended(&self, now: i64) -> bool {
+        now < self.suspended_until
+    }
+
+    pub fn has_badge(&self, badge_type: &BadgeType) -> bool {
+        self.badges
+            .iter()
+            .any(|badge| &badge.badge_type == badge_type)
+    }
+
+    pub fn has_defined_badge(&self, badge_id: u8) -> bool {
+        self.defined_badges[badge_id as usize / 8] & (1 << (badge_id % 8)) != 0
+    }
+
+    pub fn set_defined_badge(&mut self, badge_id: u8) {
+        self.defined_badges[badge_id as usize / 8] |= 1 << (badge_id % 8);
+    }
+
+    /// Award any counter-driven badges the profile now qualifies for.
+    /// Milestones are skipped rather than failing the approval once the profile is full.
+    pub fn award_milestone_badges(&mut self, now: i64) -> Vec<BadgeType> {
+        let milestones = [
+            (BadgeType::FirstVideo, self.accepted_submissions >= 1),
+            (BadgeType::HundredVideos, self.accepted_submissions >= 100),
+            (BadgeType::ThousandVideos, self.accepted_submissions >= 1000),
+            (
+                BadgeType::HighQuality,
+                self.accepted_submissions >= Self::HIGH_QUALITY_MIN_VIDEOS
+                    && self.average_quality_score >= Self::HIGH_QUALITY_MIN_SCORE,
+            ),
+        ];
+
+        let mut awarded = Vec::new();
+        for (badge_type, earned) in milestones {
+            if earned && !self.has_badge(&badge_type) && self.badges.len() < Self::MAX_BADGES {
+                self.badges.push(Badge {
+                    badge_type: badge_type.clone(),
+                    earned_at: now,
+                    token_minted: false,
+                });
+                awarded.push(badge_type);
+            }
+        }
+        awarded
+    }
+
+    /// Re-score after a review: `Some(score)` for an approval, zero included, and
+    /// `None` for a rejection
+    pub fn recalculate_reputation(&mut self, approved_score: Option<u8>) {
+        if let Some(score) = approved_score {
+            self.average_quality_score =
+                running_average(self.average_quality_score, self.accepted_submissions, score);
+        }
+
+        // The contributor is active right now, so no decay applies
+        self.apply_reputation(0);
+    }
+
+    /// Re-score with decay for the time since `last_active`
+    pub fn refresh_reputation(&mut self, now: i64) {
+        self.apply_reputation(now.saturating_sub(self.last_active));
+    }
+
+    fn apply_reputation(&mut self, idle_secs: i64) {
+        let (score, confidence) = weighted_reputation_score(
+            self.accepted_submissions,
+            self.rejection_weight as u64,
+            self.average_quality_score,
+            idle_secs,
+        );
+        self.reputation_score = score;
+        self.reputation_confidence = confidence;
+        self.reputation_version = REPUTATION_VERSION;
+    }
+}
+
+/// Why an arbiter suspended a contributor
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
+pub enum SuspensionReason {
+    StolenContent,
+    SyntheticContent,
+    Spam,
+    TermsViolation,
+    Other,
+}
+
+/// A contributor's track record within one bounty category, so buyers can gate on
+/// category-specific reputation rather than the global score
+#[account]
+#[derive(InitSpace, Default)]
+pub struct CategoryReputation {
+    pub wallet: Pubkey,
+    #[max_len(32)]
+    pub category: String, // Matches Requirements::category
+    pub accepted_submissions: u32,
+    pub rejected_submissions: u32,
+    pub rejection_weight: u32,
+    pub average_quality_score: u8,
+    pub reputation_score: u16,      // 0-1000
+    pub reputation_confidence: u16, // 0-1000
+    pub reputation_version: u8,
+    pub last_active: i64,
+    pub bump: u8,
+}
+
+impl CategoryReputation {
+    /// Fill in a freshly created account; existing accounts are left untouched
+    pub fn ensure_initialized(&mut self, wallet: Pubkey, category: &str, bump: u8) {
+        if self.wallet != Pubkey::default() {
+            return;
+        }
+        self.wallet = wallet;
+        self.category = category.to_string();
+        self.reputation_score = NEUTRAL_REPUTATION;
+        self.reputation_version = REPUTATION_VERSION;
+        self.bump = bump;
+    }
+
+    pub fn record_approval(&mut self, quality_score: u8, now: i64) {
+        self.accepted_submissions += 1;
+        self.average_quality_score = running_average(
+            self.average_quality_score,
+            self.accepted_submissions,
+            quality_score,
+        );
+        self.last_active = now;
+        self.apply_reputation(0);
+    }
 
-        // Update average quality score
-        if new_quality_score > 0 && self.accepted_submissions > 0 {
-            let total_quality = (self.average_quality_score as u32
-                * (self.accepted_submissions - 1) as u32)
-                + new_quality_score as u32;
-            self.average_quality_score = (total_quality / self.accepted_submissions as u32) as u8;
+    pub fn record_rejection(&mut self, reason: RejectionReason, now: i64) {
+        self.rejected_submissions += 1;
+        self.rejection_weight = self
+            .rejection_weight
+            .saturating_add(reason.reputation_weight());
+        self.last_active = now;
+        self.apply_reputation(0);
+    }
+
+    /// Re-score with decay for the time since `last_active`
+    pub fn refresh_reputation(&mut self, now: i64) {
+        if self.last_active > 0 {
+            self.apply_reputation(now.saturating_sub(self.last_active));
         }
+    }
+
+    fn apply_reputation(&mut self, idle_secs: i64) {
+        let (score, confidence) = weighted_reputation_score(
+            self.accepted_submissions,
+            self.rejection_weight as u64,
+            self.average_quality_score,
+            idle_secs,
+        );
+        self.reputation_score = score;
+        self.reputation_confidence = confidence;
+        self.reputation_version = REPUTATION_VERSION;
+    }
+}
 
-        // Calculate reputation: base 500, +/- based on performance
-        // Acceptance rate weight: 50%
-        // Quality score weight: 50%
-        let acceptance_points = (acceptance_rate as u16 * 5) / 2; // Max 250
-        let quality_points = (self.average_quality_score as u16 * 250) / 100; // Max 250
+/// A bounty creator's track record, so contributors can see how fairly they review
+#[account]
+#[derive(InitSpace, Default)]
+pub struct BuyerProfile {
+    pub wallet: Pubkey,
+    pub bounties_created: u32,
+    pub total_paid_out: u64,
+    pub approved_submissions: u32,
+    pub rejected_submissions: u32,
+    pub disputes_lost: u32, // Disputes the arbiter settled in the contributor's favour
+    pub average_review_latency_secs: u64, // Submission to approval/rejection
+    pub created_at: i64,
+    pub bump: u8,
+}
 
-        self.reputation_score = 500 + acceptance_points + quality_points;
+impl BuyerProfile {
+    /// Fill in a freshly created account; existing accounts are left untouched
+    pub fn ensure_initialized(&mut self, wallet: Pubkey, now: i64, bump: u8) {
+        if self.wallet != Pubkey::default() {
+            return;
+        }
+        self.wallet = wallet;
+        self.created_at = now;
+        self.bump = bump;
+    }
 
-        // Cap at 1000
-        if self.reputation_score > 1000 {
-            self.reputation_score = 1000;
+    /// Record a settled review: `Some(reward)` for an approval, `None` for a rejection
+    pub fn record_review(&mut self, paid_out: Option<u64>, latency_secs: i64) -> Result<()> {
+        match paid_out {
+            Some(reward) => {
+                self.approved_submissions += 1;
+                self.total_paid_out = self
+                    .total_paid_out
+                    .checked_add(reward)
+                    .ok_or(ErrorCode::Overflow)?;
+            }
+            None => self.rejected_submissions += 1,
         }
+
+        let reviews = self.approved_submissions as u128 + self.rejected_submissions as u128;
+        let total =
+            self.average_review_latency_secs as u128 * (reviews - 1) + latency_secs.max(0) as u128;
+        self.average_review_latency_secs = (total / reviews) as u64;
+        Ok(())
+    }
+}
+
+// ============================================================================
+// REPUTATION SCORING
+// ============================================================================
+
+/// Bumped whenever the scoring formula changes
+pub const REPUTATION_VERSION: u8 = 4;
+/// Neutral score for a contributor with no history
+pub const NEUTRAL_REPUTATION: u16 = 500;
+/// Pseudo-reviews of prior evidence blended into every score
+pub const REPUTATION_PRIOR_WEIGHT: u64 = 10;
+/// Idle time after which a score's lead over neutral halves
+pub const REPUTATION_HALF_LIFE_SECS: i64 = 90 * 24 * 60 * 60;
+/// Weight of one ordinary rejection; see `RejectionReason::reputation_weight`
+pub const STANDARD_REJECTION_WEIGHT: u32 = 4;
+
+/// Score a contributor from their review history.
+///
+/// Acceptance rate and average quality are each pulled toward a neutral prior
+/// (50%) by `REPUTATION_PRIOR_WEIGHT` pseudo-reviews, so a single result can't
+/// swing the score far, then weighted equally onto 0-1000. A score above neutral
+/// decays toward it with a half-life of `REPUTATION_HALF_LIFE_SECS` of inactivity;
+/// a score below neutral stays put, so waiting out a penalty doesn't lift it.
+/// Confidence (0-1000) grows with reviewed volume and decays in every case.
+///
+/// Returns `(score, confidence)`.
+pub fn reputation_score(
+    accepted: u32,
+    rejected: u32,
+    average_quality: u8,
+    idle_secs: i64,
+) -> (u16, u16) {
+    weighted_reputation_score(
+        accepted,
+        rejected as u64 * STANDARD_REJECTION_WEIGHT as u64,
+        average_quality,
+        idle_secs,
+    )
+}
+
+/// `reputation_score` with rejections given as a total weight in
+/// `STANDARD_REJECTION_WEIGHT` units, so minor rejections count for less than fraud
+pub fn weighted_reputation_score(
+    accepted: u32,
+    rejection_weight: u64,
+    average_quality: u8,
+    idle_secs: i64,
+) -> (u16, u16) {
+    // Everything below is in rejection-weight units
+    let unit = STANDARD_REJECTION_WEIGHT as u64;
+    let accepted = accepted as u64 * unit;
+    let reviewed = accepted + rejection_weight;
+    let prior = REPUTATION_PRIOR_WEIGHT * unit;
+    let neutral = NEUTRAL_REPUTATION as u64;
+
+    // Both components on a 0-1000 scale
+    let acceptance = (accepted * 1000 + prior * neutral) / (reviewed + prior);
+    let quality =
+        (accepted * average_quality.min(100) as u64 * 10 + prior * neutral) / (accepted + prior);
+    let raw = (acceptance + quality) / 2;
+
+    let decay = decay_factor_bps(idle_secs);
+    let score = if raw > neutral {
+        neutral + (raw - neutral) * decay / 10_000
+    } else {
+        raw
+    };
+    let confidence = reviewed * 1000 / (reviewed + prior) * decay / 10_000;
+
+    (score.min(1000) as u16, confidence as u16)
+}
+
+/// Fold the newest score into an average over `count` scores, the newest included
+fn running_average(average: u8, count: u32, new_score: u8) -> u8 {
+    let total = average as u32 * (count - 1) + new_score as u32;
+    (total / count) as u8
+}
+
+/// Exponential decay in basis points, halving every `REPUTATION_HALF_LIFE_SECS`
+/// and interpolated linearly within each half-life to stay in integer math
+fn decay_factor_bps(idle_secs: i64) -> u64 {
+    if idle_secs <= 0 {
+        return 10_000;
+    }
+    let halvings = idle_secs / REPUTATION_HALF_LIFE_SECS;
+    if halvings >= 14 {
+        return 0;
     }
+    let base = 10_000u64 >> halvings;
+    let into_half_life = (idle_secs % REPUTATION_HALF_LIFE_SECS) as u64;
+    base - base * into_half_life / (2 * REPUTATION_HALF_LIFE_SECS as u64)
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
 pub struct Badge {
     pub badge_type: BadgeType,
     pub earned_at: i64,
+    pub token_minted: bool, // Soulbound token minted to the wallet
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
@@ -720,6 +3804,114 @@ pub enum BadgeType {
     CategoryExpert,
 }
 
+impl BadgeType {
+    /// Seed byte for the badge's mint PDA
+    pub fn seed(&self) -> u8 {
+        self.clone() as u8
+    }
+
+    /// Badges that `award_milestone_badges` grants from the profile's counters
+    pub fn is_milestone(&self) -> bool {
+        matches!(
+            self,
+            BadgeType::FirstVideo
+                | BadgeType::HundredVideos
+                | BadgeType::ThousandVideos
+                | BadgeType::HighQuality
+        )
+    }
+
+    pub fn token_name_and_symbol(&self) -> (&'static str, &'static str) {
+        match self {
+            BadgeType::FirstVideo => ("TerraTrain First Video", "TTFIRST"),
+            BadgeType::HundredVideos => ("TerraTrain 100 Videos", "TT100"),
+            BadgeType::ThousandVideos => ("TerraTrain 1000 Videos", "TT1000"),
+            BadgeType::HighQuality => ("TerraTrain High Quality", "TTHQ"),
+            BadgeType::EarlyAdopter => ("TerraTrain Early Adopter", "TTEARLY"),
+            BadgeType::CategoryExpert => ("TerraTrain Category Expert", "TTEXPERT"),
+        }
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct ProgramConfig {
+    pub admin: Pubkey,
+    pub bump: u8,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct ValidatorOracle {
+    pub validator: Pubkey,
+    pub stake: u64, // Lamports locked in this PDA on top of rent
+    pub active: bool,
+    pub attestations_posted: u32,
+    pub registered_at: i64,
+    pub bump: u8,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct QualityAttestation {
+    pub submission: Pubkey,
+    pub validator: Pubkey,
+    pub score: u8,          // 0-100
+    pub checks_passed: u16, // Bitmask of automated QA checks that passed
+    #[max_len(32)]
+    pub model_version: String,
+    pub attested_at: i64,
+    pub bump: u8,
+}
+
+/// Admin-registered badge, added without a program upgrade
+#[account]
+#[derive(InitSpace)]
+pub struct BadgeDefinition {
+    pub badge_id: u8, // Bit index in ContributorProfile.defined_badges
+    #[max_len(32)]
+    pub name: String,
+    #[max_len(32)]
+    pub category: Option<String>,
+    #[max_len(200)]
+    pub criteria: String,
+    pub rule: AutoAwardRule,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl BadgeDefinition {
+    pub const MAX_NAME_LEN: usize = 32;
+    pub const MAX_CRITERIA_LEN: usize = 200;
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
+pub enum AutoAwardRule {
+    Manual, // Only the admin can award it
+    AcceptedSubmissions { min: u32 },
+    AverageQuality { min_score: u8, min_videos: u32 },
+    Reputation { min_score: u16 },
+    TotalEarnings { min_lamports: u64 },
+}
+
+impl AutoAwardRule {
+    pub fn is_met_by(&self, profile: &ContributorProfile) -> bool {
+        match *self {
+            AutoAwardRule::Manual => false,
+            AutoAwardRule::AcceptedSubmissions { min } => profile.accepted_submissions >= min,
+            AutoAwardRule::AverageQuality {
+                min_score,
+                min_videos,
+            } => {
+                profile.accepted_submissions >= min_videos
+                    && profile.average_quality_score >= min_score
+            }
+            AutoAwardRule::Reputation { min_score } => profile.reputation_score >= min_score,
+            AutoAwardRule::TotalEarnings { min_lamports } => profile.total_earnings >= min_lamports,
+        }
+    }
+}
+
 #[account]
 #[derive(InitSpace)]
 pub struct DatasetNFT {
@@ -730,6 +3922,24 @@ pub struct DatasetNFT {
     pub royalty_percentage: u8,
     pub created_at: i64,
     pub total_sales: u32,
+    pub pending_creator: Option<Pubkey>, // Proposed new creator awaiting acceptance
+    pub bounty: Pubkey,                  // Source bounty whose sponsors share in sales
+    pub sponsor_bps: u16,                // Sponsors' cut of each sale, fixed at creation
+    pub bump: u8,
+}
+
+/// On-chain proof that `holder` bought `dataset`; other programs check it through
+/// `interface::holds_license`
+#[account]
+#[derive(InitSpace)]
+pub struct DatasetLicense {
+    pub dataset: Pubkey,
+    pub holder: Pubkey,
+    pub license_type: LicenseType, // As sold on the latest purchase
+    pub purchases: u32,
+    pub total_paid: u64,
+    pub first_purchased_at: i64,
+    pub last_purchased_at: i64,
     pub bump: u8,
 }
 
@@ -759,12 +3969,45 @@ pub struct BountyStatusChanged {
     pub new_status: BountyStatus,
 }
 
+#[event]
+pub struct SponsorContributed {
+    pub bounty_id: [u8; 16],
+    pub sponsor: Pubkey,
+    pub amount: u64,
+    pub total_contributed: u64,
+    pub share_bps: u16, // Of the bounty's total pool after this deposit
+}
+
+#[event]
+pub struct SponsorRefunded {
+    pub bounty_id: [u8; 16],
+    pub sponsor: Pubkey,
+    pub refund: u64,
+}
+
+#[event]
+pub struct SponsorProceedsClaimed {
+    pub bounty_id: [u8; 16],
+    pub sponsor: Pubkey,
+    pub proceeds: u64,
+}
+
+#[event]
+pub struct AuthorityRefunded {
+    pub bounty_id: [u8; 16],
+    pub authority: Pubkey,
+    pub refund: u64,
+}
+
 #[event]
 pub struct VideoSubmitted {
     pub submission_id: [u8; 16],
     pub bounty_id: [u8; 16],
+    pub sequence: u32,
     pub contributor: Pubkey,
+    pub content_hash: [u8; 32],
     pub escrow_amount: u64,
+    pub bond_amount: u64,
 }
 
 #[event]
@@ -775,10 +4018,171 @@ pub struct SubmissionApproved {
     pub quality_score: u8,
 }
 
+#[event]
+pub struct RewardTierApplied {
+    pub submission_id: [u8; 16],
+    pub quality_score: u8,
+    pub payout_bps: u16,
+    pub escrow_amount: u64,
+    pub reward: u64, // Paid to the contributor, bonus included
+    pub bonus: u64,  // Drawn from the bonus pool
+    pub refunded_to_pool: u64,
+}
+
+#[event]
+pub struct BatchItemSkipped {
+    pub index: u8,
+    pub submission: Pubkey,
+    pub error_code: u32,
+}
+
+#[event]
+pub struct BatchReviewed {
+    pub bounty_id: [u8; 16],
+    pub approved: u8,
+    pub rejected: u8,
+    pub skipped: u8,
+}
+
 #[event]
 pub struct SubmissionRejected {
     pub submission_id: [u8; 16],
     pub contributor: Pubkey,
+    pub reason: RejectionReason,
+    pub bond: u64,
+    pub bond_forfeited: bool, // Paid to the bounty authority instead of the contributor
+    pub feedback_uri: String,
+}
+
+#[event]
+pub struct AttestationPolicyUpdated {
+    pub bounty_id: [u8; 16],
+    pub policy: AttestationPolicy,
+}
+
+#[event]
+pub struct ContentReuseUpdated {
+    pub bounty_id: [u8; 16],
+    pub allow: bool,
+}
+
+#[event]
+pub struct ReputationGateUpdated {
+    pub bounty_id: [u8; 16],
+    pub min_category_reputation: u16,
+}
+
+#[event]
+pub struct BountyAuthorityTransferProposed {
+    pub bounty_id: [u8; 16],
+    pub authority: Pubkey,
+    pub proposed: Pubkey,
+}
+
+#[event]
+pub struct BountyAuthorityTransferCancelled {
+    pub bounty_id: [u8; 16],
+    pub proposed: Pubkey,
+}
+
+#[event]
+pub struct BountyAuthorityTransferred {
+    pub bounty_id: [u8; 16],
+    pub previous: Pubkey,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct SubmissionBondUpdated {
+    pub bounty_id: [u8; 16],
+    pub bond: u64,
+}
+
+#[event]
+pub struct RewardTiersUpdated {
+    pub bounty_id: [u8; 16],
+    pub tiers: Vec<RewardTier>,
+}
+
+#[event]
+pub struct BonusPoolFunded {
+    pub bounty_id: [u8; 16],
+    pub amount: u64,
+    pub bonus_pool: u64,
+}
+
+#[event]
+pub struct ReviewPanelUpdated {
+    pub bounty_id: [u8; 16],
+    pub reviewers: Vec<Pubkey>,
+    pub required_approvals: u8,
+}
+
+#[event]
+pub struct ReviewVoteCast {
+    pub submission_id: [u8; 16],
+    pub reviewer: Pubkey,
+    pub approve: bool,
+    pub quality_score: u8,
+    pub approvals: u8,
+    pub rejections: u8,
+}
+
+#[event]
+pub struct SubmissionDisputed {
+    pub submission_id: [u8; 16],
+    pub approvals: u8,
+    pub rejections: u8,
+}
+
+#[event]
+pub struct DisputeResolved {
+    pub submission_id: [u8; 16],
+    pub approved: bool,
+}
+
+#[event]
+pub struct ConfigInitialized {
+    pub admin: Pubkey,
+}
+
+#[event]
+pub struct ContributorSuspended {
+    pub wallet: Pubkey,
+    pub reason: SuspensionReason,
+    pub suspended_until: i64,
+}
+
+#[event]
+pub struct SuspensionLifted {
+    pub wallet: Pubkey,
+}
+
+#[event]
+pub struct ValidatorRegistered {
+    pub validator: Pubkey,
+    pub stake: u64,
+}
+
+#[event]
+pub struct ValidatorStatusChanged {
+    pub validator: Pubkey,
+    pub active: bool,
+}
+
+#[event]
+pub struct ValidatorDeregistered {
+    pub validator: Pubkey,
+    pub stake: u64,
+}
+
+#[event]
+pub struct QualityAttested {
+    pub submission_id: [u8; 16],
+    pub validator: Pubkey,
+    pub score: u8,
+    pub checks_passed: u16,
+    pub model_version: String,
 }
 
 #[event]
@@ -787,12 +4191,45 @@ pub struct ProfileCreated {
     pub reputation_score: u16,
 }
 
+#[event]
+pub struct ReputationRefreshed {
+    pub wallet: Pubkey,
+    pub reputation_score: u16,
+    pub reputation_confidence: u16,
+}
+
 #[event]
 pub struct BadgeAwarded {
     pub wallet: Pubkey,
     pub badge_type: BadgeType,
 }
 
+#[event]
+pub struct BadgeDefinitionCreated {
+    pub badge_id: u8,
+    pub name: String,
+    pub category: Option<String>,
+}
+
+#[event]
+pub struct DefinedBadgeAwarded {
+    pub wallet: Pubkey,
+    pub badge_id: u8,
+}
+
+#[event]
+pub struct BadgeMintInitialized {
+    pub badge_type: BadgeType,
+    pub mint: Pubkey,
+}
+
+#[event]
+pub struct BadgeTokenMinted {
+    pub wallet: Pubkey,
+    pub badge_type: BadgeType,
+    pub mint: Pubkey,
+}
+
 #[event]
 pub struct DatasetCreated {
     pub dataset_id: [u8; 16],
@@ -805,6 +4242,27 @@ pub struct DatasetPurchased {
     pub dataset_id: [u8; 16],
     pub buyer: Pubkey,
     pub price: u64,
+    pub sponsor_share: u64, // Part of the price held for the source bounty's sponsors
+}
+
+#[event]
+pub struct DatasetCreatorTransferProposed {
+    pub dataset_id: [u8; 16],
+    pub creator: Pubkey,
+    pub proposed: Pubkey,
+}
+
+#[event]
+pub struct DatasetCreatorTransferCancelled {
+    pub dataset_id: [u8; 16],
+    pub proposed: Pubkey,
+}
+
+#[event]
+pub struct DatasetCreatorTransferred {
+    pub dataset_id: [u8; 16],
+    pub previous: Pubkey,
+    pub creator: Pubkey,
 }
 
 // ============================================================================
@@ -845,4 +4303,410 @@ pub enum ErrorCode {
 
     #[msg("Invalid royalty percentage")]
     InvalidRoyalty,
+
+    #[msg("Invalid bounty requirements")]
+    InvalidRequirements,
+
+    #[msg("Video is shorter than the bounty minimum")]
+    DurationTooShort,
+
+    #[msg("Video is longer than the bounty maximum")]
+    DurationTooLong,
+
+    #[msg("Video resolution is below the bounty minimum")]
+    ResolutionTooLow,
+
+    #[msg("Video frame rate is below the bounty minimum")]
+    FpsTooLow,
+
+    #[msg("Video orientation is not allowed by the bounty")]
+    OrientationNotAllowed,
+
+    #[msg("Video is missing a required sensor stream")]
+    MissingSensorStream,
+
+    #[msg("Camera position does not match the bounty")]
+    CameraPositionMismatch,
+
+    #[msg("Unauthorized")]
+    Unauthorized,
+
+    #[msg("Submission does not belong to this bounty or contributor")]
+    SubmissionMismatch,
+
+    #[msg("Quality score must be between 0 and 100")]
+    InvalidQualityScore,
+
+    #[msg("Invalid attestation policy")]
+    InvalidAttestationPolicy,
+
+    #[msg("Validator is not active")]
+    ValidatorInactive,
+
+    #[msg("Validator stake is below the bounty minimum")]
+    InsufficientValidatorStake,
+
+    #[msg("Contributors cannot attest their own submissions")]
+    SelfAttestation,
+
+    #[msg("Invalid review panel")]
+    InvalidReviewPanel,
+
+    #[msg("Signer is not on the bounty's review panel")]
+    NotAReviewer,
+
+    #[msg("This bounty requires reviewer consensus")]
+    ConsensusRequired,
+
+    #[msg("This bounty has no review panel")]
+    ConsensusNotConfigured,
+
+    #[msg("Content has already been submitted")]
+    DuplicateContent,
+
+    #[msg("Badge has not been earned")]
+    BadgeNotEarned,
+
+    #[msg("Badge token already minted")]
+    BadgeTokenAlreadyMinted,
+
+    #[msg("Invalid badge definition")]
+    InvalidBadgeDefinition,
+
+    #[msg("Profile does not meet the badge criteria")]
+    BadgeCriteriaNotMet,
+
+    #[msg("Reputation gate must be between 0 and 1000")]
+    InvalidReputationGate,
+
+    #[msg("Category reputation is below the bounty's minimum")]
+    ReputationTooLow,
+
+    #[msg("Contributor is suspended")]
+    ContributorSuspended,
+
+    #[msg("Feedback URI is too long")]
+    FeedbackTooLong,
+
+    #[msg("Invalid reward tiers")]
+    InvalidRewardTiers,
+
+    #[msg("Batch decisions and accounts do not line up")]
+    InvalidBatch,
+
+    #[msg("Bounty has not expired yet")]
+    BountyNotExpired,
+
+    #[msg("No refund left to claim")]
+    RefundAlreadyClaimed,
+
+    #[msg("Ownership can't be transferred to that key")]
+    InvalidTransferTarget,
+
+    #[msg("No ownership transfer is pending")]
+    NoPendingTransfer,
+
+    #[msg("Signer is not the pending owner")]
+    NotPendingAuthority,
+
+    #[msg("Bounty requirements were written by an unsupported program version")]
+    UnsupportedRequirementsVersion,
+
+    #[msg("Reviewers cannot vote on their own submissions")]
+    SelfReview,
+
+    #[msg("Bounty account doesn't match the dataset's source bounty")]
+    DatasetBountyMismatch,
+
+    #[msg("Settle pending submissions before changing the review panel")]
+    SubmissionsPending,
+
+    #[msg("Milestone badges are awarded by approvals, not by hand")]
+    MilestoneBadge,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAY: i64 = 24 * 60 * 60;
+
+    #[test]
+    fn new_contributor_is_neutral_with_no_confidence() {
+        assert_eq!(reputation_score(0, 0, 0, 0), (NEUTRAL_REPUTATION, 0));
+    }
+
+    #[test]
+    fn single_perfect_video_stays_near_neutral() {
+        let (score, confidence) = reputation_score(1, 0, 100, 0);
+        assert!(score > NEUTRAL_REPUTATION && score < 600, "score {score}");
+        assert!(confidence < 100, "confidence {confidence}");
+    }
+
+    #[test]
+    fn single_rejection_keeps_score_close_to_neutral() {
+        let (score, _) = reputation_score(0, 1, 0, 0);
+        assert!(score < NEUTRAL_REPUTATION && score > 450, "score {score}");
+    }
+
+    #[test]
+    fn long_perfect_record_approaches_maximum() {
+        let (score, confidence) = reputation_score(1000, 0, 100, 0);
+        assert!(score > 980, "score {score}");
+        assert!(confidence > 980, "confidence {confidence}");
+    }
+
+    #[test]
+    fn rejection_only_record_loses_the_acceptance_half() {
+        // With nothing accepted, quality stays at the prior and acceptance goes to zero
+        let (score, _) = reputation_score(0, 1000, 0, 0);
+        assert!(score < 260, "score {score}");
+        let (low_quality, _) = reputation_score(1000, 0, 0, 0);
+        assert!(low_quality < 520, "score {low_quality}");
+    }
+
+    #[test]
+    fn more_evidence_moves_score_further() {
+        let (few, _) = reputation_score(5, 0, 90, 0);
+        let (many, _) = reputation_score(50, 0, 90, 0);
+        assert!(many > few);
+    }
+
+    #[test]
+    fn one_half_life_halves_distance_from_neutral() {
+        let (fresh, fresh_confidence) = reputation_score(200, 0, 100, 0);
+        let (idle, idle_confidence) = reputation_score(200, 0, 100, REPUTATION_HALF_LIFE_SECS);
+        let neutral = NEUTRAL_REPUTATION as i32;
+        assert_eq!(idle as i32 - neutral, (fresh as i32 - neutral) / 2);
+        assert_eq!(idle_confidence, fresh_confidence / 2);
+    }
+
+    #[test]
+    fn decay_is_monotonic_and_bottoms_out_at_neutral() {
+        let mut previous = u16::MAX;
+        for days in (0..=2000).step_by(30) {
+            let (score, _) = reputation_score(200, 0, 100, days * DAY);
+            assert!(score <= previous);
+            assert!(score >= NEUTRAL_REPUTATION);
+            previous = score;
+        }
+        assert_eq!(
+            reputation_score(200, 0, 100, 5000 * DAY),
+            (NEUTRAL_REPUTATION, 0)
+        );
+    }
+
+    #[test]
+    fn decay_leaves_low_scores_in_place() {
+        let (fresh, fresh_confidence) = reputation_score(0, 100, 0, 0);
+        let (idle, idle_confidence) = reputation_score(0, 100, 0, 180 * DAY);
+        assert!(fresh < NEUTRAL_REPUTATION);
+        assert_eq!(idle, fresh);
+        assert!(idle_confidence < fresh_confidence);
+    }
+
+    #[test]
+    fn score_stays_in_range_for_extreme_inputs() {
+        for (accepted, rejected, quality, idle) in [
+            (u32::MAX, 0, u8::MAX, 0),
+            (0, u32::MAX, 0, 0),
+            (u32::MAX, u32::MAX, 100, i64::MAX),
+            (7, 3, 250, -DAY),
+        ] {
+            let (score, confidence) = reputation_score(accepted, rejected, quality, idle);
+            assert!(score <= 1000 && confidence <= 1000);
+        }
+    }
+
+    #[test]
+    fn category_reputation_scores_its_own_history() {
+        let wallet = Pubkey::new_unique();
+        let mut rep = CategoryReputation::default();
+        rep.ensure_initialized(wallet, "kitchen", 254);
+        assert_eq!(rep.reputation_score, NEUTRAL_REPUTATION);
+
+        rep.record_approval(90, DAY);
+        rep.record_approval(70, DAY);
+        rep.record_rejection(RejectionReason::Other, 2 * DAY);
+        assert_eq!(rep.average_quality_score, 80);
+        assert_eq!(rep.last_active, 2 * DAY);
+        assert_eq!(
+            (rep.reputation_score, rep.reputation_confidence),
+            reputation_score(2, 1, 80, 0)
+        );
+
+        // Re-initializing an existing account must not reset it
+        rep.ensure_initialized(Pubkey::new_unique(), "outdoor", 1);
+        assert_eq!(rep.wallet, wallet);
+        assert_eq!(rep.accepted_submissions, 2);
+    }
+
+    #[test]
+    fn buyer_profile_averages_review_latency() {
+        let mut buyer = BuyerProfile::default();
+        buyer.ensure_initialized(Pubkey::new_unique(), 0, 255);
+
+        buyer.record_review(Some(100), DAY).unwrap();
+        buyer.record_review(None, 3 * DAY).unwrap();
+        buyer.record_review(Some(50), 2 * DAY).unwrap();
+        assert_eq!(buyer.approved_submissions, 2);
+        assert_eq!(buyer.rejected_submissions, 1);
+        assert_eq!(buyer.total_paid_out, 150);
+        assert_eq!(buyer.average_review_latency_secs, 2 * DAY as u64);
+
+        // Clock skew never produces a negative latency
+        buyer.record_review(None, -DAY).unwrap();
+        assert_eq!(buyer.average_review_latency_secs, 3 * DAY as u64 / 2);
+    }
+
+    #[test]
+    fn suspension_blocks_until_it_expires() {
+        let mut profile = ContributorProfile::default();
+        profile.initialize(Pubkey::new_unique(), 0, 255);
+        assert!(!profile.is_suspended(0));
+
+        profile.suspended_until = 10 * DAY;
+        assert!(profile.is_suspended(DAY));
+        assert!(!profile.is_suspended(10 * DAY));
+
+        profile.suspended_until = i64::MAX;
+        assert!(profile.is_suspended(i64::MAX - 1));
+    }
+
+    #[test]
+    fn standard_rejections_match_the_unweighted_score() {
+        let weight = RejectionReason::WrongTask.reputation_weight() as u64;
+        assert_eq!(
+            weighted_reputation_score(12, 3 * weight, 85, DAY),
+            reputation_score(12, 3, 85, DAY)
+        );
+    }
+
+    #[test]
+    fn fraud_hurts_more_than_technical_rejections() {
+        let score_after = |reason: RejectionReason| {
+            weighted_reputation_score(10, reason.reputation_weight() as u64, 80, 0).0
+        };
+        let technical = score_after(RejectionReason::TooShort);
+        let ordinary = score_after(RejectionReason::Other);
+        let inappropriate = score_after(RejectionReason::Inappropriate);
+        let fraud = score_after(RejectionReason::Fraud);
+        assert!(technical > ordinary, "{technical} vs {ordinary}");
+        assert!(ordinary > inappropriate, "{ordinary} vs {inappropriate}");
+        assert!(inappropriate > fraud, "{inappropriate} vs {fraud}");
+    }
+
+    fn tiers(table: &[(u8, u16)]) -> Vec<RewardTier> {
+        table
+            .iter()
+            .map(|&(min_score, payout_bps)| RewardTier {
+                min_score,
+                payout_bps,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn reward_tiers_pick_the_highest_tier_reached() {
+        let table = tiers(&[(0, 5_000), (60, 10_000), (91, 20_000)]);
+        assert_eq!(RewardTier::payout_bps_for(&table, 0), 5_000);
+        assert_eq!(RewardTier::payout_bps_for(&table, 59), 5_000);
+        assert_eq!(RewardTier::payout_bps_for(&table, 60), 10_000);
+        assert_eq!(RewardTier::payout_bps_for(&table, 90), 10_000);
+        assert_eq!(RewardTier::payout_bps_for(&table, 100), 20_000);
+        assert_eq!(
+            RewardTier::payout_bps_for(&[], 42),
+            RewardTier::BASE_PAYOUT_BPS
+        );
+    }
+
+    #[test]
+    fn reward_tier_tables_must_cover_every_score() {
+        let valid = tiers(&[(0, 5_000), (60, 10_000), (91, 20_000)]);
+        assert!(RewardTier::validate_table(&valid).is_ok());
+        for invalid in [
+            tiers(&[(10, 10_000)]),
+            tiers(&[(0, 10_000), (50, 5_000), (50, 20_000)]),
+            tiers(&[(0, 20_001)]),
+            tiers(&[(0, 1), (1, 1), (2, 1), (3, 1), (4, 1), (5, 1)]),
+        ] {
+            assert!(RewardTier::validate_table(&invalid).is_err());
+        }
+    }
+
+    #[test]
+    fn skipped_batch_items_report_the_custom_error_number() {
+        let err: Error = ErrorCode::InvalidStatus.into();
+        assert_eq!(batch_error_code(&err), u32::from(ErrorCode::InvalidStatus));
+    }
+
+    #[test]
+    fn sponsor_refunds_split_the_unspent_pool_by_deposit() {
+        // 3 SOL from the authority and 1 SOL from a sponsor; 2 SOL left unspent
+        let (total, sponsored, unspent) = (3_000, 1_000, 2_000);
+        let sponsor_refund = pro_rata_share(unspent, sponsored, total);
+        let authority_refund = pro_rata_share(unspent, total - sponsored, total);
+        assert_eq!(sponsor_refund, 666);
+        assert_eq!(authority_refund, 1_333);
+        assert!(sponsor_refund + authority_refund <= unspent);
+        assert_eq!(pro_rata_share(unspent, sponsored, 0), 0);
+
+        let record = BountySponsor {
+            amount: sponsored,
+            ..Default::default()
+        };
+        assert_eq!(record.share_bps(total), 3_333);
+    }
+
+    #[test]
+    fn license_checks_verify_the_pda_before_the_data() {
+        let dataset = Pubkey::new_unique();
+        let holder = Pubkey::new_unique();
+        let (key, bump) = Pubkey::find_program_address(
+            &[b"license", dataset.as_ref(), holder.as_ref()],
+            &crate::ID,
+        );
+        let license = DatasetLicense {
+            dataset,
+            holder,
+            license_type: LicenseType::Unlimited,
+            purchases: 1,
+            total_paid: 1_000,
+            first_purchased_at: 0,
+            last_purchased_at: 0,
+            bump,
+        };
+        let mut data = Vec::new();
+        license.try_serialize(&mut data).unwrap();
+        let mut lamports = 1_000_000;
+        let info = AccountInfo::new(
+            &key,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &crate::ID,
+            false,
+            0,
+        );
+        assert!(interface::holds_license(&info, &dataset, &holder).unwrap());
+        // The account sits at the holder's address, so it proves nothing for anyone else
+        assert!(interface::holds_license(&info, &dataset, &Pubkey::new_unique()).is_err());
+
+        // A license PDA that was never created
+        let system = System::id();
+        let mut empty_lamports = 0;
+        let mut empty = Vec::new();
+        let info = AccountInfo::new(
+            &key,
+            false,
+            false,
+            &mut empty_lamports,
+            &mut empty,
+            &system,
+            false,
+            0,
+        );
+        assert!(!interface::holds_license(&info, &dataset, &holder).unwrap());
+    }
 }
@@ -0,0 +1,222 @@
+//! LiteSVM with the program's SBF build deployed. Instructions run under the real
+//! runtime, so CPIs, account creation, system transfers and `emit!` all behave as they
+//! do on-chain.
+//!
+//! The program is deployed through the upgradeable loader, so `initialize_config` can
+//! check its upgrade authority. Build it before running the tests: `anchor build` (or
+//! `cargo build-sbf`) writes the `target/deploy/unimake_backend.so` that [`Svm::new`]
+//! loads. Until it exists the crate's build script leaves the tests that need it out
+//! and says so.
+
+use std::collections::HashMap;
+
+use anchor_lang::prelude::{Clock, Pubkey};
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::AccountDeserialize;
+use litesvm::types::TransactionResult;
+use litesvm::LiteSVM;
+use solana_account::Account;
+use solana_keypair::Keypair;
+use solana_loader_v3_interface::get_program_data_address;
+use solana_loader_v3_interface::state::UpgradeableLoaderState;
+use solana_signer::Signer;
+use solana_transaction::Transaction;
+use unimake_backend::{BountyPool, ID};
+
+/// The program's SBF build
+pub const PROGRAM_SO: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/../target/deploy/unimake_backend.so"
+);
+
+/// Unix time every `Svm` starts at
+pub const GENESIS_TIME: i64 = 1_700_000_000;
+
+const LAMPORTS_PER_SOL: u64 = 1_000_000_000;
+
+/// A LiteSVM bank with the program deployed
+pub struct Svm {
+    bank: LiteSVM,
+    /// Pays every transaction fee, so the wallets a test tracks move only by what the
+    /// program does
+    fee_payer: Keypair,
+    upgrade_authority: Pubkey,
+    /// Keypairs of the wallets handed out, so `process` can sign for them
+    keypairs: HashMap<Pubkey, Keypair>,
+}
+
+impl Default for Svm {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Svm {
+    pub fn new() -> Self {
+        let mut bank = LiteSVM::new();
+        let fee_payer = Keypair::new();
+        bank.airdrop(&fee_payer.pubkey(), 1_000 * LAMPORTS_PER_SOL)
+            .unwrap();
+
+        let mut clock = bank.get_sysvar::<Clock>();
+        clock.unix_timestamp = GENESIS_TIME;
+        bank.set_sysvar(&clock);
+
+        let upgrade_authority = Keypair::new();
+        let mut svm = Self {
+            bank,
+            fee_payer,
+            upgrade_authority: upgrade_authority.pubkey(),
+            keypairs: HashMap::new(),
+        };
+        svm.airdrop(&upgrade_authority.pubkey(), 10 * LAMPORTS_PER_SOL);
+        svm.keypairs
+            .insert(upgrade_authority.pubkey(), upgrade_authority);
+        svm.deploy();
+        svm
+    }
+
+    /// Write the program and its program-data account the way the upgradeable loader
+    /// leaves them after a deploy
+    fn deploy(&mut self) {
+        let elf = std::fs::read(PROGRAM_SO)
+            .unwrap_or_else(|err| panic!("{PROGRAM_SO}: {err}; run `anchor build` first"));
+
+        let program_data = get_program_data_address(&ID);
+        let mut data = bincode::serialize(&UpgradeableLoaderState::ProgramData {
+            slot: 0,
+            upgrade_authority_address: Some(self.upgrade_authority),
+        })
+        .unwrap();
+        data.resize(UpgradeableLoaderState::size_of_programdata_metadata(), 0);
+        data.extend_from_slice(&elf);
+        self.set_loader_account(program_data, data, false);
+
+        let program = bincode::serialize(&UpgradeableLoaderState::Program {
+            programdata_address: program_data,
+        })
+        .unwrap();
+        self.set_loader_account(ID, program, true);
+    }
+
+    fn set_loader_account(&mut self, address: Pubkey, data: Vec<u8>, executable: bool) {
+        let account = Account {
+            lamports: self.bank.minimum_balance_for_rent_exemption(data.len()),
+            data,
+            owner: solana_sdk_ids::bpf_loader_upgradeable::ID,
+            executable,
+            rent_epoch: 0,
+        };
+        self.set_account(address, account);
+    }
+
+    /// The program's upgrade authority, the only key `initialize_config` accepts
+    pub fn upgrade_authority(&self) -> Pubkey {
+        self.upgrade_authority
+    }
+
+    /// A new wallet holding 100 SOL, which `process` signs for
+    pub fn wallet(&mut self) -> Pubkey {
+        let wallet = Keypair::new();
+        let key = wallet.pubkey();
+        self.airdrop(&key, 100 * LAMPORTS_PER_SOL);
+        self.keypairs.insert(key, wallet);
+        key
+    }
+
+    pub fn airdrop(&mut self, key: &Pubkey, lamports: u64) {
+        self.bank.airdrop(key, lamports).expect("airdrop");
+    }
+
+    /// Run `ix` in its own transaction, signed by every wallet it marks as a signer.
+    /// The fee is paid by the bank's fee payer.
+    pub fn process(&mut self, ix: Instruction) -> TransactionResult {
+        let mut signers = vec![&self.fee_payer];
+        for meta in ix.accounts.iter().filter(|meta| meta.is_signer) {
+            let keypair = self
+                .keypairs
+                .get(&meta.pubkey)
+                .unwrap_or_else(|| panic!("no keypair for signer {}", meta.pubkey));
+            if !signers.iter().any(|signer| signer.pubkey() == meta.pubkey) {
+                signers.push(keypair);
+            }
+        }
+        let transaction = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.fee_payer.pubkey()),
+            &signers,
+            self.bank.latest_blockhash(),
+        );
+        let result = self.bank.send_transaction(transaction);
+        // An identical transaction would otherwise be rejected as already processed
+        self.bank.expire_blockhash();
+        result
+    }
+
+    pub fn set_account(&mut self, key: Pubkey, account: Account) {
+        self.bank.set_account(key, account).expect("set_account");
+    }
+
+    /// The account at `key`, unless it was never created or has been closed
+    pub fn get_account(&self, key: &Pubkey) -> Option<Account> {
+        self.bank
+            .get_account(key)
+            .filter(|account| account.lamports > 0)
+    }
+
+    pub fn exists(&self, key: &Pubkey) -> bool {
+        self.get_account(key).is_some()
+    }
+
+    pub fn lamports(&self, key: &Pubkey) -> u64 {
+        self.bank.get_balance(key).unwrap_or(0)
+    }
+
+    /// Sum of the lamports held by `keys`
+    pub fn total_lamports(&self, keys: &[Pubkey]) -> u128 {
+        keys.iter().map(|key| self.lamports(key) as u128).sum()
+    }
+
+    /// Rent-exempt minimum for the account at `key`, at its current size
+    pub fn rent(&self, key: &Pubkey) -> u64 {
+        let len = self
+            .get_account(key)
+            .map_or(0, |account| account.data.len());
+        self.bank.minimum_balance_for_rent_exemption(len)
+    }
+
+    /// Deserialize an Anchor account, or `None` if there is none at `key`
+    pub fn try_account<T: AccountDeserialize>(&self, key: &Pubkey) -> Option<T> {
+        let account = self.get_account(key)?;
+        Some(
+            T::try_deserialize(&mut account.data.as_slice())
+                .unwrap_or_else(|err| panic!("account {key} failed to deserialize: {err}")),
+        )
+    }
+
+    /// Deserialize an Anchor account, panicking if it is missing or malformed
+    #[track_caller]
+    pub fn account<T: AccountDeserialize>(&self, key: &Pubkey) -> T {
+        self.try_account(key)
+            .unwrap_or_else(|| panic!("account {key} does not exist"))
+    }
+
+    pub fn bounty(&self, bounty_id: &[u8; 16]) -> BountyPool {
+        self.account(&unimake_client::pda::bounty(bounty_id).0)
+    }
+
+    pub fn now(&self) -> i64 {
+        self.bank.get_sysvar::<Clock>().unix_timestamp
+    }
+
+    pub fn warp_to(&mut self, unix_timestamp: i64) {
+        let mut clock = self.bank.get_sysvar::<Clock>();
+        clock.unix_timestamp = unix_timestamp;
+        clock.slot += 1;
+        self.bank.set_sysvar(&clock);
+    }
+
+    pub fn advance(&mut self, secs: i64) {
+        self.warp_to(self.now() + secs);
+    }
+}
@@ -0,0 +1,665 @@
+//! Shared fixtures for the program's and the client's tests: a LiteSVM bank with the
+//! program's SBF build deployed, PDA derivations, instruction builders and accounts.
+//!
+//! Most fixtures seed the account an instruction would leave behind, in the state that
+//! instruction leaves it in, so a test can start from any point in a bounty's life
+//! without replaying it. Tests of the instructions that create accounts
+//! (`create_bounty`, `submit_video`, `cast_review_vote`, `attest_submission`, ...) run
+//! them through the program instead, with the client builders a backend would use.
+
+mod harness;
+
+pub use harness::*;
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::system_program;
+use anchor_lang::{InstructionData, Space, ToAccountMetas};
+use litesvm::types::{TransactionMetadata, TransactionResult};
+use solana_account::Account;
+use solana_instruction::error::InstructionError;
+use solana_transaction_error::TransactionError;
+use unimake_backend::{
+    accounts, instruction, AttestationPolicy, AutoAwardRule, BadgeDefinition, BountyPool,
+    BountySponsor, BountyStatus, BuyerProfile, CameraPosition, CategoryReputation, ContentRecord,
+    ContributorProfile, DatasetNFT, LicenseType, Orientation, Requirements, SubmissionStatus,
+    ValidatorOracle, VideoSpec, VideoSubmission,
+};
+use unimake_client::events::{parse_logs, ProgramEvent};
+use unimake_client::instructions;
+
+pub const SOL: u64 = 1_000_000_000;
+pub const DAY: i64 = 24 * 60 * 60;
+pub const CATEGORY: &str = "kitchen";
+
+/// Build a bounty program instruction from Anchor's generated account and data structs
+pub fn ix(accounts: impl ToAccountMetas, data: impl InstructionData) -> Instruction {
+    Instruction {
+        program_id: unimake_backend::ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+/// Assert that a transaction failed with one of the program's (or Anchor's) error codes
+#[track_caller]
+pub fn assert_error(result: TransactionResult, code: impl Into<u32>) {
+    match result {
+        Ok(_) => panic!("transaction succeeded"),
+        Err(failed) => assert_eq!(
+            failed.err,
+            TransactionError::InstructionError(0, InstructionError::Custom(code.into())),
+            "{:#?}",
+            failed.meta.logs
+        ),
+    }
+}
+
+/// Assert that a transaction failed because a required signature was missing
+#[track_caller]
+pub fn assert_not_signed(result: TransactionResult) {
+    assert_error(result, anchor_lang::error::ErrorCode::AccountNotSigner);
+}
+
+/// Clear the signer flag on `key`, as if the transaction omitted its signature
+pub fn unsigned(mut ix: Instruction, key: &Pubkey) -> Instruction {
+    for meta in ix.accounts.iter_mut().filter(|meta| meta.pubkey == *key) {
+        meta.is_signer = false;
+    }
+    ix
+}
+
+/// Events the program emitted in a transaction
+pub fn emitted(meta: &TransactionMetadata) -> Vec<ProgramEvent> {
+    parse_logs(&meta.logs)
+}
+
+/// Pad or truncate a label into a 16-byte id
+pub fn id16(label: &str) -> [u8; 16] {
+    let mut id = [0u8; 16];
+    let len = label.len().min(16);
+    id[..len].copy_from_slice(&label.as_bytes()[..len]);
+    id
+}
+
+/// Stand-in for a video's SHA-256: the label, zero-padded
+pub fn content_hash(label: &str) -> [u8; 32] {
+    let mut hash = [0u8; 32];
+    let len = label.len().min(32);
+    hash[..len].copy_from_slice(&label.as_bytes()[..len]);
+    hash
+}
+
+pub fn requirements() -> Requirements {
+    Requirements {
+        version: Requirements::VERSION,
+        min_duration_secs: 30,
+        max_duration_secs: 600,
+        min_width: 1280,
+        min_height: 720,
+        min_fps: 30,
+        allowed_orientations: vec![],
+        required_sensors: vec![],
+        camera_position: CameraPosition::Any,
+        category: CATEGORY.to_string(),
+        tags: vec!["knife".to_string()],
+    }
+}
+
+/// A spec that meets `requirements()`
+pub fn spec() -> VideoSpec {
+    VideoSpec {
+        duration_secs: 60,
+        width: 1920,
+        height: 1080,
+        fps: 30,
+        orientation: Orientation::Landscape,
+        sensors: vec![],
+        camera_position: CameraPosition::Egocentric,
+    }
+}
+
+// ============================================================================
+// PDAS
+// ============================================================================
+
+pub mod pda {
+    use super::*;
+    use std::cell::RefCell;
+    use std::collections::HashMap;
+
+    thread_local! {
+        static BUMPS: RefCell<HashMap<Pubkey, u8>> = RefCell::new(HashMap::new());
+    }
+
+    fn find(seeds: &[&[u8]]) -> Pubkey {
+        let (key, bump) = Pubkey::find_program_address(seeds, &unimake_backend::ID);
+        BUMPS.with(|bumps| bumps.borrow_mut().insert(key, bump));
+        key
+    }
+
+    /// Canonical bump of a PDA derived through this module
+    pub fn bump(key: &Pubkey) -> u8 {
+        BUMPS.with(|bumps| bumps.borrow()[key])
+    }
+
+    pub fn bounty(bounty_id: &[u8; 16]) -> Pubkey {
+        find(&[b"bounty", bounty_id])
+    }
+
+    pub fn buyer(wallet: &Pubkey) -> Pubkey {
+        find(&[b"buyer", wallet.as_ref()])
+    }
+
+    pub fn sponsor(bounty: &Pubkey, sponsor: &Pubkey) -> Pubkey {
+        find(&[b"sponsor", bounty.as_ref(), sponsor.as_ref()])
+    }
+
+    pub fn submission(bounty: &Pubkey, sequence: u32) -> Pubkey {
+        find(&[b"submission", bounty.as_ref(), &sequence.to_le_bytes()])
+    }
+
+    pub fn content(content_hash: &[u8; 32]) -> Pubkey {
+        find(&[b"content", content_hash])
+    }
+
+    pub fn profile(wallet: &Pubkey) -> Pubkey {
+        find(&[b"profile", wallet.as_ref()])
+    }
+
+    pub fn category(wallet: &Pubkey, category: &str) -> Pubkey {
+        find(&[b"category_rep", wallet.as_ref(), category.as_bytes()])
+    }
+
+    pub fn config() -> Pubkey {
+        find(&[b"config"])
+    }
+
+    pub fn validator(validator: &Pubkey) -> Pubkey {
+        find(&[b"validator", validator.as_ref()])
+    }
+
+    pub fn badge_definition(badge_id: u8) -> Pubkey {
+        find(&[b"badge_def", &[badge_id]])
+    }
+
+    pub fn badge_mint(seed: u8) -> Pubkey {
+        find(&[b"badge_mint", &[seed]])
+    }
+
+    pub fn dataset(dataset_id: &[u8; 16]) -> Pubkey {
+        find(&[b"dataset", dataset_id])
+    }
+
+    pub fn license(dataset: &Pubkey, buyer: &Pubkey) -> Pubkey {
+        find(&[b"license", dataset.as_ref(), buyer.as_ref()])
+    }
+}
+
+// ============================================================================
+// SEEDED ACCOUNTS
+// ============================================================================
+
+/// Store `value` as a bounty program account sized as its `init` would size it,
+/// holding rent exemption plus `escrow` lamports
+pub fn put<T: AccountSerialize + Space>(svm: &mut Svm, key: Pubkey, value: &T, escrow: u64) {
+    let mut data = Vec::with_capacity(8 + T::INIT_SPACE);
+    value.try_serialize(&mut data).expect("serialize account");
+    data.resize(8 + T::INIT_SPACE, 0);
+    let lamports = Rent::default().minimum_balance(data.len()) + escrow;
+    svm.set_account(
+        key,
+        Account {
+            lamports,
+            data,
+            owner: unimake_backend::ID,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+}
+
+/// Rewrite a stored account's fields in place, leaving its lamports alone
+pub fn edit<T: AccountSerialize + AccountDeserialize>(
+    svm: &mut Svm,
+    key: &Pubkey,
+    f: impl FnOnce(&mut T),
+) {
+    let mut value: T = svm.account(key);
+    f(&mut value);
+    let mut account = svm.get_account(key).expect("account exists");
+    let len = account.data.len();
+    account.data.clear();
+    value
+        .try_serialize(&mut account.data)
+        .expect("serialize account");
+    account.data.resize(len, 0);
+    svm.set_account(*key, account);
+}
+
+/// Move lamports between accounts for a seeded fixture, as its instruction's system transfer would
+fn transfer(svm: &mut Svm, from: &Pubkey, to: &Pubkey, lamports: u64) {
+    let mut source = svm.get_account(from).expect("payer exists");
+    source.lamports -= lamports;
+    svm.set_account(*from, source);
+    let mut destination = svm.get_account(to).expect("payee exists");
+    destination.lamports += lamports;
+    svm.set_account(*to, destination);
+}
+
+/// A seeded bounty
+pub struct Bounty {
+    pub id: [u8; 16],
+    pub pool: Pubkey,
+    pub authority: Pubkey,
+}
+
+impl Bounty {
+    pub fn buyer_profile(&self) -> Pubkey {
+        pda::buyer(&self.authority)
+    }
+
+    pub fn state(&self, svm: &Svm) -> BountyPool {
+        svm.account(&self.pool)
+    }
+
+    pub fn edit(&self, svm: &mut Svm, f: impl FnOnce(&mut BountyPool)) {
+        edit(svm, &self.pool, f);
+    }
+
+    pub fn update(&self) -> accounts::UpdateBounty {
+        accounts::UpdateBounty {
+            bounty_pool: self.pool,
+            authority: self.authority,
+        }
+    }
+
+    pub fn review(
+        &self,
+        svm: &Svm,
+        submission: Pubkey,
+        contributor: Pubkey,
+    ) -> accounts::ReviewSubmission {
+        let content_hash = svm.account::<VideoSubmission>(&submission).content_hash;
+        accounts::ReviewSubmission {
+            submission,
+            content_record: pda::content(&content_hash),
+            bounty_pool: self.pool,
+            contributor_profile: pda::profile(&contributor),
+            category_reputation: pda::category(&contributor, CATEGORY),
+            buyer_profile: self.buyer_profile(),
+            contributor,
+            authority: self.authority,
+            system_program: system_program::ID,
+        }
+    }
+
+    pub fn close(&self) -> accounts::CloseBounty {
+        accounts::CloseBounty {
+            bounty_pool: self.pool,
+            authority: self.authority,
+            system_program: system_program::ID,
+        }
+    }
+
+    pub fn complete(&self) -> Instruction {
+        ix(self.close(), instruction::CompleteBounty {})
+    }
+
+    pub fn cancel(&self) -> Instruction {
+        ix(self.close(), instruction::CancelBounty {})
+    }
+
+    pub fn authority_refund(&self) -> Instruction {
+        ix(self.close(), instruction::ClaimAuthorityRefund {})
+    }
+
+    pub fn close_expired(&self) -> Instruction {
+        ix(
+            accounts::CloseExpiredBounty {
+                bounty_pool: self.pool,
+                authority: self.authority,
+            },
+            instruction::CloseExpiredBounty {},
+        )
+    }
+
+    pub fn sponsor_refund(&self, sponsor: Pubkey) -> accounts::ClaimSponsorRefund {
+        accounts::ClaimSponsorRefund {
+            bounty_pool: self.pool,
+            sponsor_record: pda::sponsor(&self.pool, &sponsor),
+            sponsor,
+        }
+    }
+}
+
+/// The state `create_bounty` leaves a bounty paying `reward` for each of `target`
+/// videos in, open for 30 days
+pub fn new_pool(
+    svm: &Svm,
+    authority: Pubkey,
+    id: [u8; 16],
+    reward: u64,
+    target: u32,
+) -> BountyPool {
+    BountyPool {
+        authority,
+        bounty_id: id,
+        task_description: "Record a video of chopping vegetables".to_string(),
+        requirements: requirements(),
+        reward_per_video: reward,
+        total_pool: reward * target as u64,
+        remaining_pool: reward * target as u64,
+        videos_target: target,
+        videos_collected: 0,
+        pending_submissions: 0,
+        submission_count: 0,
+        status: BountyStatus::Active,
+        created_at: svm.now(),
+        expires_at: svm.now() + 30 * DAY,
+        attestation_policy: AttestationPolicy::default(),
+        required_approvals: 0,
+        reviewers: vec![],
+        allow_content_reuse: false,
+        min_category_reputation: 0,
+        submission_bond: 0,
+        reward_tiers: vec![],
+        bonus_pool: 0,
+        sponsored_amount: 0,
+        refund_pool: 0,
+        authority_refunded: 0,
+        sponsor_proceeds: 0,
+        pending_authority: None,
+    }
+}
+
+/// A funded, active bounty and its authority's buyer profile, paid for by the authority
+pub fn open_bounty(
+    svm: &mut Svm,
+    authority: Pubkey,
+    label: &str,
+    reward: u64,
+    target: u32,
+) -> Bounty {
+    let id = id16(label);
+    let pool = new_pool(svm, authority, id, reward, target);
+    let bounty = Bounty {
+        id,
+        pool: pda::bounty(&id),
+        authority,
+    };
+    put(svm, bounty.pool, &pool, 0);
+    transfer(svm, &authority, &bounty.pool, pool.total_pool);
+
+    let buyer = bounty.buyer_profile();
+    if svm.exists(&buyer) {
+        edit(svm, &buyer, |profile: &mut BuyerProfile| {
+            profile.bounties_created += 1
+        });
+    } else {
+        let mut profile = BuyerProfile::default();
+        profile.ensure_initialized(authority, svm.now(), pda::bump(&buyer));
+        profile.bounties_created = 1;
+        put(svm, buyer, &profile, 0);
+    }
+    bounty
+}
+
+/// A contributor profile and category reputation as a first submission creates them
+pub fn contributor_profile(svm: &mut Svm, wallet: Pubkey) -> Pubkey {
+    let key = pda::profile(&wallet);
+    if !svm.exists(&key) {
+        let mut profile = ContributorProfile::default();
+        profile.initialize(wallet, svm.now(), pda::bump(&key));
+        put(svm, key, &profile, 0);
+    }
+
+    let category = pda::category(&wallet, CATEGORY);
+    if !svm.exists(&category) {
+        let mut reputation = CategoryReputation::default();
+        reputation.ensure_initialized(wallet, CATEGORY, pda::bump(&category));
+        put(svm, category, &reputation, 0);
+    }
+    key
+}
+
+/// A pending submission as `submit_video` leaves it, with the escrow reserved in the
+/// pool, the bounty's bond paid in by the contributor and the content's program-wide
+/// record counting it
+pub fn submit(svm: &mut Svm, bounty: &Bounty, contributor: Pubkey, label: &str) -> Pubkey {
+    let pool = bounty.state(svm);
+    let key = pda::submission(&bounty.pool, pool.submission_count);
+    let content = pda::content(&content_hash(label));
+    let mut record = if svm.exists(&content) {
+        svm.account(&content)
+    } else {
+        ContentRecord {
+            content_hash: [0; 32],
+            contributor: Pubkey::default(),
+            use_count: 0,
+            exclusive: false,
+            bump: 0,
+        }
+    };
+    record.record_use(
+        content_hash(label),
+        contributor,
+        !pool.allow_content_reuse,
+        pda::bump(&content),
+    );
+    put(svm, content, &record, 0);
+
+    let submission = VideoSubmission {
+        submission_id: id16(label),
+        sequence: pool.submission_count,
+        contributor,
+        bounty_id: bounty.id,
+        content_hash: content_hash(label),
+        ipfs_hash: "QmTest".to_string(),
+        arweave_tx: "ArweaveTest".to_string(),
+        metadata_uri: "https://metadata/test".to_string(),
+        spec: spec(),
+        submission_timestamp: svm.now(),
+        status: SubmissionStatus::Pending,
+        escrow_amount: pool.reward_per_video,
+        quality_score: 0,
+        approvals: 0,
+        rejections: 0,
+        review_scores: vec![],
+        bond_amount: pool.submission_bond,
+        rejection_reason: None,
+        feedback_uri: String::new(),
+        bump: pda::bump(&key),
+    };
+    put(svm, key, &submission, 0);
+    transfer(svm, &contributor, &key, pool.submission_bond);
+
+    bounty.edit(svm, |pool| {
+        pool.remaining_pool -= pool.reward_per_video;
+        pool.submission_count += 1;
+        pool.pending_submissions += 1;
+    });
+    let profile = contributor_profile(svm, contributor);
+    let now = svm.now();
+    edit(svm, &profile, |profile: &mut ContributorProfile| {
+        profile.total_submissions += 1;
+        profile.pending_submissions += 1;
+        profile.last_active = now;
+    });
+    key
+}
+
+/// A co-sponsor's deposit as `contribute_to_bounty` records it
+pub fn sponsor(svm: &mut Svm, bounty: &Bounty, sponsor: Pubkey, amount: u64) -> Pubkey {
+    let key = pda::sponsor(&bounty.pool, &sponsor);
+    let record = BountySponsor {
+        bounty: bounty.pool,
+        sponsor,
+        amount,
+        refunded: 0,
+        proceeds_claimed: 0,
+        first_contributed_at: svm.now(),
+        bump: pda::bump(&key),
+    };
+    put(svm, key, &record, 0);
+    transfer(svm, &sponsor, &bounty.pool, amount);
+    bounty.edit(svm, |pool| {
+        pool.total_pool += amount;
+        pool.remaining_pool += amount;
+        pool.sponsored_amount += amount;
+    });
+    key
+}
+
+/// Bonus lamports deposited by the authority, as `fund_bonus_pool` adds them
+pub fn fund_bonus(svm: &mut Svm, bounty: &Bounty, amount: u64) {
+    transfer(svm, &bounty.authority, &bounty.pool, amount);
+    bounty.edit(svm, |pool| pool.bonus_pool += amount);
+}
+
+/// The program config, initialized by the upgrade authority with a fresh admin;
+/// returns the admin
+pub fn config(svm: &mut Svm) -> Pubkey {
+    let admin = svm.wallet();
+    let authority = svm.upgrade_authority();
+    svm.process(instructions::initialize_config(&authority, &admin))
+        .expect("initialize_config");
+    admin
+}
+
+/// A registered, active validator oracle holding `stake`
+pub fn validator(svm: &mut Svm, validator: Pubkey, stake: u64) -> Pubkey {
+    let key = pda::validator(&validator);
+    let oracle = ValidatorOracle {
+        validator,
+        stake,
+        active: true,
+        attestations_posted: 0,
+        registered_at: svm.now(),
+        bump: pda::bump(&key),
+    };
+    put(svm, key, &oracle, stake);
+    key
+}
+
+pub fn badge_definition(svm: &mut Svm, badge_id: u8, rule: AutoAwardRule) -> Pubkey {
+    let key = pda::badge_definition(badge_id);
+    let definition = BadgeDefinition {
+        badge_id,
+        name: "Kitchen Regular".to_string(),
+        category: Some(CATEGORY.to_string()),
+        criteria: "Ten accepted kitchen videos".to_string(),
+        rule,
+        created_at: svm.now(),
+        bump: pda::bump(&key),
+    };
+    put(svm, key, &definition, 0);
+    key
+}
+
+/// A dataset listed by `bounty`'s authority, built from the bounty without sponsors
+pub fn dataset(svm: &mut Svm, bounty: &Bounty, label: &str, price: u64) -> Pubkey {
+    let id = id16(label);
+    let key = pda::dataset(&id);
+    let dataset = DatasetNFT {
+        dataset_id: id,
+        license_type: LicenseType::Unlimited,
+        creator: bounty.authority,
+        price,
+        royalty_percentage: 10,
+        created_at: svm.now(),
+        total_sales: 0,
+        pending_creator: None,
+        bounty: bounty.pool,
+        sponsor_bps: 0,
+        bump: pda::bump(&key),
+    };
+    put(svm, key, &dataset, 0);
+    key
+}
+
+// ============================================================================
+// INSTRUCTIONS
+// ============================================================================
+
+pub fn approve(svm: &mut Svm, bounty: &Bounty, submission: Pubkey, contributor: Pubkey, score: u8) {
+    svm.process(ix(
+        bounty.review(svm, submission, contributor),
+        instruction::ApproveSubmission {
+            quality_score: score,
+        },
+    ))
+    .expect("approve_submission");
+}
+
+/// `create_bounty` arguments for a bounty that funds exactly `target` rewards
+pub fn bounty_args(
+    label: &str,
+    reward: u64,
+    target: u32,
+    expires_at: i64,
+) -> instruction::CreateBounty {
+    instruction::CreateBounty {
+        bounty_id: id16(label),
+        reward_per_video: reward,
+        total_pool: reward * target as u64,
+        videos_target: target,
+        task_description: "Chop vegetables".to_string(),
+        requirements: requirements(),
+        expires_at,
+    }
+}
+
+/// Create a bounty through `create_bounty`, open for 30 days, with `authority` paying
+pub fn create_bounty(
+    svm: &mut Svm,
+    authority: Pubkey,
+    label: &str,
+    reward: u64,
+    target: u32,
+) -> BountyPool {
+    let args = bounty_args(label, reward, target, svm.now() + 30 * DAY);
+    svm.process(instructions::create_bounty(&authority, &authority, args))
+        .expect("create_bounty");
+    svm.bounty(&id16(label))
+}
+
+/// A bounty `authority` created and completed without approving anything, for
+/// datasets to name as their source; returns its id
+pub fn completed_bounty(svm: &mut Svm, authority: Pubkey, label: &str) -> [u8; 16] {
+    let bounty = create_bounty(svm, authority, label, SOL / 10, 1);
+    svm.process(instructions::complete_bounty(&bounty.bounty_id, &authority))
+        .expect("complete_bounty");
+    bounty.bounty_id
+}
+
+/// A submission of the video `label` that meets `requirements()`
+pub fn submit_args(label: &str) -> instruction::SubmitVideo {
+    instruction::SubmitVideo {
+        submission_id: id16(label),
+        content_hash: content_hash(label),
+        ipfs_hash: "QmTest".to_string(),
+        arweave_tx: "ArweaveTest".to_string(),
+        metadata_uri: "https://metadata/test".to_string(),
+        spec: spec(),
+    }
+}
+
+/// Submit `label` to a bounty through `submit_video`; returns the submission's address
+/// and state
+pub fn submit_video(
+    svm: &mut Svm,
+    bounty_id: &[u8; 16],
+    contributor: Pubkey,
+    label: &str,
+) -> (Pubkey, VideoSubmission) {
+    let pool = svm.bounty(bounty_id);
+    let address = pda::submission(&pda::bounty(bounty_id), pool.submission_count);
+    svm.process(instructions::submit_video(
+        &pool,
+        &contributor,
+        submit_args(label),
+    ))
+    .expect("submit_video");
+    (address, svm.account(&address))
+}
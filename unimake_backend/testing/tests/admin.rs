@@ -0,0 +1,152 @@
+#![cfg(program_built)]
+
+use anchor_lang::error::ErrorCode as AnchorError;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use unimake_backend::{
+    accounts, instruction, ContributorProfile, SuspensionReason, ValidatorOracle,
+};
+use unimake_testing::*;
+
+fn suspend(contributor: Pubkey, admin: Pubkey, duration_secs: u64) -> Instruction {
+    ix(
+        accounts::SuspendContributor {
+            contributor_profile: pda::profile(&contributor),
+            contributor,
+            config: pda::config(),
+            admin,
+            system_program: anchor_lang::system_program::ID,
+        },
+        instruction::SuspendContributor {
+            reason: SuspensionReason::Spam,
+            duration_secs,
+        },
+    )
+}
+
+fn lift(contributor: Pubkey, admin: Pubkey) -> Instruction {
+    ix(
+        accounts::LiftSuspension {
+            contributor_profile: pda::profile(&contributor),
+            config: pda::config(),
+            admin,
+        },
+        instruction::LiftSuspension {},
+    )
+}
+
+// ============================================================================
+// SUSPENSIONS
+// ============================================================================
+
+#[test]
+fn the_admin_suspends_and_bans_contributors() {
+    let mut svm = Svm::new();
+    let admin = config(&mut svm);
+    let contributor = svm.wallet();
+    let profile = contributor_profile(&mut svm, contributor);
+
+    svm.process(suspend(contributor, admin, 7 * DAY as u64))
+        .unwrap();
+    let suspended: ContributorProfile = svm.account(&profile);
+    assert_eq!(suspended.suspended_until, svm.now() + 7 * DAY);
+    assert!(suspended.suspension_reason == Some(SuspensionReason::Spam));
+
+    // A zero duration is a ban that only lifting ends
+    svm.process(suspend(contributor, admin, 0)).unwrap();
+    let banned: ContributorProfile = svm.account(&profile);
+    assert_eq!(banned.suspended_until, i64::MAX);
+
+    svm.process(lift(contributor, admin)).unwrap();
+    let lifted: ContributorProfile = svm.account(&profile);
+    assert_eq!(lifted.suspended_until, 0);
+    assert!(lifted.suspension_reason.is_none());
+}
+
+#[test]
+fn only_the_admin_suspends() {
+    let mut svm = Svm::new();
+    let admin = config(&mut svm);
+    let contributor = svm.wallet();
+    contributor_profile(&mut svm, contributor);
+    let stranger = svm.wallet();
+
+    assert_error(
+        svm.process(suspend(contributor, stranger, 0)),
+        AnchorError::ConstraintHasOne,
+    );
+    assert_not_signed(svm.process(unsigned(suspend(contributor, admin, 0), &admin)));
+
+    svm.process(suspend(contributor, admin, 0)).unwrap();
+    assert_error(
+        svm.process(lift(contributor, stranger)),
+        AnchorError::ConstraintHasOne,
+    );
+}
+
+// ============================================================================
+// VALIDATOR ORACLES
+// ============================================================================
+
+fn set_active(validator: Pubkey, admin: Pubkey, active: bool) -> Instruction {
+    ix(
+        accounts::SetValidatorActive {
+            validator_oracle: pda::validator(&validator),
+            config: pda::config(),
+            admin,
+        },
+        instruction::SetValidatorActive { active },
+    )
+}
+
+fn deregister(oracle: Pubkey, validator: Pubkey) -> Instruction {
+    ix(
+        accounts::DeregisterValidator {
+            validator_oracle: oracle,
+            validator,
+        },
+        instruction::DeregisterValidator {},
+    )
+}
+
+#[test]
+fn the_admin_toggles_validators() {
+    let mut svm = Svm::new();
+    let admin = config(&mut svm);
+    let validator_wallet = svm.wallet();
+    let oracle = validator(&mut svm, validator_wallet, SOL);
+
+    svm.process(set_active(validator_wallet, admin, false))
+        .unwrap();
+    assert!(!svm.account::<ValidatorOracle>(&oracle).active);
+
+    svm.process(set_active(validator_wallet, admin, true))
+        .unwrap();
+    assert!(svm.account::<ValidatorOracle>(&oracle).active);
+
+    let stranger = svm.wallet();
+    assert_error(
+        svm.process(set_active(validator_wallet, stranger, false)),
+        AnchorError::ConstraintHasOne,
+    );
+}
+
+#[test]
+fn deregistering_returns_stake_and_rent() {
+    let mut svm = Svm::new();
+    let validator_wallet = svm.wallet();
+    let oracle = validator(&mut svm, validator_wallet, 2 * SOL);
+    let locked = svm.lamports(&oracle);
+    let before = svm.lamports(&validator_wallet);
+
+    // Only the validator itself can close its oracle
+    let stranger = svm.wallet();
+    assert_error(
+        svm.process(deregister(oracle, stranger)),
+        AnchorError::ConstraintSeeds,
+    );
+
+    svm.process(deregister(oracle, validator_wallet)).unwrap();
+    assert!(!svm.exists(&oracle));
+    assert_eq!(svm.lamports(&validator_wallet), before + locked);
+}
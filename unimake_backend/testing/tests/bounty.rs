@@ -0,0 +1,565 @@
+#![cfg(program_built)]
+
+use anchor_lang::error::ErrorCode as AnchorError;
+use anchor_lang::prelude::*;
+use unimake_backend::{
+    accounts, instruction, AttestationPolicy, BountySponsor, BountyStatus, ErrorCode,
+    RejectionReason, RewardTier,
+};
+use unimake_testing::*;
+
+fn setup() -> (Svm, Bounty) {
+    let mut svm = Svm::new();
+    let authority = svm.wallet();
+    let bounty = open_bounty(&mut svm, authority, "bounty", SOL / 10, 10);
+    (svm, bounty)
+}
+
+#[test]
+fn pause_and_resume_toggle_the_status() {
+    let (mut svm, bounty) = setup();
+
+    svm.process(ix(bounty.update(), instruction::PauseBounty {}))
+        .unwrap();
+    assert!(bounty.state(&svm).status == BountyStatus::Paused);
+    assert_error(
+        svm.process(ix(bounty.update(), instruction::PauseBounty {})),
+        ErrorCode::InvalidStatus,
+    );
+
+    svm.process(ix(bounty.update(), instruction::ResumeBounty {}))
+        .unwrap();
+    assert!(bounty.state(&svm).status == BountyStatus::Active);
+    assert_error(
+        svm.process(ix(bounty.update(), instruction::ResumeBounty {})),
+        ErrorCode::InvalidStatus,
+    );
+}
+
+#[test]
+fn only_the_signing_authority_can_update_a_bounty() {
+    let (mut svm, bounty) = setup();
+    let stranger = svm.wallet();
+
+    let accounts = accounts::UpdateBounty {
+        bounty_pool: bounty.pool,
+        authority: stranger,
+    };
+    assert_error(
+        svm.process(ix(accounts, instruction::PauseBounty {})),
+        AnchorError::ConstraintHasOne,
+    );
+    assert_not_signed(svm.process(unsigned(bounty.complete(), &bounty.authority)));
+    assert!(bounty.state(&svm).status == BountyStatus::Active);
+}
+
+#[test]
+fn complete_bounty_closes_it_to_submissions() {
+    let (mut svm, bounty) = setup();
+    let contributor = svm.wallet();
+    submit(&mut svm, &bounty, contributor, "pending");
+    let before = svm.lamports(&bounty.authority);
+
+    svm.process(bounty.complete()).unwrap();
+    let pool = bounty.state(&svm);
+    assert!(pool.status == BountyStatus::Completed);
+    // The unreserved rewards go back; the pending submission keeps its escrow
+    assert_eq!(svm.lamports(&bounty.authority) - before, 9 * SOL / 10);
+    assert_eq!(pool.remaining_pool, 0);
+    assert_error(
+        svm.process(ix(bounty.update(), instruction::ResumeBounty {})),
+        ErrorCode::InvalidStatus,
+    );
+    assert_error(svm.process(bounty.complete()), ErrorCode::InvalidStatus);
+    assert_error(svm.process(bounty.cancel()), ErrorCode::InvalidStatus);
+}
+
+#[test]
+fn cancel_refunds_the_unspent_pool_and_keeps_pending_escrow() {
+    let (mut svm, bounty) = setup();
+    let contributor = svm.wallet();
+    let submission = submit(&mut svm, &bounty, contributor, "pending");
+    bounty.edit(&mut svm, |pool| pool.bonus_pool = SOL / 20);
+    svm.airdrop(&bounty.pool, SOL / 20);
+    let pool_rent = svm.lamports(&bounty.pool) - SOL - SOL / 20;
+    let before = svm.lamports(&bounty.authority);
+
+    svm.process(bounty.cancel()).unwrap();
+
+    // Nine unreserved rewards and the bonus pool go back; one reward stays in escrow
+    assert_eq!(
+        svm.lamports(&bounty.authority) - before,
+        9 * SOL / 10 + SOL / 20
+    );
+    assert_eq!(svm.lamports(&bounty.pool), pool_rent + SOL / 10);
+    let pool = bounty.state(&svm);
+    assert!(pool.status == BountyStatus::Cancelled);
+    assert_eq!((pool.remaining_pool, pool.bonus_pool), (0, 0));
+    assert_error(svm.process(bounty.cancel()), ErrorCode::InvalidStatus);
+
+    // The pending submission can still be paid from its escrow
+    approve(&mut svm, &bounty, submission, contributor, 70);
+    assert_eq!(svm.lamports(&bounty.pool), pool_rent);
+}
+
+#[test]
+fn expired_bounties_close_permissionlessly_after_expiry() {
+    let (mut svm, bounty) = setup();
+    assert_error(
+        svm.process(bounty.close_expired()),
+        ErrorCode::BountyNotExpired,
+    );
+
+    svm.warp_to(bounty.state(&svm).expires_at);
+    let before = svm.lamports(&bounty.authority);
+    svm.process(bounty.close_expired()).unwrap();
+    assert_eq!(svm.lamports(&bounty.authority) - before, SOL);
+    assert!(bounty.state(&svm).status == BountyStatus::Expired);
+
+    assert_error(
+        svm.process(bounty.close_expired()),
+        ErrorCode::InvalidStatus,
+    );
+}
+
+#[test]
+fn expired_bounty_refunds_go_to_the_recorded_authority() {
+    let (mut svm, bounty) = setup();
+    let stranger = svm.wallet();
+    svm.warp_to(bounty.state(&svm).expires_at);
+
+    let close = accounts::CloseExpiredBounty {
+        bounty_pool: bounty.pool,
+        authority: stranger,
+    };
+    assert_error(
+        svm.process(ix(close, instruction::CloseExpiredBounty {})),
+        AnchorError::ConstraintHasOne,
+    );
+}
+
+#[test]
+fn sponsors_claim_their_share_of_a_cancelled_bounty_once() {
+    let (mut svm, bounty) = setup();
+    let backer = svm.wallet();
+    let record = sponsor(&mut svm, &bounty, backer, SOL);
+    let contributor = svm.wallet();
+    let submission = submit(&mut svm, &bounty, contributor, "paid");
+    approve(&mut svm, &bounty, submission, contributor, 80);
+
+    assert_error(
+        svm.process(ix(
+            bounty.sponsor_refund(backer),
+            instruction::ClaimSponsorRefund {},
+        )),
+        ErrorCode::InvalidStatus,
+    );
+
+    let before_authority = svm.lamports(&bounty.authority);
+    svm.process(bounty.cancel()).unwrap();
+
+    // 2 SOL funded half each, 0.1 SOL paid out: each side gets half of 1.9 SOL
+    let share = 19 * SOL / 20;
+    assert_eq!(svm.lamports(&bounty.authority) - before_authority, share);
+
+    let before_backer = svm.lamports(&backer);
+    svm.process(ix(
+        bounty.sponsor_refund(backer),
+        instruction::ClaimSponsorRefund {},
+    ))
+    .unwrap();
+    assert_eq!(svm.lamports(&backer) - before_backer, share);
+    assert_eq!(svm.account::<BountySponsor>(&record).refunded, share);
+
+    assert_error(
+        svm.process(ix(
+            bounty.sponsor_refund(backer),
+            instruction::ClaimSponsorRefund {},
+        )),
+        ErrorCode::RefundAlreadyClaimed,
+    );
+}
+
+#[test]
+fn escrow_returned_after_close_is_shared_by_later_claims() {
+    let (mut svm, bounty) = setup();
+    let backer = svm.wallet();
+    let record = sponsor(&mut svm, &bounty, backer, SOL);
+    let contributor = svm.wallet();
+    let submission = submit(&mut svm, &bounty, contributor, "late");
+    let claim = || {
+        ix(
+            bounty.sponsor_refund(backer),
+            instruction::ClaimSponsorRefund {},
+        )
+    };
+
+    assert_error(
+        svm.process(bounty.authority_refund()),
+        ErrorCode::InvalidStatus,
+    );
+    svm.process(bounty.complete()).unwrap();
+    assert_error(
+        svm.process(bounty.authority_refund()),
+        ErrorCode::RefundAlreadyClaimed,
+    );
+
+    // Sponsors claim from completed bounties too: half of the 1.9 SOL left unreserved
+    let before_backer = svm.lamports(&backer);
+    svm.process(claim()).unwrap();
+    assert_eq!(svm.lamports(&backer) - before_backer, 19 * SOL / 20);
+    assert_error(svm.process(claim()), ErrorCode::RefundAlreadyClaimed);
+
+    // The rejected escrow lands in the refund pool rather than the closed pool
+    svm.process(ix(
+        bounty.review(&svm, submission, contributor),
+        instruction::RejectSubmission {
+            reason: RejectionReason::WrongTask,
+            feedback_uri: String::new(),
+        },
+    ))
+    .unwrap();
+    let pool = bounty.state(&svm);
+    assert_eq!(pool.remaining_pool, 0);
+    assert_eq!(pool.refund_pool, 2 * SOL);
+
+    let before_backer = svm.lamports(&backer);
+    svm.process(claim()).unwrap();
+    assert_eq!(svm.lamports(&backer) - before_backer, SOL / 20);
+    assert_eq!(svm.account::<BountySponsor>(&record).refunded, SOL);
+
+    let before_authority = svm.lamports(&bounty.authority);
+    svm.process(bounty.authority_refund()).unwrap();
+    assert_eq!(svm.lamports(&bounty.authority) - before_authority, SOL / 20);
+    assert_eq!(bounty.state(&svm).authority_refunded, SOL);
+    assert_error(
+        svm.process(bounty.authority_refund()),
+        ErrorCode::RefundAlreadyClaimed,
+    );
+}
+
+#[test]
+fn sponsors_share_dataset_sales_by_deposit() {
+    let (mut svm, bounty) = setup();
+    let (backer, other) = (svm.wallet(), svm.wallet());
+    let record = sponsor(&mut svm, &bounty, backer, 3 * SOL);
+    sponsor(&mut svm, &bounty, other, SOL);
+    svm.process(bounty.complete()).unwrap();
+    let claim = |sponsor| {
+        ix(
+            bounty.sponsor_refund(sponsor),
+            instruction::ClaimSponsorProceeds {},
+        )
+    };
+    assert_error(svm.process(claim(backer)), ErrorCode::RefundAlreadyClaimed);
+
+    // Sales of datasets built from the bounty, as `purchase_dataset` leaves them
+    let sell = |svm: &mut Svm, share: u64| {
+        bounty.edit(svm, |pool| pool.sponsor_proceeds += share);
+        svm.airdrop(&bounty.pool, share);
+    };
+    sell(&mut svm, SOL / 5);
+    let before = svm.lamports(&backer);
+    svm.process(claim(backer)).unwrap();
+    assert_eq!(svm.lamports(&backer) - before, 3 * SOL / 20);
+    assert_error(svm.process(claim(backer)), ErrorCode::RefundAlreadyClaimed);
+
+    // Later sales add to what each sponsor can claim
+    sell(&mut svm, SOL / 5);
+    let before = svm.lamports(&backer);
+    svm.process(claim(backer)).unwrap();
+    assert_eq!(svm.lamports(&backer) - before, 3 * SOL / 20);
+    assert_eq!(
+        svm.account::<BountySponsor>(&record).proceeds_claimed,
+        3 * SOL / 10
+    );
+    let before = svm.lamports(&other);
+    svm.process(claim(other)).unwrap();
+    assert_eq!(svm.lamports(&other) - before, SOL / 10);
+}
+
+#[test]
+fn sponsor_records_belong_to_their_sponsor() {
+    let (mut svm, bounty) = setup();
+    let backer = svm.wallet();
+    let record = sponsor(&mut svm, &bounty, backer, SOL);
+    let stranger = svm.wallet();
+    bounty.edit(&mut svm, |pool| pool.status = BountyStatus::Cancelled);
+
+    let claim = accounts::ClaimSponsorRefund {
+        bounty_pool: bounty.pool,
+        sponsor_record: record,
+        sponsor: stranger,
+    };
+    assert_error(
+        svm.process(ix(claim, instruction::ClaimSponsorRefund {})),
+        AnchorError::ConstraintSeeds,
+    );
+}
+
+#[test]
+fn contributions_are_checked_before_any_transfer() {
+    let (mut svm, bounty) = setup();
+    let backer = svm.wallet();
+    let record = sponsor(&mut svm, &bounty, backer, SOL);
+    let contribute = |amount| {
+        ix(
+            accounts::ContributeToBounty {
+                bounty_pool: bounty.pool,
+                sponsor_record: record,
+                sponsor: backer,
+                system_program: anchor_lang::system_program::ID,
+            },
+            instruction::ContributeToBounty { amount },
+        )
+    };
+
+    assert_error(svm.process(contribute(0)), ErrorCode::InvalidAmount);
+
+    svm.warp_to(bounty.state(&svm).expires_at);
+    assert_error(svm.process(contribute(SOL)), ErrorCode::BountyExpired);
+
+    bounty.edit(&mut svm, |pool| pool.status = BountyStatus::Completed);
+    assert_error(svm.process(contribute(SOL)), ErrorCode::BountyNotActive);
+}
+
+#[test]
+fn bonus_pool_deposits_must_be_positive() {
+    let (mut svm, bounty) = setup();
+
+    let fund = accounts::FundBonusPool {
+        bounty_pool: bounty.pool,
+        authority: bounty.authority,
+        payer: bounty.authority,
+        system_program: anchor_lang::system_program::ID,
+    };
+    assert_error(
+        svm.process(ix(fund, instruction::FundBonusPool { amount: 0 })),
+        ErrorCode::InvalidAmount,
+    );
+}
+
+#[test]
+fn attestation_policies_are_validated() {
+    let (mut svm, bounty) = setup();
+    let policy = |approve, reject| AttestationPolicy {
+        auto_approve_min_score: approve,
+        auto_reject_max_score: reject,
+        min_validator_stake: SOL,
+    };
+
+    svm.process(ix(
+        bounty.update(),
+        instruction::SetAttestationPolicy {
+            policy: policy(Some(90), Some(20)),
+        },
+    ))
+    .unwrap();
+    let stored = bounty.state(&svm).attestation_policy;
+    assert_eq!(stored.auto_approve_min_score, Some(90));
+    assert_eq!(stored.auto_reject_max_score, Some(20));
+    assert_eq!(stored.min_validator_stake, SOL);
+
+    for (approve, reject) in [(Some(101), None), (None, Some(101)), (Some(50), Some(50))] {
+        assert_error(
+            svm.process(ix(
+                bounty.update(),
+                instruction::SetAttestationPolicy {
+                    policy: policy(approve, reject),
+                },
+            )),
+            ErrorCode::InvalidAttestationPolicy,
+        );
+    }
+}
+
+#[test]
+fn bond_reuse_and_reputation_settings_are_stored() {
+    let (mut svm, bounty) = setup();
+
+    svm.process(ix(
+        bounty.update(),
+        instruction::SetSubmissionBond { bond: SOL / 100 },
+    ))
+    .unwrap();
+    svm.process(ix(
+        bounty.update(),
+        instruction::SetContentReuse { allow: true },
+    ))
+    .unwrap();
+    svm.process(ix(
+        bounty.update(),
+        instruction::SetReputationGate {
+            min_category_reputation: 1000,
+        },
+    ))
+    .unwrap();
+
+    let pool = bounty.state(&svm);
+    assert_eq!(pool.submission_bond, SOL / 100);
+    assert!(pool.allow_content_reuse);
+    assert_eq!(pool.min_category_reputation, 1000);
+
+    assert_error(
+        svm.process(ix(
+            bounty.update(),
+            instruction::SetReputationGate {
+                min_category_reputation: 1001,
+            },
+        )),
+        ErrorCode::InvalidReputationGate,
+    );
+}
+
+#[test]
+fn reward_tier_tables_are_validated() {
+    let (mut svm, bounty) = setup();
+    let tier = |min_score, payout_bps| RewardTier {
+        min_score,
+        payout_bps,
+    };
+
+    let tiers = vec![tier(0, 5_000), tier(80, 15_000)];
+    svm.process(ix(
+        bounty.update(),
+        instruction::SetRewardTiers {
+            tiers: tiers.clone(),
+        },
+    ))
+    .unwrap();
+    assert!(bounty.state(&svm).reward_tiers == tiers);
+
+    for tiers in [
+        vec![tier(10, 10_000)],
+        vec![tier(0, 10_000), tier(0, 12_000)],
+        vec![tier(0, 20_001)],
+        (0..6).map(|i| tier(i * 10, 10_000)).collect(),
+    ] {
+        assert_error(
+            svm.process(ix(bounty.update(), instruction::SetRewardTiers { tiers })),
+            ErrorCode::InvalidRewardTiers,
+        );
+    }
+}
+
+#[test]
+fn review_panels_are_validated() {
+    let (mut svm, bounty) = setup();
+    let reviewers: Vec<Pubkey> = (0..3).map(|_| Pubkey::new_unique()).collect();
+
+    svm.process(ix(
+        bounty.update(),
+        instruction::SetReviewPanel {
+            reviewers: reviewers.clone(),
+            required_approvals: 2,
+        },
+    ))
+    .unwrap();
+    let pool = bounty.state(&svm);
+    assert_eq!(pool.reviewers, reviewers);
+    assert_eq!(pool.required_approvals, 2);
+
+    let duplicate = vec![reviewers[0], reviewers[0]];
+    let too_many: Vec<Pubkey> = (0..6).map(|_| Pubkey::new_unique()).collect();
+    for (reviewers, required_approvals) in [(duplicate, 1), (reviewers, 4), (too_many, 1)] {
+        assert_error(
+            svm.process(ix(
+                bounty.update(),
+                instruction::SetReviewPanel {
+                    reviewers,
+                    required_approvals,
+                },
+            )),
+            ErrorCode::InvalidReviewPanel,
+        );
+    }
+}
+
+#[test]
+fn authority_handover_takes_a_proposal_and_an_acceptance() {
+    let (mut svm, bounty) = setup();
+    let successor = svm.wallet();
+    // The successor already runs a bounty, so its buyer profile exists
+    open_bounty(&mut svm, successor, "successor", SOL / 10, 1);
+    let stranger = svm.wallet();
+    let accept = |new_authority| {
+        ix(
+            accounts::AcceptBountyAuthority {
+                bounty_pool: bounty.pool,
+                buyer_profile: pda::buyer(&new_authority),
+                new_authority,
+                payer: new_authority,
+                system_program: anchor_lang::system_program::ID,
+            },
+            instruction::AcceptBountyAuthority {},
+        )
+    };
+
+    for target in [bounty.authority, Pubkey::default()] {
+        assert_error(
+            svm.process(ix(
+                bounty.update(),
+                instruction::ProposeBountyAuthority {
+                    new_authority: target,
+                },
+            )),
+            ErrorCode::InvalidTransferTarget,
+        );
+    }
+    assert_error(
+        svm.process(ix(
+            bounty.update(),
+            instruction::CancelBountyAuthorityTransfer {},
+        )),
+        ErrorCode::NoPendingTransfer,
+    );
+    assert_error(
+        svm.process(accept(successor)),
+        ErrorCode::NotPendingAuthority,
+    );
+
+    svm.process(ix(
+        bounty.update(),
+        instruction::ProposeBountyAuthority {
+            new_authority: successor,
+        },
+    ))
+    .unwrap();
+    assert_eq!(bounty.state(&svm).pending_authority, Some(successor));
+    open_bounty(&mut svm, stranger, "stranger", SOL / 10, 1);
+    assert_error(
+        svm.process(accept(stranger)),
+        ErrorCode::NotPendingAuthority,
+    );
+
+    svm.process(accept(successor)).unwrap();
+    let pool = bounty.state(&svm);
+    assert_eq!(pool.authority, successor);
+    assert_eq!(pool.pending_authority, None);
+
+    // The previous authority has lost control
+    assert_error(
+        svm.process(ix(bounty.update(), instruction::PauseBounty {})),
+        AnchorError::ConstraintHasOne,
+    );
+}
+
+#[test]
+fn pending_handovers_can_be_withdrawn() {
+    let (mut svm, bounty) = setup();
+    let successor = svm.wallet();
+
+    svm.process(ix(
+        bounty.update(),
+        instruction::ProposeBountyAuthority {
+            new_authority: successor,
+        },
+    ))
+    .unwrap();
+    svm.process(ix(
+        bounty.update(),
+        instruction::CancelBountyAuthorityTransfer {},
+    ))
+    .unwrap();
+    assert_eq!(bounty.state(&svm).pending_authority, None);
+}
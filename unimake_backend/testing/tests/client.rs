@@ -0,0 +1,405 @@
+//! The client against the program's SBF build: its PDAs and fetchers against the
+//! accounts the program stores, and its builders run through the program.
+
+#![cfg(program_built)]
+
+use std::cell::RefCell;
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use solana_transaction::Transaction;
+use unimake_backend::{
+    instruction, BadgeType, BountyPool, BountyStatus, ContributorProfile, DatasetNFT, LicenseType,
+    RejectionReason, ReviewDecision, SubmissionStatus, SuspensionReason, VideoSubmission,
+    MAX_BATCH_REVIEWS,
+};
+use unimake_client::accounts::{self as fetch, ProgramAccount};
+use unimake_client::{instructions, pda as client_pda, AccountSource, ClientError};
+use unimake_testing::*;
+
+/// The harness as an [`AccountSource`], recording the size of each batch fetch
+struct Source<'a> {
+    svm: &'a Svm,
+    batches: RefCell<Vec<usize>>,
+}
+
+fn source(svm: &Svm) -> Source<'_> {
+    Source {
+        svm,
+        batches: RefCell::default(),
+    }
+}
+
+impl AccountSource for Source<'_> {
+    fn account_data(&self, address: &Pubkey) -> unimake_client::Result<Option<Vec<u8>>> {
+        Ok(self
+            .svm
+            .get_account(address)
+            .map(|account| account.data.clone()))
+    }
+
+    fn accounts_data(&self, addresses: &[Pubkey]) -> unimake_client::Result<Vec<Option<Vec<u8>>>> {
+        self.batches.borrow_mut().push(addresses.len());
+        addresses
+            .iter()
+            .map(|address| self.account_data(address))
+            .collect()
+    }
+}
+
+#[test]
+fn pdas_match_the_program() {
+    let mut svm = Svm::new();
+    let authority = svm.wallet();
+    let contributor = svm.wallet();
+    let bounty = open_bounty(&mut svm, authority, "pdas", SOL / 10, 2);
+    let submission = submit(&mut svm, &bounty, contributor, "video");
+    let dataset = dataset(&mut svm, &bounty, "kitchen-set", SOL);
+
+    // Each fixture account only decodes at the address the program derives for it
+    assert_eq!(client_pda::bounty(&bounty.id).0, bounty.pool);
+    assert_eq!(client_pda::submission(&bounty.pool, 0).0, submission);
+    assert_eq!(
+        client_pda::buyer_profile(&authority).0,
+        bounty.buyer_profile()
+    );
+    assert_eq!(
+        client_pda::profile(&contributor).0,
+        pda::profile(&contributor)
+    );
+    assert_eq!(
+        client_pda::category_reputation(&contributor, CATEGORY).0,
+        pda::category(&contributor, CATEGORY)
+    );
+    assert_eq!(client_pda::dataset(&id16("kitchen-set")).0, dataset);
+    assert_eq!(
+        client_pda::license(&dataset, &contributor).0,
+        pda::license(&dataset, &contributor)
+    );
+    assert_eq!(client_pda::config().0, pda::config());
+    assert_eq!(
+        client_pda::validator(&contributor).0,
+        pda::validator(&contributor)
+    );
+    assert_eq!(client_pda::badge_definition(7).0, pda::badge_definition(7));
+    assert_eq!(
+        client_pda::badge_mint(&BadgeType::HighQuality).0,
+        pda::badge_mint(BadgeType::HighQuality.seed())
+    );
+    assert_eq!(client_pda::bounty(&bounty.id).1, pda::bump(&bounty.pool));
+}
+
+#[test]
+fn bounty_builders_drive_the_lifecycle() {
+    let mut svm = Svm::new();
+    let authority = svm.wallet();
+    let bounty = open_bounty(&mut svm, authority, "lifecycle", SOL / 10, 2);
+
+    svm.process(instructions::pause_bounty(&bounty.id, &authority))
+        .unwrap();
+    let paused = fetch::fetch_bounty(&source(&svm), &bounty.id).unwrap();
+    assert!(paused.status == BountyStatus::Paused);
+
+    svm.process(instructions::resume_bounty(&bounty.id, &authority))
+        .unwrap();
+    svm.process(instructions::complete_bounty(&bounty.id, &authority))
+        .unwrap();
+    let completed = fetch::fetch_bounty(&source(&svm), &bounty.id).unwrap();
+    assert!(completed.status == BountyStatus::Completed);
+
+    let other = open_bounty(&mut svm, authority, "cancelled", SOL / 10, 2);
+    svm.process(instructions::cancel_bounty(&other.id, &authority))
+        .unwrap();
+    let cancelled = fetch::fetch_bounty(&source(&svm), &other.id).unwrap();
+    assert!(cancelled.status == BountyStatus::Cancelled);
+}
+
+/// Largest serialized transaction a validator accepts (`PACKET_DATA_SIZE`)
+const PACKET_DATA_SIZE: u64 = 1232;
+/// Most accounts one transaction may lock
+const MAX_TX_ACCOUNT_LOCKS: usize = 64;
+
+/// A bounty with `count` pending submissions from distinct contributors
+fn pending_batch(svm: &mut Svm, label: &str, count: usize) -> (BountyPool, Vec<VideoSubmission>) {
+    let authority = svm.wallet();
+    let bounty = open_bounty(svm, authority, label, SOL / 10, count as u32);
+    for index in 0..count {
+        let contributor = svm.wallet();
+        submit(svm, &bounty, contributor, &format!("{label}-{index}"));
+    }
+    let pool = fetch::fetch_bounty(&source(svm), &bounty.id).unwrap();
+    let pending = fetch::fetch_pending_submissions(&source(svm), &pool)
+        .unwrap()
+        .into_iter()
+        .map(|(_, submission)| submission)
+        .collect();
+    (pool, pending)
+}
+
+fn approve_all(pool: &BountyPool, submissions: &[VideoSubmission]) -> Instruction {
+    let approve = ReviewDecision::Approve { quality_score: 80 };
+    let items: Vec<_> = submissions
+        .iter()
+        .map(|submission| (submission, approve))
+        .collect();
+    instructions::batch_review(pool, &items, true)
+}
+
+/// Size of a legacy transaction carrying `ix`, signed by the bounty authority
+fn transaction_size(pool: &BountyPool, ix: &Instruction) -> (u64, usize) {
+    let transaction = Transaction::new_with_payer(std::slice::from_ref(ix), Some(&pool.authority));
+    (
+        bincode::serialized_size(&transaction).unwrap(),
+        transaction.message.account_keys.len(),
+    )
+}
+
+#[test]
+fn a_maximum_size_batch_fits_in_one_transaction() {
+    let mut svm = Svm::new();
+    let (pool, pending) = pending_batch(&mut svm, "max-batch", MAX_BATCH_REVIEWS);
+
+    let ix = approve_all(&pool, &pending);
+    let (size, keys) = transaction_size(&pool, &ix);
+    assert!(size <= PACKET_DATA_SIZE);
+    assert!(keys <= MAX_TX_ACCOUNT_LOCKS);
+
+    svm.process(ix).unwrap();
+    let pool = fetch::fetch_bounty(&source(&svm), &pool.bounty_id).unwrap();
+    assert_eq!(pool.videos_collected as usize, MAX_BATCH_REVIEWS);
+
+    // One more item no longer fits, so the program cap is the real limit
+    let (pool, pending) = pending_batch(&mut svm, "over-batch", MAX_BATCH_REVIEWS + 1);
+    let (size, _) = transaction_size(&pool, &approve_all(&pool, &pending));
+    assert!(size > PACKET_DATA_SIZE);
+}
+
+#[test]
+fn review_builders_settle_pending_submissions() {
+    let mut svm = Svm::new();
+    let authority = svm.wallet();
+    let contributor = svm.wallet();
+    let bounty = open_bounty(&mut svm, authority, "reviews", SOL / 10, 4);
+    for label in ["first", "second", "third", "fourth"] {
+        submit(&mut svm, &bounty, contributor, label);
+    }
+
+    let pool = fetch::fetch_bounty(&source(&svm), &bounty.id).unwrap();
+    let pending = fetch::fetch_pending_submissions(&source(&svm), &pool).unwrap();
+    assert_eq!(pending.len(), 4);
+    assert_eq!(pending[0].0, pda::submission(&bounty.pool, 0));
+
+    svm.process(instructions::approve_submission(&pool, &pending[0].1, 90))
+        .unwrap();
+    svm.process(instructions::reject_submission(
+        &pool,
+        &pending[1].1,
+        RejectionReason::LowResolution,
+        "https://feedback/second".to_string(),
+    ))
+    .unwrap();
+    svm.process(instructions::batch_review(
+        &pool,
+        &[
+            (&pending[2].1, ReviewDecision::Approve { quality_score: 70 }),
+            (
+                &pending[3].1,
+                ReviewDecision::Reject {
+                    reason: RejectionReason::Duplicate,
+                },
+            ),
+        ],
+        true,
+    ))
+    .unwrap();
+
+    let pool = fetch::fetch_bounty(&source(&svm), &bounty.id).unwrap();
+    assert!(fetch::fetch_pending_submissions(&source(&svm), &pool)
+        .unwrap()
+        .is_empty());
+    let statuses: Vec<_> = fetch::fetch_submissions(&source(&svm), &pool)
+        .unwrap()
+        .into_iter()
+        .map(|(_, submission)| (submission.status, submission.quality_score))
+        .collect();
+    assert!(
+        statuses
+            == [
+                (SubmissionStatus::Approved, 90),
+                (SubmissionStatus::Rejected, 0),
+                (SubmissionStatus::Approved, 70),
+                (SubmissionStatus::Rejected, 0),
+            ]
+    );
+    assert_eq!(pool.videos_collected, 2);
+}
+
+#[test]
+fn submissions_are_fetched_in_batches() {
+    let mut svm = Svm::new();
+    let authority = svm.wallet();
+    let contributor = svm.wallet();
+    let bounty = open_bounty(&mut svm, authority, "batches", SOL / 1000, 250);
+    for sequence in 0..250 {
+        submit(&mut svm, &bounty, contributor, &format!("video-{sequence}"));
+    }
+
+    let pool = bounty.state(&svm);
+    let source = source(&svm);
+    let submissions = fetch::fetch_submissions(&source, &pool).unwrap();
+    assert_eq!(*source.batches.borrow(), [100, 100, 50]);
+    assert_eq!(submissions.len(), 250);
+    for (sequence, (address, submission)) in submissions.iter().enumerate() {
+        assert_eq!(*address, pda::submission(&bounty.pool, sequence as u32));
+        assert_eq!(submission.sequence, sequence as u32);
+    }
+}
+
+#[test]
+fn profile_builders_award_refresh_and_suspend() {
+    let mut svm = Svm::new();
+    let admin = config(&mut svm);
+    let contributor = svm.wallet();
+    let newcomer = svm.wallet();
+    contributor_profile(&mut svm, contributor);
+    assert!(fetch::fetch_profile(&source(&svm), &newcomer)
+        .unwrap()
+        .is_none());
+
+    svm.process(instructions::award_badge(
+        &contributor,
+        &admin,
+        BadgeType::EarlyAdopter,
+    ))
+    .unwrap();
+    svm.advance(30 * DAY);
+    svm.process(instructions::refresh_reputation(&contributor))
+        .unwrap();
+    svm.process(instructions::suspend_contributor(
+        &contributor,
+        &admin,
+        SuspensionReason::Spam,
+        0,
+    ))
+    .unwrap();
+
+    let profile = fetch::fetch_profile(&source(&svm), &contributor)
+        .unwrap()
+        .unwrap();
+    assert!(profile.has_badge(&BadgeType::EarlyAdopter));
+    assert_eq!(profile.suspended_until, i64::MAX);
+
+    svm.process(instructions::lift_suspension(&contributor, &admin))
+        .unwrap();
+    let profile = fetch::fetch_profile(&source(&svm), &contributor)
+        .unwrap()
+        .unwrap();
+    assert_eq!(profile.suspended_until, 0);
+}
+
+#[test]
+fn dataset_builders_hand_over_the_creator() {
+    let mut svm = Svm::new();
+    let creator = svm.wallet();
+    let successor = svm.wallet();
+    let id = id16("kitchen-set");
+    let bounty = open_bounty(&mut svm, creator, "source", SOL / 10, 2);
+    dataset(&mut svm, &bounty, "kitchen-set", SOL);
+
+    svm.process(instructions::propose_dataset_creator(
+        &id, &creator, &successor,
+    ))
+    .unwrap();
+    svm.process(instructions::accept_dataset_creator(&id, &successor))
+        .unwrap();
+
+    let stored = fetch::fetch_dataset(&source(&svm), &id).unwrap();
+    assert_eq!(stored.creator, successor);
+    assert!(stored.pending_creator.is_none());
+    assert!(
+        fetch::fetch_license(&source(&svm), &client_pda::dataset(&id).0, &successor)
+            .unwrap()
+            .is_none()
+    );
+}
+
+#[test]
+fn creating_builders_match_the_program_accounts() {
+    let mut svm = Svm::new();
+    let authority = svm.wallet();
+    let contributor = svm.wallet();
+    let bounty = open_bounty(&mut svm, authority, "creating", SOL / 10, 2);
+    submit(&mut svm, &bounty, contributor, "first");
+    let pool = bounty.state(&svm);
+
+    let submit = instructions::submit_video(
+        &pool,
+        &contributor,
+        instruction::SubmitVideo {
+            submission_id: id16("second"),
+            content_hash: content_hash("second"),
+            ipfs_hash: "QmTest".to_string(),
+            arweave_tx: "ArweaveTest".to_string(),
+            metadata_uri: "https://metadata/test".to_string(),
+            spec: spec(),
+        },
+    );
+    let keys: Vec<_> = submit.accounts.iter().map(|meta| meta.pubkey).collect();
+    assert_eq!(keys[0], pda::submission(&bounty.pool, 1));
+    assert_eq!(keys[3], bounty.pool);
+    assert_eq!(keys[5], pda::category(&contributor, CATEGORY));
+    assert!(submit.accounts[6].is_signer);
+
+    let key = dataset(&mut svm, &bounty, "kitchen-set", SOL);
+    let stored = fetch::fetch_dataset(&source(&svm), &id16("kitchen-set")).unwrap();
+    let purchase = instructions::purchase_dataset(&stored, &contributor);
+    assert_eq!(purchase.accounts[0].pubkey, key);
+    assert_eq!(
+        purchase.accounts[3].pubkey,
+        pda::license(&key, &contributor)
+    );
+    assert!(purchase.accounts[1].is_signer && !purchase.accounts[2].is_signer);
+    assert_eq!(purchase.accounts[4].pubkey, bounty.pool);
+    assert!(purchase.accounts[4].is_writable);
+
+    let create = instructions::create_dataset(
+        &authority,
+        &bounty.id,
+        instruction::CreateDataset {
+            dataset_id: id16("bounty-set"),
+            license_type: LicenseType::Unlimited,
+            price: SOL,
+            royalty_percentage: 10,
+        },
+    );
+    assert_eq!(create.accounts[0].pubkey, pda::dataset(&id16("bounty-set")));
+    assert_eq!(create.accounts[2].pubkey, bounty.pool);
+}
+
+#[test]
+fn accounts_decode_by_discriminator() {
+    let mut svm = Svm::new();
+    let creator = svm.wallet();
+    let bounty = open_bounty(&mut svm, creator, "source", SOL / 10, 2);
+    let key = dataset(&mut svm, &bounty, "kitchen-set", SOL);
+    let data = &svm.get_account(&key).unwrap().data;
+
+    let decoded = ProgramAccount::decode(&key, data).unwrap();
+    assert_eq!(decoded.name(), "DatasetNFT");
+    let ProgramAccount::DatasetNFT(stored) = decoded else {
+        panic!("decoded as {}", decoded.name());
+    };
+    assert!(stored.license_type == LicenseType::Unlimited);
+
+    // A typed fetch of the wrong account type fails rather than misreading it
+    assert!(matches!(
+        fetch::fetch::<ContributorProfile>(&source(&svm), &key),
+        Err(ClientError::InvalidAccount { .. })
+    ));
+    let missing = client_pda::dataset(&id16("missing")).0;
+    assert!(matches!(
+        fetch::fetch::<DatasetNFT>(&source(&svm), &missing),
+        Err(ClientError::AccountNotFound(address)) if address == missing
+    ));
+    assert!(ProgramAccount::decode(&key, &[0; 64]).is_err());
+}
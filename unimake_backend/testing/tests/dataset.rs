@@ -0,0 +1,173 @@
+#![cfg(program_built)]
+
+use anchor_lang::error::ErrorCode as AnchorError;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use unimake_backend::{accounts, instruction, DatasetLicense, DatasetNFT, ErrorCode, LicenseType};
+use unimake_testing::*;
+
+fn update(dataset: Pubkey, creator: Pubkey) -> accounts::UpdateDataset {
+    accounts::UpdateDataset {
+        dataset_nft: dataset,
+        creator,
+    }
+}
+
+fn purchase(dataset: Pubkey, buyer: Pubkey, creator: Pubkey, bounty: Pubkey) -> Instruction {
+    ix(
+        accounts::PurchaseDataset {
+            dataset_nft: dataset,
+            buyer,
+            creator,
+            license: pda::license(&dataset, &buyer),
+            bounty_pool: bounty,
+            system_program: anchor_lang::system_program::ID,
+        },
+        instruction::PurchaseDataset {},
+    )
+}
+
+/// A returning buyer's license already exists, so no account is created up front
+fn returning_buyer(svm: &mut Svm, dataset: Pubkey, buyer: Pubkey) {
+    let license = pda::license(&dataset, &buyer);
+    let now = svm.now();
+    put(
+        svm,
+        license,
+        &DatasetLicense {
+            dataset,
+            holder: buyer,
+            license_type: LicenseType::Unlimited,
+            purchases: 1,
+            total_paid: SOL,
+            first_purchased_at: now,
+            last_purchased_at: now,
+            bump: pda::bump(&license),
+        },
+        0,
+    );
+}
+
+fn propose(dataset: Pubkey, creator: Pubkey, new_creator: Pubkey) -> Instruction {
+    ix(
+        update(dataset, creator),
+        instruction::ProposeDatasetCreator { new_creator },
+    )
+}
+
+fn accept(dataset: Pubkey, new_creator: Pubkey) -> Instruction {
+    ix(
+        accounts::AcceptDatasetCreator {
+            dataset_nft: dataset,
+            new_creator,
+        },
+        instruction::AcceptDatasetCreator {},
+    )
+}
+
+#[test]
+fn creators_hand_datasets_over_in_two_steps() {
+    let mut svm = Svm::new();
+    let creator = svm.wallet();
+    let successor = svm.wallet();
+    let stranger = svm.wallet();
+    let source = open_bounty(&mut svm, creator, "source", SOL / 10, 2);
+    let dataset = dataset(&mut svm, &source, "kitchen-set", SOL);
+
+    assert_error(
+        svm.process(propose(dataset, creator, creator)),
+        ErrorCode::InvalidTransferTarget,
+    );
+    assert_error(
+        svm.process(propose(dataset, creator, Pubkey::default())),
+        ErrorCode::InvalidTransferTarget,
+    );
+    assert_error(
+        svm.process(propose(dataset, stranger, successor)),
+        AnchorError::ConstraintHasOne,
+    );
+
+    svm.process(propose(dataset, creator, successor)).unwrap();
+    assert!(svm.account::<DatasetNFT>(&dataset).pending_creator == Some(successor));
+
+    assert_error(
+        svm.process(accept(dataset, stranger)),
+        ErrorCode::NotPendingAuthority,
+    );
+    svm.process(accept(dataset, successor)).unwrap();
+    let stored: DatasetNFT = svm.account(&dataset);
+    assert_eq!(stored.creator, successor);
+    assert!(stored.pending_creator.is_none());
+
+    // The previous creator has no say any more
+    assert_error(
+        svm.process(propose(dataset, creator, stranger)),
+        AnchorError::ConstraintHasOne,
+    );
+}
+
+#[test]
+fn pending_creator_handovers_can_be_withdrawn() {
+    let mut svm = Svm::new();
+    let creator = svm.wallet();
+    let successor = svm.wallet();
+    let source = open_bounty(&mut svm, creator, "source", SOL / 10, 2);
+    let dataset = dataset(&mut svm, &source, "kitchen-set", SOL);
+    let cancel = || {
+        ix(
+            update(dataset, creator),
+            instruction::CancelDatasetCreatorTransfer {},
+        )
+    };
+
+    assert_error(svm.process(cancel()), ErrorCode::NoPendingTransfer);
+
+    svm.process(propose(dataset, creator, successor)).unwrap();
+    svm.process(cancel()).unwrap();
+    assert!(svm
+        .account::<DatasetNFT>(&dataset)
+        .pending_creator
+        .is_none());
+    assert_error(
+        svm.process(accept(dataset, successor)),
+        ErrorCode::NotPendingAuthority,
+    );
+}
+
+#[test]
+fn purchases_pay_the_current_creator_only() {
+    let mut svm = Svm::new();
+    let creator = svm.wallet();
+    let buyer = svm.wallet();
+    let stranger = svm.wallet();
+    let source = open_bounty(&mut svm, creator, "source", SOL / 10, 2);
+    let dataset = dataset(&mut svm, &source, "kitchen-set", SOL);
+
+    returning_buyer(&mut svm, dataset, buyer);
+
+    assert_error(
+        svm.process(purchase(dataset, buyer, stranger, source.pool)),
+        AnchorError::ConstraintAddress,
+    );
+    assert_eq!(svm.account::<DatasetNFT>(&dataset).total_sales, 0);
+}
+
+#[test]
+fn purchases_must_route_the_sponsor_share_to_the_source_bounty() {
+    let mut svm = Svm::new();
+    let creator = svm.wallet();
+    let buyer = svm.wallet();
+    let source = open_bounty(&mut svm, creator, "source", SOL / 10, 2);
+    let other = open_bounty(&mut svm, creator, "other", SOL / 10, 2);
+    let dataset = dataset(&mut svm, &source, "kitchen-set", SOL);
+    edit(&mut svm, &dataset, |dataset: &mut DatasetNFT| {
+        dataset.sponsor_bps = 5_000;
+    });
+    returning_buyer(&mut svm, dataset, buyer);
+
+    assert_error(
+        svm.process(purchase(dataset, buyer, creator, other.pool)),
+        ErrorCode::DatasetBountyMismatch,
+    );
+    assert_eq!(svm.account::<DatasetNFT>(&dataset).total_sales, 0);
+}
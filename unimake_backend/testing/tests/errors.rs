@@ -0,0 +1,518 @@
+//! Error codes raised by the instructions that create accounts, plus the dataset's
+//! source-bounty check end to end.
+
+#![cfg(program_built)]
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::InstructionData;
+use litesvm::types::TransactionResult;
+use unimake_backend::{
+    instruction as args, AttestationPolicy, AutoAwardRule, Badge, BadgeType, BountyPool,
+    ContributorProfile, DatasetNFT, ErrorCode, LicenseType, SuspensionReason, VideoSubmission,
+};
+use unimake_client::{instructions, pda};
+use unimake_testing::*;
+
+fn settings(svm: &mut Svm, authority: Pubkey, bounty_id: &[u8; 16], data: impl InstructionData) {
+    svm.process(instructions::update_bounty_settings(
+        bounty_id, &authority, data,
+    ))
+    .expect("update_bounty_settings");
+}
+
+fn review_panel(svm: &mut Svm, authority: Pubkey, bounty_id: &[u8; 16], reviewers: &[Pubkey]) {
+    settings(
+        svm,
+        authority,
+        bounty_id,
+        args::SetReviewPanel {
+            reviewers: reviewers.to_vec(),
+            required_approvals: 1,
+        },
+    );
+}
+
+fn register_validator(svm: &mut Svm, admin: Pubkey, validator: Pubkey, stake: u64) {
+    svm.process(instructions::register_validator(&validator, &admin, stake))
+        .expect("register_validator");
+}
+
+fn attest(
+    svm: &mut Svm,
+    bounty: &BountyPool,
+    submission: &VideoSubmission,
+    validator: Pubkey,
+) -> TransactionResult {
+    svm.process(instructions::attest_submission(
+        bounty,
+        submission,
+        &validator,
+        args::AttestSubmission {
+            score: 80,
+            checks_passed: 0,
+            model_version: "qa-v1".to_string(),
+        },
+    ))
+}
+
+fn submit_result(
+    svm: &mut Svm,
+    bounty_id: &[u8; 16],
+    contributor: Pubkey,
+    label: &str,
+) -> TransactionResult {
+    let bounty = svm.bounty(bounty_id);
+    svm.process(instructions::submit_video(
+        &bounty,
+        &contributor,
+        submit_args(label),
+    ))
+}
+
+// Bounty creation
+
+#[test]
+fn bounties_need_a_target() {
+    let mut svm = Svm::new();
+    let authority = svm.wallet();
+    let args = args::CreateBounty {
+        total_pool: SOL,
+        ..bounty_args("no-target", SOL / 10, 0, svm.now() + DAY)
+    };
+
+    assert_error(
+        svm.process(instructions::create_bounty(&authority, &authority, args)),
+        ErrorCode::InvalidTarget,
+    );
+}
+
+#[test]
+fn the_pool_must_cover_every_reward() {
+    let mut svm = Svm::new();
+    let authority = svm.wallet();
+    let args = args::CreateBounty {
+        total_pool: SOL / 10,
+        ..bounty_args("short", SOL / 10, 2, svm.now() + DAY)
+    };
+
+    assert_error(
+        svm.process(instructions::create_bounty(&authority, &authority, args)),
+        ErrorCode::InsufficientPool,
+    );
+    assert!(!svm.exists(&pda::bounty(&id16("short")).0));
+}
+
+// Submission
+
+#[test]
+fn a_pending_submission_holds_the_last_slot() {
+    let mut svm = Svm::new();
+    let authority = svm.wallet();
+    let contributor = svm.wallet();
+    let bounty = create_bounty(&mut svm, authority, "one-slot", SOL / 10, 1);
+    submit_video(&mut svm, &bounty.bounty_id, contributor, "first");
+
+    assert_error(
+        submit_result(&mut svm, &bounty.bounty_id, contributor, "second"),
+        ErrorCode::BountyFull,
+    );
+}
+
+#[test]
+fn submission_counters_cannot_overflow() {
+    let mut svm = Svm::new();
+    let authority = svm.wallet();
+    let contributor = svm.wallet();
+    let bounty = create_bounty(&mut svm, authority, "overflow", SOL / 10, 2);
+    svm.process(instructions::initialize_profile(&contributor))
+        .unwrap();
+    edit(
+        &mut svm,
+        &pda::profile(&contributor).0,
+        |profile: &mut ContributorProfile| profile.total_submissions = u32::MAX,
+    );
+
+    assert_error(
+        submit_result(&mut svm, &bounty.bounty_id, contributor, "video"),
+        ErrorCode::Overflow,
+    );
+}
+
+#[test]
+fn content_is_submitted_once() {
+    let mut svm = Svm::new();
+    let authority = svm.wallet();
+    let contributor = svm.wallet();
+    let bounty = create_bounty(&mut svm, authority, "dupes", SOL / 10, 3);
+    let other = create_bounty(&mut svm, authority, "elsewhere", SOL / 10, 3);
+    submit_video(&mut svm, &bounty.bounty_id, contributor, "video");
+
+    // Within the same bounty
+    assert_error(
+        submit_result(&mut svm, &bounty.bounty_id, contributor, "video"),
+        ErrorCode::DuplicateContent,
+    );
+    // And in another bounty that doesn't allow reuse
+    assert_error(
+        submit_result(&mut svm, &other.bounty_id, contributor, "video"),
+        ErrorCode::DuplicateContent,
+    );
+}
+
+#[test]
+fn the_reputation_gate_turns_away_new_contributors() {
+    let mut svm = Svm::new();
+    let authority = svm.wallet();
+    let contributor = svm.wallet();
+    let bounty = create_bounty(&mut svm, authority, "gated", SOL / 10, 2);
+    settings(
+        &mut svm,
+        authority,
+        &bounty.bounty_id,
+        args::SetReputationGate {
+            min_category_reputation: 600,
+        },
+    );
+
+    assert_error(
+        submit_result(&mut svm, &bounty.bounty_id, contributor, "video"),
+        ErrorCode::ReputationTooLow,
+    );
+}
+
+#[test]
+fn suspended_contributors_cannot_submit() {
+    let mut svm = Svm::new();
+    let admin = config(&mut svm);
+    let authority = svm.wallet();
+    let contributor = svm.wallet();
+    let bounty = create_bounty(&mut svm, authority, "suspended", SOL / 10, 2);
+    svm.process(instructions::suspend_contributor(
+        &contributor,
+        &admin,
+        SuspensionReason::StolenContent,
+        DAY as u64,
+    ))
+    .unwrap();
+
+    assert_error(
+        submit_result(&mut svm, &bounty.bounty_id, contributor, "video"),
+        ErrorCode::ContributorSuspended,
+    );
+}
+
+// Review panels
+
+#[test]
+fn votes_need_a_configured_panel() {
+    let mut svm = Svm::new();
+    let authority = svm.wallet();
+    let contributor = svm.wallet();
+    let reviewer = svm.wallet();
+    let bounty = create_bounty(&mut svm, authority, "no-panel", SOL / 10, 2);
+    let (_, submission) = submit_video(&mut svm, &bounty.bounty_id, contributor, "video");
+    let bounty = svm.bounty(&bounty.bounty_id);
+
+    assert_error(
+        svm.process(instructions::cast_review_vote(
+            &bounty,
+            &submission,
+            &reviewer,
+            true,
+            80,
+        )),
+        ErrorCode::ConsensusNotConfigured,
+    );
+}
+
+#[test]
+fn only_panel_members_vote() {
+    let mut svm = Svm::new();
+    let authority = svm.wallet();
+    let contributor = svm.wallet();
+    let reviewer = svm.wallet();
+    let stranger = svm.wallet();
+    let bounty = create_bounty(&mut svm, authority, "panel", SOL / 10, 2);
+    review_panel(&mut svm, authority, &bounty.bounty_id, &[reviewer]);
+    let (_, submission) = submit_video(&mut svm, &bounty.bounty_id, contributor, "video");
+    let bounty = svm.bounty(&bounty.bounty_id);
+
+    assert_error(
+        svm.process(instructions::cast_review_vote(
+            &bounty,
+            &submission,
+            &stranger,
+            true,
+            80,
+        )),
+        ErrorCode::NotAReviewer,
+    );
+}
+
+#[test]
+fn reviewers_cannot_vote_on_their_own_submissions() {
+    let mut svm = Svm::new();
+    let authority = svm.wallet();
+    let reviewer = svm.wallet();
+    let bounty = create_bounty(&mut svm, authority, "self-review", SOL / 10, 2);
+    review_panel(&mut svm, authority, &bounty.bounty_id, &[reviewer]);
+    let (_, submission) = submit_video(&mut svm, &bounty.bounty_id, reviewer, "video");
+    let bounty = svm.bounty(&bounty.bounty_id);
+
+    assert_error(
+        svm.process(instructions::cast_review_vote(
+            &bounty,
+            &submission,
+            &reviewer,
+            true,
+            80,
+        )),
+        ErrorCode::SelfReview,
+    );
+}
+
+#[test]
+fn panels_cannot_change_under_pending_votes() {
+    let mut svm = Svm::new();
+    let authority = svm.wallet();
+    let contributor = svm.wallet();
+    let reviewers = [svm.wallet(), svm.wallet()];
+    let bounty = create_bounty(&mut svm, authority, "panel-swap", SOL / 10, 2);
+    review_panel(&mut svm, authority, &bounty.bounty_id, &[reviewers[0]]);
+    submit_video(&mut svm, &bounty.bounty_id, contributor, "video");
+
+    assert_error(
+        svm.process(instructions::update_bounty_settings(
+            &bounty.bounty_id,
+            &authority,
+            args::SetReviewPanel {
+                reviewers: vec![reviewers[1]],
+                required_approvals: 1,
+            },
+        )),
+        ErrorCode::SubmissionsPending,
+    );
+}
+
+// Validators
+
+#[test]
+fn inactive_validators_cannot_attest() {
+    let mut svm = Svm::new();
+    let admin = config(&mut svm);
+    let authority = svm.wallet();
+    let contributor = svm.wallet();
+    let validator = svm.wallet();
+    register_validator(&mut svm, admin, validator, SOL);
+    svm.process(instructions::set_validator_active(
+        &validator, &admin, false,
+    ))
+    .unwrap();
+    let bounty = create_bounty(&mut svm, authority, "inactive", SOL / 10, 2);
+    let (_, submission) = submit_video(&mut svm, &bounty.bounty_id, contributor, "video");
+    let bounty = svm.bounty(&bounty.bounty_id);
+
+    assert_error(
+        attest(&mut svm, &bounty, &submission, validator),
+        ErrorCode::ValidatorInactive,
+    );
+}
+
+#[test]
+fn validators_need_the_bountys_minimum_stake() {
+    let mut svm = Svm::new();
+    let admin = config(&mut svm);
+    let authority = svm.wallet();
+    let contributor = svm.wallet();
+    let validator = svm.wallet();
+    register_validator(&mut svm, admin, validator, SOL);
+    let bounty = create_bounty(&mut svm, authority, "staked", SOL / 10, 2);
+    settings(
+        &mut svm,
+        authority,
+        &bounty.bounty_id,
+        args::SetAttestationPolicy {
+            policy: AttestationPolicy {
+                auto_approve_min_score: Some(70),
+                auto_reject_max_score: None,
+                min_validator_stake: 2 * SOL,
+            },
+        },
+    );
+    let (_, submission) = submit_video(&mut svm, &bounty.bounty_id, contributor, "video");
+    let bounty = svm.bounty(&bounty.bounty_id);
+
+    assert_error(
+        attest(&mut svm, &bounty, &submission, validator),
+        ErrorCode::InsufficientValidatorStake,
+    );
+}
+
+#[test]
+fn validators_cannot_attest_their_own_submissions() {
+    let mut svm = Svm::new();
+    let admin = config(&mut svm);
+    let authority = svm.wallet();
+    let validator = svm.wallet();
+    register_validator(&mut svm, admin, validator, SOL);
+    let bounty = create_bounty(&mut svm, authority, "self-attest", SOL / 10, 2);
+    let (_, submission) = submit_video(&mut svm, &bounty.bounty_id, validator, "video");
+    let bounty = svm.bounty(&bounty.bounty_id);
+
+    assert_error(
+        attest(&mut svm, &bounty, &submission, validator),
+        ErrorCode::SelfAttestation,
+    );
+}
+
+// Administration and badges
+
+#[test]
+fn only_the_upgrade_authority_initializes_the_config() {
+    let mut svm = Svm::new();
+    let stranger = svm.wallet();
+
+    assert_error(
+        svm.process(instructions::initialize_config(&stranger, &stranger)),
+        ErrorCode::Unauthorized,
+    );
+    assert!(!svm.exists(&pda::config().0));
+}
+
+#[test]
+fn badge_definitions_are_length_checked() {
+    let mut svm = Svm::new();
+    let admin = config(&mut svm);
+
+    assert_error(
+        svm.process(instructions::create_badge_definition(
+            &admin,
+            args::CreateBadgeDefinition {
+                badge_id: 1,
+                name: "x".repeat(33),
+                category: None,
+                criteria: String::new(),
+                rule: AutoAwardRule::Manual,
+            },
+        )),
+        ErrorCode::InvalidBadgeDefinition,
+    );
+}
+
+#[test]
+fn profiles_hold_a_bounded_number_of_badges() {
+    let mut svm = Svm::new();
+    let contributor = svm.wallet();
+    let admin = config(&mut svm);
+    svm.process(instructions::initialize_profile(&contributor))
+        .unwrap();
+    // Only six badge types exist, so fill the profile directly
+    edit(
+        &mut svm,
+        &pda::profile(&contributor).0,
+        |profile: &mut ContributorProfile| {
+            profile.badges = vec![
+                Badge {
+                    badge_type: BadgeType::FirstVideo,
+                    earned_at: 0,
+                    token_minted: false,
+                };
+                ContributorProfile::MAX_BADGES
+            ];
+        },
+    );
+
+    assert_error(
+        svm.process(instructions::award_badge(
+            &contributor,
+            &admin,
+            BadgeType::EarlyAdopter,
+        )),
+        ErrorCode::TooManyBadges,
+    );
+}
+
+// Datasets
+
+#[test]
+fn royalties_are_a_percentage() {
+    let mut svm = Svm::new();
+    let creator = svm.wallet();
+    let bounty_id = completed_bounty(&mut svm, creator, "source");
+
+    assert_error(
+        svm.process(instructions::create_dataset(
+            &creator,
+            &bounty_id,
+            args::CreateDataset {
+                dataset_id: id16("royalty"),
+                license_type: LicenseType::SingleUse,
+                price: SOL,
+                royalty_percentage: 101,
+            },
+        )),
+        ErrorCode::InvalidRoyalty,
+    );
+}
+
+fn sourced_dataset_args(label: &str) -> args::CreateDataset {
+    args::CreateDataset {
+        dataset_id: id16(label),
+        license_type: LicenseType::Unlimited,
+        price: SOL,
+        royalty_percentage: 10,
+    }
+}
+
+#[test]
+fn datasets_come_from_the_creators_completed_bounties() {
+    let mut svm = Svm::new();
+    let creator = svm.wallet();
+    let stranger = svm.wallet();
+    let open = create_bounty(&mut svm, creator, "open", SOL / 10, 1);
+    let completed = completed_bounty(&mut svm, creator, "completed");
+
+    assert_error(
+        svm.process(instructions::create_dataset(
+            &creator,
+            &open.bounty_id,
+            sourced_dataset_args("from-open"),
+        )),
+        ErrorCode::InvalidStatus,
+    );
+    assert_error(
+        svm.process(instructions::create_dataset(
+            &stranger,
+            &completed,
+            sourced_dataset_args("from-stranger"),
+        )),
+        ErrorCode::Unauthorized,
+    );
+}
+
+#[test]
+fn purchases_must_pay_the_source_bountys_sponsors() {
+    let mut svm = Svm::new();
+    let creator = svm.wallet();
+    let buyer = svm.wallet();
+    let source = completed_bounty(&mut svm, creator, "source");
+    let other = completed_bounty(&mut svm, creator, "other");
+    svm.process(instructions::create_dataset(
+        &creator,
+        &source,
+        sourced_dataset_args("sourced"),
+    ))
+    .unwrap();
+    let address = pda::dataset(&id16("sourced")).0;
+    let dataset: DatasetNFT = svm.account(&address);
+
+    // Naming another bounty would send the sponsors' share to the wrong pool
+    let mut misrouted = dataset.clone();
+    misrouted.bounty = pda::bounty(&other).0;
+    assert_error(
+        svm.process(instructions::purchase_dataset(&misrouted, &buyer)),
+        ErrorCode::DatasetBountyMismatch,
+    );
+    assert_eq!(svm.account::<DatasetNFT>(&address).total_sales, 0);
+    assert!(!svm.exists(&pda::license(&address, &buyer).0));
+}
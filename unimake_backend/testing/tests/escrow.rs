@@ -0,0 +1,759 @@
+#![cfg(program_built)]
+
+use anchor_lang::error::ErrorCode as AnchorError;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use unimake_backend::{
+    accounts, instruction, BadgeType, BountyPool, BuyerProfile, CategoryReputation, ContentRecord,
+    ContributorProfile, ErrorCode, RejectionReason, ReviewDecision, RewardTier, SubmissionStatus,
+    VideoSubmission, BATCH_ACCOUNTS_PER_ITEM, MAX_BATCH_REVIEWS,
+};
+use unimake_testing::*;
+
+const REWARD: u64 = SOL / 10;
+
+fn setup() -> (Svm, Bounty, Pubkey) {
+    let mut svm = Svm::new();
+    let authority = svm.wallet();
+    let bounty = open_bounty(&mut svm, authority, "escrow", REWARD, 10);
+    let contributor = svm.wallet();
+    (svm, bounty, contributor)
+}
+
+fn reject(
+    svm: &Svm,
+    bounty: &Bounty,
+    submission: Pubkey,
+    contributor: Pubkey,
+    reason: RejectionReason,
+) -> Instruction {
+    ix(
+        bounty.review(svm, submission, contributor),
+        instruction::RejectSubmission {
+            reason,
+            feedback_uri: "ipfs://feedback".to_string(),
+        },
+    )
+}
+
+/// The pool holds rent, its unreserved balance, the bonus pool and every pending escrow
+#[track_caller]
+fn assert_pool_balanced(svm: &Svm, bounty: &Bounty, pending: u64) {
+    let pool: BountyPool = svm.account(&bounty.pool);
+    let rent = Rent::default().minimum_balance(8 + BountyPool::INIT_SPACE);
+    assert_eq!(
+        svm.lamports(&bounty.pool),
+        rent + pool.remaining_pool + pool.bonus_pool + pending * pool.reward_per_video
+    );
+}
+
+/// Every account a review of `submissions` can move lamports between
+fn review_accounts(svm: &Svm, bounty: &Bounty, submissions: &[Pubkey]) -> Vec<Pubkey> {
+    let mut accounts = vec![bounty.pool, bounty.authority, bounty.buyer_profile()];
+    for submission in submissions {
+        let stored: VideoSubmission = svm.account(submission);
+        accounts.extend([
+            *submission,
+            stored.contributor,
+            pda::content(&stored.content_hash),
+            pda::profile(&stored.contributor),
+            pda::category(&stored.contributor, CATEGORY),
+        ]);
+    }
+    accounts.sort();
+    accounts.dedup();
+    accounts
+}
+
+#[test]
+fn approval_pays_the_escrow_and_records_the_review() {
+    let (mut svm, bounty, contributor) = setup();
+    let submission = submit(&mut svm, &bounty, contributor, "video");
+    svm.advance(3_600);
+    let before = svm.lamports(&contributor);
+    let accounts = review_accounts(&svm, &bounty, &[submission]);
+    let total = svm.total_lamports(&accounts);
+
+    approve(&mut svm, &bounty, submission, contributor, 80);
+
+    assert_eq!(svm.lamports(&contributor) - before, REWARD);
+    assert_eq!(svm.total_lamports(&accounts), total);
+    assert_pool_balanced(&svm, &bounty, 0);
+
+    let stored: VideoSubmission = svm.account(&submission);
+    assert!(stored.status == SubmissionStatus::Approved);
+    assert_eq!(stored.quality_score, 80);
+    assert_eq!(bounty.state(&svm).videos_collected, 1);
+
+    let profile: ContributorProfile = svm.account(&pda::profile(&contributor));
+    assert_eq!(profile.accepted_submissions, 1);
+    assert_eq!(profile.pending_submissions, 0);
+    assert_eq!(profile.total_earnings, REWARD);
+    assert!(profile.has_badge(&BadgeType::FirstVideo));
+    let category: CategoryReputation = svm.account(&pda::category(&contributor, CATEGORY));
+    assert_eq!(category.accepted_submissions, 1);
+    let buyer: BuyerProfile = svm.account(&bounty.buyer_profile());
+    assert_eq!(buyer.approved_submissions, 1);
+    assert_eq!(buyer.total_paid_out, REWARD);
+    assert_eq!(buyer.average_review_latency_secs, 3_600);
+}
+
+#[test]
+fn approval_scores_are_capped_at_100() {
+    let (mut svm, bounty, contributor) = setup();
+    let submission = submit(&mut svm, &bounty, contributor, "video");
+
+    assert_error(
+        svm.process(ix(
+            bounty.review(&svm, submission, contributor),
+            instruction::ApproveSubmission { quality_score: 101 },
+        )),
+        ErrorCode::InvalidQualityScore,
+    );
+    let stored: VideoSubmission = svm.account(&submission);
+    assert!(stored.status == SubmissionStatus::Pending);
+
+    approve(&mut svm, &bounty, submission, contributor, 100);
+    let stored: VideoSubmission = svm.account(&submission);
+    assert_eq!(stored.quality_score, 100);
+}
+
+#[test]
+fn a_submission_is_settled_only_once() {
+    let (mut svm, bounty, contributor) = setup();
+    let submission = submit(&mut svm, &bounty, contributor, "video");
+    approve(&mut svm, &bounty, submission, contributor, 80);
+
+    assert_error(
+        svm.process(ix(
+            bounty.review(&svm, submission, contributor),
+            instruction::ApproveSubmission { quality_score: 80 },
+        )),
+        ErrorCode::InvalidStatus,
+    );
+    assert_error(
+        svm.process(reject(
+            &svm,
+            &bounty,
+            submission,
+            contributor,
+            RejectionReason::Other,
+        )),
+        ErrorCode::InvalidStatus,
+    );
+}
+
+#[test]
+fn pending_submissions_hold_their_slots() {
+    let mut svm = Svm::new();
+    let authority = svm.wallet();
+    let bounty = open_bounty(&mut svm, authority, "two-videos", REWARD, 2);
+    let contributor = svm.wallet();
+    // A sponsor's deposit funds more rewards than the bounty has slots
+    let sponsor_wallet = svm.wallet();
+    sponsor(&mut svm, &bounty, sponsor_wallet, REWARD);
+    let first = submit(&mut svm, &bounty, contributor, "first");
+    let second = submit(&mut svm, &bounty, contributor, "second");
+
+    // `submit_video` refuses a third submission while both are pending
+    let pool = bounty.state(&svm);
+    assert_eq!(pool.pending_submissions, 2);
+    assert!(!pool.has_open_slot());
+    assert!(pool.remaining_pool >= pool.reward_per_video);
+
+    // A rejection frees its slot; an approval turns it into a collected video
+    svm.process(reject(
+        &svm,
+        &bounty,
+        second,
+        contributor,
+        RejectionReason::Other,
+    ))
+    .unwrap();
+    let pool = bounty.state(&svm);
+    assert_eq!(pool.pending_submissions, 1);
+    assert!(pool.has_open_slot());
+
+    approve(&mut svm, &bounty, first, contributor, 80);
+    let pool = bounty.state(&svm);
+    assert_eq!((pool.videos_collected, pool.pending_submissions), (1, 0));
+    assert!(pool.has_open_slot());
+    assert_pool_balanced(&svm, &bounty, 0);
+}
+
+#[test]
+fn reviews_require_the_bounty_authority() {
+    let (mut svm, bounty, contributor) = setup();
+    let submission = submit(&mut svm, &bounty, contributor, "video");
+    let stranger = svm.wallet();
+
+    let mut accounts = bounty.review(&svm, submission, contributor);
+    accounts.authority = stranger;
+    assert_error(
+        svm.process(ix(
+            accounts,
+            instruction::ApproveSubmission { quality_score: 80 },
+        )),
+        AnchorError::ConstraintHasOne,
+    );
+    assert_not_signed(svm.process(unsigned(
+        reject(
+            &svm,
+            &bounty,
+            submission,
+            contributor,
+            RejectionReason::Spam,
+        ),
+        &bounty.authority,
+    )));
+}
+
+#[test]
+fn payment_goes_to_the_submitting_contributor() {
+    let (mut svm, bounty, contributor) = setup();
+    let submission = submit(&mut svm, &bounty, contributor, "mine");
+    let other = svm.wallet();
+    submit(&mut svm, &bounty, other, "theirs");
+
+    assert_error(
+        svm.process(ix(
+            bounty.review(&svm, submission, other),
+            instruction::ApproveSubmission { quality_score: 80 },
+        )),
+        ErrorCode::SubmissionMismatch,
+    );
+}
+
+#[test]
+fn panel_bounties_reject_single_reviewer_settlement() {
+    let (mut svm, bounty, contributor) = setup();
+    let submission = submit(&mut svm, &bounty, contributor, "video");
+    bounty.edit(&mut svm, |pool| {
+        pool.reviewers = vec![Pubkey::new_unique(), Pubkey::new_unique()];
+        pool.required_approvals = 2;
+    });
+
+    assert_error(
+        svm.process(ix(
+            bounty.review(&svm, submission, contributor),
+            instruction::ApproveSubmission { quality_score: 80 },
+        )),
+        ErrorCode::ConsensusRequired,
+    );
+    assert_error(
+        svm.process(reject(
+            &svm,
+            &bounty,
+            submission,
+            contributor,
+            RejectionReason::Other,
+        )),
+        ErrorCode::ConsensusRequired,
+    );
+    assert_error(
+        svm.process(batch(
+            &svm,
+            &bounty,
+            &[(submission, contributor)],
+            vec![ReviewDecision::Approve { quality_score: 80 }],
+            true,
+        )),
+        ErrorCode::ConsensusRequired,
+    );
+}
+
+#[test]
+fn reward_tiers_scale_the_payout() {
+    let (mut svm, bounty, contributor) = setup();
+    bounty.edit(&mut svm, |pool| {
+        pool.reward_tiers = vec![
+            RewardTier {
+                min_score: 0,
+                payout_bps: 5_000,
+            },
+            RewardTier {
+                min_score: 80,
+                payout_bps: 15_000,
+            },
+        ];
+        pool.bonus_pool = REWARD / 4;
+    });
+    svm.airdrop(&bounty.pool, REWARD / 4);
+    let low = submit(&mut svm, &bounty, contributor, "low");
+    let high = submit(&mut svm, &bounty, contributor, "high");
+    let before = svm.lamports(&contributor);
+
+    // Half the escrow is paid and half returns to the pool
+    approve(&mut svm, &bounty, low, contributor, 50);
+    assert_eq!(svm.lamports(&contributor) - before, REWARD / 2);
+    assert_eq!(bounty.state(&svm).remaining_pool, 8 * REWARD + REWARD / 2);
+
+    // The bonus is capped by what is left in the bonus pool
+    approve(&mut svm, &bounty, high, contributor, 95);
+    assert_eq!(
+        svm.lamports(&contributor) - before,
+        REWARD / 2 + REWARD + REWARD / 4
+    );
+    assert_eq!(bounty.state(&svm).bonus_pool, 0);
+    assert_pool_balanced(&svm, &bounty, 0);
+}
+
+#[test]
+fn rejection_returns_the_escrow_and_refunds_an_honest_bond() {
+    let (mut svm, bounty, contributor) = setup();
+    bounty.edit(&mut svm, |pool| pool.submission_bond = SOL / 100);
+    let submission = submit(&mut svm, &bounty, contributor, "video");
+    let before = svm.lamports(&contributor);
+
+    svm.process(reject(
+        &svm,
+        &bounty,
+        submission,
+        contributor,
+        RejectionReason::TooShort,
+    ))
+    .unwrap();
+
+    assert_eq!(svm.lamports(&contributor) - before, SOL / 100);
+    assert_eq!(bounty.state(&svm).remaining_pool, 10 * REWARD);
+    assert_pool_balanced(&svm, &bounty, 0);
+    let stored: VideoSubmission = svm.account(&submission);
+    assert!(stored.status == SubmissionStatus::Rejected);
+    assert!(stored.rejection_reason == Some(RejectionReason::TooShort));
+    assert_eq!(stored.feedback_uri, "ipfs://feedback");
+    assert_eq!(stored.bond_amount, 0);
+
+    let profile: ContributorProfile = svm.account(&pda::profile(&contributor));
+    assert_eq!(profile.rejected_submissions, 1);
+    assert_eq!(
+        profile.rejection_weight,
+        RejectionReason::TooShort.reputation_weight()
+    );
+    let buyer: BuyerProfile = svm.account(&bounty.buyer_profile());
+    assert_eq!(buyer.rejected_submissions, 1);
+}
+
+#[test]
+fn spam_forfeits_the_bond_to_the_authority() {
+    let (mut svm, bounty, contributor) = setup();
+    bounty.edit(&mut svm, |pool| pool.submission_bond = SOL / 100);
+    let submission = submit(&mut svm, &bounty, contributor, "video");
+    let contributor_before = svm.lamports(&contributor);
+    let authority_before = svm.lamports(&bounty.authority);
+
+    svm.process(reject(
+        &svm,
+        &bounty,
+        submission,
+        contributor,
+        RejectionReason::Spam,
+    ))
+    .unwrap();
+
+    assert_eq!(svm.lamports(&contributor), contributor_before);
+    assert_eq!(
+        svm.lamports(&bounty.authority) - authority_before,
+        SOL / 100
+    );
+}
+
+#[test]
+fn rejection_releases_exclusive_content() {
+    let (mut svm, bounty, contributor) = setup();
+    let submission = submit(&mut svm, &bounty, contributor, "stolen");
+    let content = pda::content(&content_hash("stolen"));
+    let record: ContentRecord = svm.account(&content);
+    assert!(record.exclusive);
+
+    svm.process(reject(
+        &svm,
+        &bounty,
+        submission,
+        contributor,
+        RejectionReason::Fraud,
+    ))
+    .unwrap();
+
+    let record: ContentRecord = svm.account(&content);
+    assert_eq!(record.use_count, 0);
+    assert!(!record.exclusive);
+}
+
+#[test]
+fn feedback_longer_than_the_limit_is_rejected() {
+    let (mut svm, bounty, contributor) = setup();
+    let submission = submit(&mut svm, &bounty, contributor, "video");
+
+    assert_error(
+        svm.process(ix(
+            bounty.review(&svm, submission, contributor),
+            instruction::RejectSubmission {
+                reason: RejectionReason::Other,
+                feedback_uri: "x".repeat(VideoSubmission::MAX_FEEDBACK_URI_LEN + 1),
+            },
+        )),
+        ErrorCode::FeedbackTooLong,
+    );
+}
+
+// ============================================================================
+// DISPUTES
+// ============================================================================
+
+fn resolve(
+    svm: &Svm,
+    bounty: &Bounty,
+    submission: Pubkey,
+    contributor: Pubkey,
+    admin: Pubkey,
+    approve: bool,
+    quality_score: u8,
+) -> Instruction {
+    ix(
+        accounts::ResolveDispute {
+            submission,
+            content_record: pda::content(&svm.account::<VideoSubmission>(&submission).content_hash),
+            bounty_pool: bounty.pool,
+            contributor_profile: pda::profile(&contributor),
+            category_reputation: pda::category(&contributor, CATEGORY),
+            buyer_profile: bounty.buyer_profile(),
+            contributor,
+            config: pda::config(),
+            admin,
+        },
+        instruction::ResolveDispute {
+            approve,
+            quality_score,
+        },
+    )
+}
+
+/// Park a submission as a split panel vote would
+fn dispute(svm: &mut Svm, submission: &Pubkey) {
+    edit(svm, submission, |submission: &mut VideoSubmission| {
+        submission.approvals = 1;
+        submission.rejections = 1;
+        submission.review_scores = vec![90, 20];
+        submission.status = SubmissionStatus::Disputed;
+    });
+}
+
+#[test]
+fn the_admin_settles_disputes_in_the_contributors_favour() {
+    let (mut svm, bounty, contributor) = setup();
+    let admin = config(&mut svm);
+    let submission = submit(&mut svm, &bounty, contributor, "video");
+    let before = svm.lamports(&contributor);
+
+    assert_error(
+        svm.process(resolve(
+            &svm,
+            &bounty,
+            submission,
+            contributor,
+            admin,
+            true,
+            70,
+        )),
+        ErrorCode::InvalidStatus,
+    );
+
+    dispute(&mut svm, &submission);
+    assert_error(
+        svm.process(resolve(
+            &svm,
+            &bounty,
+            submission,
+            contributor,
+            admin,
+            true,
+            101,
+        )),
+        ErrorCode::InvalidQualityScore,
+    );
+    svm.process(resolve(
+        &svm,
+        &bounty,
+        submission,
+        contributor,
+        admin,
+        true,
+        70,
+    ))
+    .unwrap();
+
+    assert_eq!(svm.lamports(&contributor) - before, REWARD);
+    let stored: VideoSubmission = svm.account(&submission);
+    assert!(stored.status == SubmissionStatus::Approved);
+    assert_eq!(stored.quality_score, 70);
+    let buyer: BuyerProfile = svm.account(&bounty.buyer_profile());
+    assert_eq!(buyer.disputes_lost, 1);
+}
+
+#[test]
+fn disputes_resolved_against_the_contributor_return_the_escrow() {
+    let (mut svm, bounty, contributor) = setup();
+    let admin = config(&mut svm);
+    let submission = submit(&mut svm, &bounty, contributor, "video");
+    dispute(&mut svm, &submission);
+
+    svm.process(resolve(
+        &svm,
+        &bounty,
+        submission,
+        contributor,
+        admin,
+        false,
+        0,
+    ))
+    .unwrap();
+
+    assert_eq!(bounty.state(&svm).remaining_pool, 10 * REWARD);
+    let stored: VideoSubmission = svm.account(&submission);
+    assert!(stored.status == SubmissionStatus::Rejected);
+    let buyer: BuyerProfile = svm.account(&bounty.buyer_profile());
+    assert_eq!(buyer.disputes_lost, 0);
+}
+
+#[test]
+fn only_the_admin_resolves_disputes() {
+    let (mut svm, bounty, contributor) = setup();
+    config(&mut svm);
+    let submission = submit(&mut svm, &bounty, contributor, "video");
+    dispute(&mut svm, &submission);
+
+    assert_error(
+        svm.process(resolve(
+            &svm,
+            &bounty,
+            submission,
+            contributor,
+            bounty.authority,
+            true,
+            70,
+        )),
+        AnchorError::ConstraintHasOne,
+    );
+}
+
+// ============================================================================
+// BATCH REVIEW
+// ============================================================================
+
+fn batch(
+    svm: &Svm,
+    bounty: &Bounty,
+    items: &[(Pubkey, Pubkey)],
+    decisions: Vec<ReviewDecision>,
+    atomic: bool,
+) -> Instruction {
+    let mut ix = ix(
+        accounts::BatchReview {
+            bounty_pool: bounty.pool,
+            buyer_profile: bounty.buyer_profile(),
+            authority: bounty.authority,
+            system_program: anchor_lang::system_program::ID,
+        },
+        instruction::BatchReview { decisions, atomic },
+    );
+    for (submission, contributor) in items {
+        ix.accounts.extend([
+            AccountMeta::new(*submission, false),
+            AccountMeta::new(*contributor, false),
+            AccountMeta::new(pda::profile(contributor), false),
+            AccountMeta::new(pda::category(contributor, CATEGORY), false),
+            AccountMeta::new(
+                pda::content(&svm.account::<VideoSubmission>(submission).content_hash),
+                false,
+            ),
+        ]);
+    }
+    ix
+}
+
+#[test]
+fn batches_settle_each_item_in_order() {
+    let (mut svm, bounty, contributor) = setup();
+    let other = svm.wallet();
+    let first = submit(&mut svm, &bounty, contributor, "first");
+    let second = submit(&mut svm, &bounty, contributor, "second");
+    let third = submit(&mut svm, &bounty, other, "third");
+    let before = svm.lamports(&contributor);
+
+    svm.process(batch(
+        &svm,
+        &bounty,
+        &[(first, contributor), (second, contributor), (third, other)],
+        vec![
+            ReviewDecision::Approve { quality_score: 90 },
+            ReviewDecision::Approve { quality_score: 70 },
+            ReviewDecision::Reject {
+                reason: RejectionReason::WrongTask,
+            },
+        ],
+        true,
+    ))
+    .unwrap();
+
+    assert_eq!(svm.lamports(&contributor) - before, 2 * REWARD);
+    let profile: ContributorProfile = svm.account(&pda::profile(&contributor));
+    assert_eq!(profile.accepted_submissions, 2);
+    assert_eq!(profile.average_quality_score, 80);
+    let rejected: VideoSubmission = svm.account(&third);
+    assert!(rejected.status == SubmissionStatus::Rejected);
+    let pool = bounty.state(&svm);
+    assert_eq!(pool.videos_collected, 2);
+    assert_eq!(pool.remaining_pool, 8 * REWARD);
+    assert_pool_balanced(&svm, &bounty, 0);
+}
+
+#[test]
+fn atomic_batches_fail_on_any_invalid_item() {
+    let (mut svm, bounty, contributor) = setup();
+    let settled = submit(&mut svm, &bounty, contributor, "settled");
+    let pending = submit(&mut svm, &bounty, contributor, "pending");
+    approve(&mut svm, &bounty, settled, contributor, 80);
+
+    assert_error(
+        svm.process(batch(
+            &svm,
+            &bounty,
+            &[(pending, contributor), (settled, contributor)],
+            vec![
+                ReviewDecision::Approve { quality_score: 80 },
+                ReviewDecision::Approve { quality_score: 80 },
+            ],
+            true,
+        )),
+        ErrorCode::InvalidStatus,
+    );
+    assert_error(
+        svm.process(batch(
+            &svm,
+            &bounty,
+            &[(pending, contributor)],
+            vec![ReviewDecision::Approve { quality_score: 101 }],
+            true,
+        )),
+        ErrorCode::InvalidQualityScore,
+    );
+    let stored: VideoSubmission = svm.account(&pending);
+    assert!(stored.status == SubmissionStatus::Pending);
+}
+
+#[test]
+fn non_atomic_batches_skip_invalid_items() {
+    let (mut svm, bounty, contributor) = setup();
+    let other = open_bounty(&mut svm, bounty.authority, "other", REWARD, 1);
+    let foreign = submit(&mut svm, &other, contributor, "foreign");
+    let pending = submit(&mut svm, &bounty, contributor, "pending");
+    let before = svm.lamports(&contributor);
+
+    svm.process(batch(
+        &svm,
+        &bounty,
+        &[(foreign, contributor), (pending, contributor)],
+        vec![
+            ReviewDecision::Approve { quality_score: 80 },
+            ReviewDecision::Approve { quality_score: 80 },
+        ],
+        false,
+    ))
+    .unwrap();
+
+    assert_eq!(svm.lamports(&contributor) - before, REWARD);
+    let skipped: VideoSubmission = svm.account(&foreign);
+    assert!(skipped.status == SubmissionStatus::Pending);
+
+    // The same foreign item fails an atomic batch
+    assert_error(
+        svm.process(batch(
+            &svm,
+            &bounty,
+            &[(foreign, contributor)],
+            vec![ReviewDecision::Approve { quality_score: 80 }],
+            true,
+        )),
+        ErrorCode::SubmissionMismatch,
+    );
+}
+
+#[test]
+fn batch_shape_is_validated() {
+    let (mut svm, bounty, contributor) = setup();
+    let submission = submit(&mut svm, &bounty, contributor, "video");
+    let approve = ReviewDecision::Approve { quality_score: 80 };
+
+    // Decisions and account groups must line up
+    assert_error(
+        svm.process(batch(
+            &svm,
+            &bounty,
+            &[(submission, contributor)],
+            vec![approve, approve],
+            true,
+        )),
+        ErrorCode::InvalidBatch,
+    );
+    assert_error(
+        svm.process(batch(&svm, &bounty, &[], vec![], true)),
+        ErrorCode::InvalidBatch,
+    );
+    let items = vec![(submission, contributor); MAX_BATCH_REVIEWS + 1];
+    assert_error(
+        svm.process(batch(
+            &svm,
+            &bounty,
+            &items,
+            vec![approve; MAX_BATCH_REVIEWS + 1],
+            true,
+        )),
+        ErrorCode::InvalidBatch,
+    );
+
+    // Every item account must be writable
+    let mut readonly = batch(
+        &svm,
+        &bounty,
+        &[(submission, contributor)],
+        vec![approve],
+        true,
+    );
+    let profile = readonly.accounts.len() - BATCH_ACCOUNTS_PER_ITEM + 2;
+    readonly.accounts[profile].is_writable = false;
+    assert_error(svm.process(readonly), ErrorCode::InvalidBatch);
+}
+
+// ============================================================================
+// LAMPORT CONSERVATION
+// ============================================================================
+
+#[test]
+fn a_full_bounty_lifecycle_conserves_lamports() {
+    let (mut svm, bounty, contributor) = setup();
+    bounty.edit(&mut svm, |pool| pool.submission_bond = SOL / 100);
+    let other = svm.wallet();
+    let paid = submit(&mut svm, &bounty, contributor, "paid");
+    let spam = submit(&mut svm, &bounty, other, "spam");
+    let pending = submit(&mut svm, &bounty, contributor, "pending");
+    assert_pool_balanced(&svm, &bounty, 3);
+    let accounts = review_accounts(&svm, &bounty, &[paid, spam, pending]);
+    let total = svm.total_lamports(&accounts);
+
+    approve(&mut svm, &bounty, paid, contributor, 80);
+    svm.process(reject(&svm, &bounty, spam, other, RejectionReason::Spam))
+        .unwrap();
+    assert_pool_balanced(&svm, &bounty, 1);
+
+    svm.process(bounty.cancel()).unwrap();
+    assert_pool_balanced(&svm, &bounty, 1);
+    approve(&mut svm, &bounty, pending, contributor, 80);
+    assert_pool_balanced(&svm, &bounty, 0);
+
+    // Bonds went back to their owners or to the authority; only rent is left behind
+    let rent = Rent::default().minimum_balance(8 + VideoSubmission::INIT_SPACE);
+    for submission in [paid, spam, pending] {
+        assert_eq!(svm.lamports(&submission), rent);
+    }
+    assert_eq!(svm.total_lamports(&accounts), total);
+}
@@ -0,0 +1,589 @@
+//! The instructions that create accounts or move lamports through the system program,
+//! run end to end with the client's builders rather than from seeded fixtures.
+
+#![cfg(program_built)]
+
+use anchor_lang::prelude::Pubkey;
+use anchor_spl::token_2022;
+use anchor_spl::token_2022::spl_token_2022::extension::{
+    non_transferable::NonTransferable, BaseStateWithExtensions, StateWithExtensions,
+};
+use anchor_spl::token_2022::spl_token_2022::state::Mint;
+use unimake_backend::{
+    instruction as args, AttestationPolicy, AutoAwardRule, BadgeDefinition, BadgeType,
+    BountySponsor, BuyerProfile, CategoryReputation, ContentRecord, ContributorProfile,
+    DatasetLicense, DatasetNFT, LicenseType, ProgramConfig, QualityAttestation, RejectionReason,
+    ReviewVote, SubmissionStatus, SuspensionReason, ValidatorOracle, VideoSubmission,
+    NEUTRAL_REPUTATION,
+};
+use unimake_client::events::ProgramEvent;
+use unimake_client::{instructions, pda};
+use unimake_testing::*;
+
+#[test]
+fn the_upgrade_authority_initializes_the_config() {
+    let mut svm = Svm::new();
+    let admin = config(&mut svm);
+
+    let config: ProgramConfig = svm.account(&pda::config().0);
+    assert_eq!(config.admin, admin);
+}
+
+#[test]
+fn create_bounty_funds_the_pool_and_opens_a_buyer_profile() {
+    let mut svm = Svm::new();
+    let authority = svm.wallet();
+    let before = svm.lamports(&authority);
+
+    let args = bounty_args("create", SOL / 10, 3, svm.now() + 30 * DAY);
+    let meta = svm
+        .process(instructions::create_bounty(&authority, &authority, args))
+        .unwrap();
+
+    let (pool, _) = pda::bounty(&id16("create"));
+    let (buyer, _) = pda::buyer_profile(&authority);
+    let bounty = svm.bounty(&id16("create"));
+    assert_eq!(bounty.authority, authority);
+    assert_eq!(bounty.remaining_pool, 3 * SOL / 10);
+    assert_eq!(svm.lamports(&pool), svm.rent(&pool) + 3 * SOL / 10);
+    assert_eq!(
+        before - svm.lamports(&authority),
+        svm.lamports(&pool) + svm.lamports(&buyer)
+    );
+    assert_eq!(svm.account::<BuyerProfile>(&buyer).bounties_created, 1);
+    assert!(matches!(
+        &emitted(&meta)[..],
+        [ProgramEvent::BountyCreated(event)]
+            if event.bounty_id == id16("create") && event.total_pool == 3 * SOL / 10
+    ));
+
+    // A second bounty reuses the buyer profile
+    let buyer_rent = svm.lamports(&buyer);
+    create_bounty(&mut svm, authority, "create-again", SOL / 10, 1);
+    assert_eq!(svm.account::<BuyerProfile>(&buyer).bounties_created, 2);
+    assert_eq!(svm.lamports(&buyer), buyer_rent);
+}
+
+#[test]
+fn submit_video_escrows_the_reward_and_creates_the_contributor_records() {
+    let mut svm = Svm::new();
+    let authority = svm.wallet();
+    let contributor = svm.wallet();
+    let bounty = create_bounty(&mut svm, authority, "submit", SOL / 10, 2);
+    svm.process(instructions::update_bounty_settings(
+        &bounty.bounty_id,
+        &authority,
+        args::SetSubmissionBond { bond: SOL / 100 },
+    ))
+    .unwrap();
+    let bounty = svm.bounty(&bounty.bounty_id);
+    let before = svm.lamports(&contributor);
+
+    let (pool, _) = pda::bounty(&bounty.bounty_id);
+    let address = pda::submission(&pool, 0).0;
+    let meta = svm
+        .process(instructions::submit_video(
+            &bounty,
+            &contributor,
+            submit_args("video"),
+        ))
+        .unwrap();
+
+    let submission: VideoSubmission = svm.account(&address);
+    assert_eq!(submission.contributor, contributor);
+    assert!(submission.status == SubmissionStatus::Pending);
+    assert_eq!(submission.escrow_amount, SOL / 10);
+    assert_eq!(submission.bond_amount, SOL / 100);
+    // The bond is held in the submission account on top of its rent
+    assert_eq!(svm.lamports(&address), svm.rent(&address) + SOL / 100);
+
+    let bounty = svm.bounty(&bounty.bounty_id);
+    assert_eq!(bounty.remaining_pool, SOL / 10);
+    assert_eq!(bounty.pending_submissions, 1);
+    assert_eq!(bounty.submission_count, 1);
+
+    let profile_address = pda::profile(&contributor).0;
+    let category_address = pda::category_reputation(&contributor, CATEGORY).0;
+    let content_address = pda::content(&content_hash("video")).0;
+    let bounty_content_address = pda::bounty_content(&pool, &content_hash("video")).0;
+    let profile: ContributorProfile = svm.account(&profile_address);
+    assert_eq!(profile.total_submissions, 1);
+    assert_eq!(profile.pending_submissions, 1);
+    let category: CategoryReputation = svm.account(&category_address);
+    assert_eq!(category.category, CATEGORY);
+    assert_eq!(category.reputation_score, NEUTRAL_REPUTATION);
+    for record in [content_address, bounty_content_address] {
+        let record: ContentRecord = svm.account(&record);
+        assert_eq!(record.contributor, contributor);
+        assert_eq!(record.use_count, 1);
+    }
+
+    // The contributor paid the bond and every new account's rent, nothing more
+    let created = [
+        address,
+        profile_address,
+        category_address,
+        content_address,
+        bounty_content_address,
+    ];
+    assert_eq!(
+        (before - svm.lamports(&contributor)) as u128,
+        svm.total_lamports(&created)
+    );
+
+    let events = emitted(&meta);
+    assert!(matches!(
+        &events[..],
+        [ProgramEvent::ProfileCreated(_), ProgramEvent::VideoSubmitted(event)]
+            if event.sequence == 0 && event.bond_amount == SOL / 100
+    ));
+
+    // A second submission reuses the profile and category records
+    let (_, second) = submit_video(&mut svm, &bounty.bounty_id, contributor, "second");
+    assert_eq!(second.sequence, 1);
+    let profile: ContributorProfile = svm.account(&profile_address);
+    assert_eq!(profile.total_submissions, 2);
+}
+
+#[test]
+fn rejected_content_is_free_for_other_bounties() {
+    let mut svm = Svm::new();
+    let authority = svm.wallet();
+    let copier = svm.wallet();
+    let owner = svm.wallet();
+    let first = create_bounty(&mut svm, authority, "first", SOL / 10, 2);
+    let second = create_bounty(&mut svm, authority, "second", SOL / 10, 2);
+    let (_, submission) = submit_video(&mut svm, &first.bounty_id, copier, "video");
+
+    let bounty = svm.bounty(&first.bounty_id);
+    svm.process(instructions::reject_submission(
+        &bounty,
+        &submission,
+        RejectionReason::Fraud,
+        String::new(),
+    ))
+    .unwrap();
+    let record: ContentRecord = svm.account(&pda::content(&content_hash("video")).0);
+    assert_eq!(record.use_count, 0);
+    assert!(!record.exclusive);
+
+    // Neither bounty allows reuse, yet the owner can now submit the video elsewhere
+    let (_, resubmitted) = submit_video(&mut svm, &second.bounty_id, owner, "video");
+    assert_eq!(resubmitted.contributor, owner);
+    let record: ContentRecord = svm.account(&pda::content(&content_hash("video")).0);
+    assert_eq!(record.contributor, owner);
+    assert!(record.exclusive);
+}
+
+#[test]
+fn sponsor_contributions_accumulate_in_one_record() {
+    let mut svm = Svm::new();
+    let authority = svm.wallet();
+    let sponsor = svm.wallet();
+    let bounty = create_bounty(&mut svm, authority, "sponsored", SOL / 10, 4);
+    let (pool, _) = pda::bounty(&bounty.bounty_id);
+    let record = pda::sponsor(&pool, &sponsor).0;
+    let pool_before = svm.lamports(&pool);
+
+    for _ in 0..2 {
+        svm.process(instructions::contribute_to_bounty(
+            &bounty.bounty_id,
+            &sponsor,
+            SOL / 10,
+        ))
+        .unwrap();
+    }
+
+    let state: BountySponsor = svm.account(&record);
+    assert_eq!(state.sponsor, sponsor);
+    assert_eq!(state.bounty, pool);
+    assert_eq!(state.amount, 2 * SOL / 10);
+    assert_eq!(svm.lamports(&pool), pool_before + 2 * SOL / 10);
+
+    let bounty = svm.bounty(&bounty.bounty_id);
+    assert_eq!(bounty.total_pool, 6 * SOL / 10);
+    assert_eq!(bounty.remaining_pool, 6 * SOL / 10);
+    assert_eq!(bounty.sponsored_amount, 2 * SOL / 10);
+}
+
+#[test]
+fn fund_bonus_pool_moves_lamports_from_the_payer() {
+    let mut svm = Svm::new();
+    let authority = svm.wallet();
+    let payer = svm.wallet();
+    let bounty = create_bounty(&mut svm, authority, "bonus", SOL / 10, 2);
+    let (pool, _) = pda::bounty(&bounty.bounty_id);
+    let pool_before = svm.lamports(&pool);
+    let payer_before = svm.lamports(&payer);
+
+    let meta = svm
+        .process(instructions::fund_bonus_pool(
+            &bounty.bounty_id,
+            &authority,
+            &payer,
+            SOL / 20,
+        ))
+        .unwrap();
+
+    assert_eq!(svm.bounty(&bounty.bounty_id).bonus_pool, SOL / 20);
+    assert_eq!(svm.lamports(&pool), pool_before + SOL / 20);
+    assert_eq!(svm.lamports(&payer), payer_before - SOL / 20);
+    assert!(matches!(
+        &emitted(&meta)[..],
+        [ProgramEvent::BonusPoolFunded(event)] if event.bonus_pool == SOL / 20
+    ));
+}
+
+#[test]
+fn a_review_panel_quorum_pays_the_contributor() {
+    let mut svm = Svm::new();
+    let authority = svm.wallet();
+    let contributor = svm.wallet();
+    let reviewers = [svm.wallet(), svm.wallet()];
+    let bounty = create_bounty(&mut svm, authority, "panel", SOL / 10, 2);
+    svm.process(instructions::update_bounty_settings(
+        &bounty.bounty_id,
+        &authority,
+        args::SetReviewPanel {
+            reviewers: reviewers.to_vec(),
+            required_approvals: 2,
+        },
+    ))
+    .unwrap();
+    let (address, submission) = submit_video(&mut svm, &bounty.bounty_id, contributor, "video");
+    let before = svm.lamports(&contributor);
+
+    for (voted, &reviewer) in reviewers.iter().enumerate() {
+        let bounty = svm.bounty(&bounty.bounty_id);
+        svm.process(instructions::cast_review_vote(
+            &bounty,
+            &submission,
+            &reviewer,
+            true,
+            80,
+        ))
+        .unwrap();
+
+        let vote: ReviewVote = svm.account(&pda::review_vote(&address, &reviewer).0);
+        assert_eq!(vote.reviewer, reviewer);
+        assert!(vote.approve);
+        let state: VideoSubmission = svm.account(&address);
+        assert_eq!(state.approvals as usize, voted + 1);
+    }
+
+    let state: VideoSubmission = svm.account(&address);
+    assert!(state.status == SubmissionStatus::Approved);
+    assert_eq!(state.quality_score, 80);
+    assert_eq!(svm.lamports(&contributor), before + SOL / 10);
+    assert_eq!(svm.bounty(&bounty.bounty_id).videos_collected, 1);
+}
+
+#[test]
+fn a_registered_validator_attests_and_auto_approves() {
+    let mut svm = Svm::new();
+    let admin = config(&mut svm);
+    let authority = svm.wallet();
+    let contributor = svm.wallet();
+    let validator = svm.wallet();
+
+    let before = svm.lamports(&validator);
+    svm.process(instructions::register_validator(&validator, &admin, SOL))
+        .unwrap();
+    let oracle_address = pda::validator(&validator).0;
+    let oracle: ValidatorOracle = svm.account(&oracle_address);
+    assert!(oracle.active);
+    assert_eq!(oracle.stake, SOL);
+    assert_eq!(
+        svm.lamports(&oracle_address),
+        svm.rent(&oracle_address) + SOL
+    );
+    assert_eq!(
+        before - svm.lamports(&validator),
+        svm.lamports(&oracle_address)
+    );
+
+    let bounty = create_bounty(&mut svm, authority, "attested", SOL / 10, 2);
+    svm.process(instructions::update_bounty_settings(
+        &bounty.bounty_id,
+        &authority,
+        args::SetAttestationPolicy {
+            policy: AttestationPolicy {
+                auto_approve_min_score: Some(70),
+                auto_reject_max_score: None,
+                min_validator_stake: SOL,
+            },
+        },
+    ))
+    .unwrap();
+    let (address, submission) = submit_video(&mut svm, &bounty.bounty_id, contributor, "video");
+    let contributor_before = svm.lamports(&contributor);
+
+    let bounty = svm.bounty(&bounty.bounty_id);
+    svm.process(instructions::attest_submission(
+        &bounty,
+        &submission,
+        &validator,
+        args::AttestSubmission {
+            score: 85,
+            checks_passed: 0b111,
+            model_version: "qa-v1".to_string(),
+        },
+    ))
+    .unwrap();
+
+    let attestation: QualityAttestation = svm.account(&pda::attestation(&address, &validator).0);
+    assert_eq!(attestation.score, 85);
+    assert_eq!(attestation.model_version, "qa-v1");
+    assert_eq!(
+        svm.account::<ValidatorOracle>(&oracle_address)
+            .attestations_posted,
+        1
+    );
+    let state: VideoSubmission = svm.account(&address);
+    assert!(state.status == SubmissionStatus::Approved);
+    assert_eq!(svm.lamports(&contributor), contributor_before + SOL / 10);
+}
+
+#[test]
+fn attestations_leave_panel_bounties_to_the_quorum() {
+    let mut svm = Svm::new();
+    let admin = config(&mut svm);
+    let authority = svm.wallet();
+    let contributor = svm.wallet();
+    let validator = svm.wallet();
+    svm.process(instructions::register_validator(&validator, &admin, SOL))
+        .unwrap();
+
+    let bounty = create_bounty(&mut svm, authority, "panel-attested", SOL / 10, 2);
+    svm.process(instructions::update_bounty_settings(
+        &bounty.bounty_id,
+        &authority,
+        args::SetAttestationPolicy {
+            policy: AttestationPolicy {
+                auto_approve_min_score: Some(70),
+                auto_reject_max_score: None,
+                min_validator_stake: SOL,
+            },
+        },
+    ))
+    .unwrap();
+    svm.process(instructions::update_bounty_settings(
+        &bounty.bounty_id,
+        &authority,
+        args::SetReviewPanel {
+            reviewers: vec![Pubkey::new_unique()],
+            required_approvals: 1,
+        },
+    ))
+    .unwrap();
+    let (address, submission) = submit_video(&mut svm, &bounty.bounty_id, contributor, "video");
+
+    let bounty = svm.bounty(&bounty.bounty_id);
+    svm.process(instructions::attest_submission(
+        &bounty,
+        &submission,
+        &validator,
+        args::AttestSubmission {
+            score: 95,
+            checks_passed: 0b111,
+            model_version: "qa-v1".to_string(),
+        },
+    ))
+    .unwrap();
+
+    // Recorded, but the submission waits for the panel
+    assert!(svm.exists(&pda::attestation(&address, &validator).0));
+    let state: VideoSubmission = svm.account(&address);
+    assert!(state.status == SubmissionStatus::Pending);
+    assert_eq!(svm.bounty(&bounty.bounty_id).pending_submissions, 1);
+}
+
+#[test]
+fn initialize_profile_opens_a_neutral_profile() {
+    let mut svm = Svm::new();
+    let contributor = svm.wallet();
+
+    let meta = svm
+        .process(instructions::initialize_profile(&contributor))
+        .unwrap();
+
+    let profile: ContributorProfile = svm.account(&pda::profile(&contributor).0);
+    assert_eq!(profile.wallet, contributor);
+    assert_eq!(profile.reputation_score, NEUTRAL_REPUTATION);
+    assert!(profile.badges.is_empty());
+    assert!(matches!(
+        &emitted(&meta)[..],
+        [ProgramEvent::ProfileCreated(event)] if event.wallet == contributor
+    ));
+}
+
+#[test]
+fn the_admin_creates_badge_definitions() {
+    let mut svm = Svm::new();
+    let admin = config(&mut svm);
+
+    svm.process(instructions::create_badge_definition(
+        &admin,
+        args::CreateBadgeDefinition {
+            badge_id: 7,
+            name: "Knife Skills".to_string(),
+            category: Some(CATEGORY.to_string()),
+            criteria: "Ten accepted kitchen videos".to_string(),
+            rule: AutoAwardRule::AcceptedSubmissions { min: 10 },
+        },
+    ))
+    .unwrap();
+
+    let definition: BadgeDefinition = svm.account(&pda::badge_definition(7).0);
+    assert_eq!(definition.badge_id, 7);
+    assert_eq!(definition.name, "Knife Skills");
+    assert!(definition.rule == AutoAwardRule::AcceptedSubmissions { min: 10 });
+}
+
+#[test]
+fn badge_mints_are_created_over_a_prefunded_address() {
+    let mut svm = Svm::new();
+    let admin = config(&mut svm);
+    let (mint, _) = pda::badge_mint(&BadgeType::EarlyAdopter);
+    // Anyone can send lamports to the mint's address ahead of time
+    svm.airdrop(&mint, 1);
+
+    let meta = svm
+        .process(instructions::initialize_badge_mint(
+            &admin,
+            BadgeType::EarlyAdopter,
+            "https://badges/early-adopter.json".to_string(),
+        ))
+        .unwrap();
+
+    let account = svm.get_account(&mint).unwrap();
+    assert_eq!(account.owner, token_2022::ID);
+    let state = StateWithExtensions::<Mint>::unpack(&account.data).unwrap();
+    assert!(state.get_extension::<NonTransferable>().is_ok());
+    assert_eq!(state.base.decimals, 0);
+    assert_eq!(svm.lamports(&mint), svm.rent(&mint));
+    assert!(matches!(
+        &emitted(&meta)[..],
+        [ProgramEvent::BadgeMintInitialized(event)] if event.mint == mint
+    ));
+}
+
+fn dataset_args(label: &str, price: u64) -> args::CreateDataset {
+    args::CreateDataset {
+        dataset_id: id16(label),
+        license_type: LicenseType::Unlimited,
+        price,
+        royalty_percentage: 10,
+    }
+}
+
+#[test]
+fn purchases_open_a_license_once_and_pay_the_creator() {
+    let mut svm = Svm::new();
+    let creator = svm.wallet();
+    let buyer = svm.wallet();
+    let bounty_id = completed_bounty(&mut svm, creator, "source");
+    svm.process(instructions::create_dataset(
+        &creator,
+        &bounty_id,
+        dataset_args("set", SOL),
+    ))
+    .unwrap();
+    let address = pda::dataset(&id16("set")).0;
+    let dataset: DatasetNFT = svm.account(&address);
+    assert_eq!(dataset.creator, creator);
+    assert_eq!(dataset.bounty, pda::bounty(&bounty_id).0);
+    // Nobody co-funded the bounty, so the creator keeps the whole price
+    assert_eq!(dataset.sponsor_bps, 0);
+
+    let license_address = pda::license(&address, &buyer).0;
+    let creator_before = svm.lamports(&creator);
+    let buyer_before = svm.lamports(&buyer);
+    svm.process(instructions::purchase_dataset(&dataset, &buyer))
+        .unwrap();
+
+    let license: DatasetLicense = svm.account(&license_address);
+    assert_eq!(license.holder, buyer);
+    assert_eq!(license.dataset, address);
+    assert_eq!(license.purchases, 1);
+    assert_eq!(svm.lamports(&creator), creator_before + SOL);
+    assert_eq!(
+        svm.lamports(&buyer),
+        buyer_before - SOL - svm.lamports(&license_address)
+    );
+
+    // A repeat purchase reuses the license without paying its rent again
+    let license_rent = svm.lamports(&license_address);
+    let buyer_before = svm.lamports(&buyer);
+    svm.process(instructions::purchase_dataset(&dataset, &buyer))
+        .unwrap();
+    let license: DatasetLicense = svm.account(&license_address);
+    assert_eq!(license.purchases, 2);
+    assert_eq!(license.total_paid, 2 * SOL);
+    assert_eq!(svm.lamports(&license_address), license_rent);
+    assert_eq!(svm.lamports(&buyer), buyer_before - SOL);
+    assert_eq!(svm.account::<DatasetNFT>(&address).total_sales, 2);
+}
+
+#[test]
+fn datasets_from_a_bounty_hold_the_sponsor_share() {
+    let mut svm = Svm::new();
+    let creator = svm.wallet();
+    let sponsor = svm.wallet();
+    let contributor = svm.wallet();
+    let buyer = svm.wallet();
+    let bounty = create_bounty(&mut svm, creator, "source", SOL / 10, 2);
+    svm.process(instructions::contribute_to_bounty(
+        &bounty.bounty_id,
+        &sponsor,
+        SOL / 5,
+    ))
+    .unwrap();
+    let (_, submission) = submit_video(&mut svm, &bounty.bounty_id, contributor, "video");
+    let state = svm.bounty(&bounty.bounty_id);
+    svm.process(instructions::approve_submission(&state, &submission, 90))
+        .unwrap();
+    svm.process(instructions::complete_bounty(&bounty.bounty_id, &creator))
+        .unwrap();
+
+    svm.process(instructions::create_dataset(
+        &creator,
+        &bounty.bounty_id,
+        dataset_args("sourced", SOL),
+    ))
+    .unwrap();
+    let dataset: DatasetNFT = svm.account(&pda::dataset(&id16("sourced")).0);
+    let (pool, _) = pda::bounty(&bounty.bounty_id);
+    assert_eq!(dataset.bounty, pool);
+    // The sponsor put in half of the 0.4 SOL pool
+    assert_eq!(dataset.sponsor_bps, 5_000);
+
+    let pool_before = svm.lamports(&pool);
+    let creator_before = svm.lamports(&creator);
+    svm.process(instructions::purchase_dataset(&dataset, &buyer))
+        .unwrap();
+
+    assert_eq!(svm.lamports(&pool), pool_before + SOL / 2);
+    assert_eq!(svm.lamports(&creator), creator_before + SOL / 2);
+    assert_eq!(svm.bounty(&bounty.bounty_id).sponsor_proceeds, SOL / 2);
+}
+
+#[test]
+fn suspending_a_wallet_without_a_profile_creates_one() {
+    let mut svm = Svm::new();
+    let admin = config(&mut svm);
+    let wallet = Pubkey::new_unique();
+
+    svm.process(instructions::suspend_contributor(
+        &wallet,
+        &admin,
+        SuspensionReason::Spam,
+        0,
+    ))
+    .unwrap();
+
+    let profile: ContributorProfile = svm.account(&pda::profile(&wallet).0);
+    assert_eq!(profile.wallet, wallet);
+    assert_eq!(profile.suspended_until, i64::MAX);
+    assert!(profile.suspension_reason == Some(SuspensionReason::Spam));
+}
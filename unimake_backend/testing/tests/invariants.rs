@@ -0,0 +1,520 @@
+//! Property tests for pool accounting against the SBF build: random sequences of
+//! submissions, reviews, sponsorship and bounty status changes against one bounty, with
+//! the accounting invariants checked after every step, whether the step succeeded or
+//! not.
+//!
+//! Every step runs through the program, including `create_bounty`, `submit_video`,
+//! `contribute_to_bounty` and `fund_bonus_pool`, so the lamports of the accounts involved
+//! are conserved exactly from the first step to the last.
+
+#![cfg(program_built)]
+
+use std::collections::HashMap;
+
+use anchor_lang::prelude::Pubkey;
+use proptest::prelude::*;
+use proptest::sample::Index;
+use unimake_backend::{
+    instruction as args, BountyPool, BountySponsor, BountyStatus, CategoryReputation,
+    ContributorProfile, RejectionReason, ReviewDecision, RewardTier, SubmissionStatus,
+    VideoSubmission, MAX_BATCH_REVIEWS,
+};
+use unimake_client::{instructions, pda};
+use unimake_testing::*;
+
+const REWARD: u64 = SOL / 10;
+const CONTRIBUTORS: usize = 3;
+const SPONSORS: usize = 3;
+const MAX_REPUTATION: u16 = 1000;
+
+#[derive(Debug, Clone)]
+enum Op {
+    Submit {
+        contributor: usize,
+    },
+    Approve {
+        pick: Index,
+        score: u8,
+    },
+    Reject {
+        pick: Index,
+        spam: bool,
+    },
+    Batch {
+        items: Vec<(Index, Option<u8>)>,
+        atomic: bool,
+    },
+    Sponsor {
+        sponsor: usize,
+        amount: u64,
+    },
+    ClaimRefund {
+        sponsor: usize,
+    },
+    FundBonus {
+        amount: u64,
+    },
+    Pause,
+    Resume,
+    Complete,
+    Cancel,
+    CloseExpired,
+    Advance {
+        days: u8,
+    },
+    Refresh {
+        contributor: usize,
+    },
+}
+
+/// Scores past 100 are included so invalid reviews get exercised too
+fn score() -> impl Strategy<Value = u8> {
+    prop_oneof![9 => 0..=100u8, 1 => 101..=u8::MAX]
+}
+
+fn op() -> impl Strategy<Value = Op> {
+    prop_oneof![
+        6 => (0..CONTRIBUTORS).prop_map(|contributor| Op::Submit { contributor }),
+        4 => (any::<Index>(), score()).prop_map(|(pick, score)| Op::Approve { pick, score }),
+        3 => (any::<Index>(), any::<bool>()).prop_map(|(pick, spam)| Op::Reject { pick, spam }),
+        2 => (
+            prop::collection::vec((any::<Index>(), prop::option::of(score())), 1..6),
+            any::<bool>()
+        )
+            .prop_map(|(items, atomic)| Op::Batch { items, atomic }),
+        2 => (0..SPONSORS, 1..=SOL).prop_map(|(sponsor, amount)| Op::Sponsor { sponsor, amount }),
+        1 => (0..SPONSORS).prop_map(|sponsor| Op::ClaimRefund { sponsor }),
+        1 => (1..=REWARD).prop_map(|amount| Op::FundBonus { amount }),
+        1 => Just(Op::Pause),
+        1 => Just(Op::Resume),
+        1 => Just(Op::Complete),
+        1 => Just(Op::Cancel),
+        1 => Just(Op::CloseExpired),
+        2 => (0..12u8).prop_map(|days| Op::Advance { days }),
+        1 => (0..CONTRIBUTORS).prop_map(|contributor| Op::Refresh { contributor }),
+    ]
+}
+
+/// One bounty, its participants and everything submitted to it
+struct Harness {
+    svm: Svm,
+    bounty_id: [u8; 16],
+    pool: Pubkey,
+    authority: Pubkey,
+    contributors: Vec<Pubkey>,
+    sponsors: Vec<Pubkey>,
+    /// Submission and contributor, in sequence order
+    submissions: Vec<(Pubkey, Pubkey)>,
+    /// Accounts each submission created besides itself: its content records
+    content: HashMap<Pubkey, [Pubkey; 2]>,
+    /// Submission attempts so far, so every attempt uploads a new video
+    attempts: usize,
+    /// Lamports each contributor started with
+    funded: HashMap<Pubkey, u64>,
+    /// Status each submission had after the previous step
+    statuses: Vec<SubmissionStatus>,
+    /// Every account a step can move lamports between
+    accounts: Vec<Pubkey>,
+    total: u128,
+}
+
+impl Harness {
+    fn new(target: u32, bond: u64, tiers: bool) -> Self {
+        let mut svm = Svm::new();
+        let authority = svm.wallet();
+        let contributors: Vec<Pubkey> = (0..CONTRIBUTORS).map(|_| svm.wallet()).collect();
+        let sponsors: Vec<Pubkey> = (0..SPONSORS).map(|_| svm.wallet()).collect();
+        let bounty_id = id16("invariants");
+        let (pool, _) = pda::bounty(&bounty_id);
+
+        let mut accounts = vec![authority, pool, pda::buyer_profile(&authority).0];
+        for &wallet in &contributors {
+            accounts.extend([
+                wallet,
+                pda::profile(&wallet).0,
+                pda::category_reputation(&wallet, CATEGORY).0,
+            ]);
+        }
+        for &sponsor in &sponsors {
+            accounts.extend([sponsor, pda::sponsor(&pool, &sponsor).0]);
+        }
+        let funded = contributors
+            .iter()
+            .map(|&contributor| (contributor, svm.lamports(&contributor)))
+            .collect();
+        let total = svm.total_lamports(&accounts);
+
+        let mut harness = Harness {
+            svm,
+            bounty_id,
+            pool,
+            authority,
+            contributors,
+            sponsors,
+            submissions: vec![],
+            content: HashMap::new(),
+            attempts: 0,
+            funded,
+            statuses: vec![],
+            accounts,
+            total,
+        };
+
+        let authority = harness.authority;
+        let args = bounty_args("invariants", REWARD, target, harness.svm.now() + 30 * DAY);
+        harness
+            .svm
+            .process(instructions::create_bounty(&authority, &authority, args))
+            .expect("create_bounty");
+        harness
+            .svm
+            .process(instructions::update_bounty_settings(
+                &bounty_id,
+                &authority,
+                args::SetSubmissionBond { bond },
+            ))
+            .expect("set_submission_bond");
+        if tiers {
+            let tiers = instructions::update_bounty_settings(
+                &bounty_id,
+                &authority,
+                args::SetRewardTiers {
+                    tiers: vec![
+                        RewardTier {
+                            min_score: 0,
+                            payout_bps: 5_000,
+                        },
+                        RewardTier {
+                            min_score: 50,
+                            payout_bps: RewardTier::BASE_PAYOUT_BPS,
+                        },
+                        RewardTier {
+                            min_score: 90,
+                            payout_bps: RewardTier::MAX_PAYOUT_BPS,
+                        },
+                    ],
+                },
+            );
+            harness.svm.process(tiers).expect("set_reward_tiers");
+        }
+        harness
+    }
+
+    fn bounty(&self) -> BountyPool {
+        self.svm.bounty(&self.bounty_id)
+    }
+
+    fn pick(&self, index: &Index) -> Option<VideoSubmission> {
+        (!self.submissions.is_empty()).then(|| self.svm.account(&index.get(&self.submissions).0))
+    }
+
+    fn track(&mut self, account: Pubkey) {
+        if !self.accounts.contains(&account) {
+            self.accounts.push(account);
+        }
+    }
+
+    fn apply(&mut self, op: &Op) {
+        let pool = self.bounty();
+        match op {
+            Op::Submit { contributor } => {
+                let contributor = self.contributors[*contributor];
+                let label = format!("video-{}", self.attempts);
+                self.attempts += 1;
+                let address = pda::submission(&self.pool, pool.submission_count).0;
+                let hash = content_hash(&label);
+                let content = [
+                    pda::content(&hash).0,
+                    pda::bounty_content(&self.pool, &hash).0,
+                ];
+                for account in [address, content[0], content[1]] {
+                    self.track(account);
+                }
+                let result = self.svm.process(instructions::submit_video(
+                    &pool,
+                    &contributor,
+                    submit_args(&label),
+                ));
+                // A submission only fails for what `submit_video` requires of the bounty
+                let open = pool.status == BountyStatus::Active
+                    && pool.has_open_slot()
+                    && pool.remaining_pool >= pool.reward_per_video
+                    && self.svm.now() < pool.expires_at;
+                assert_eq!(result.is_ok(), open, "{result:?}");
+                if result.is_ok() {
+                    self.submissions.push((address, contributor));
+                    self.content.insert(address, content);
+                }
+            }
+            Op::Approve { pick, score } => {
+                if let Some(submission) = self.pick(pick) {
+                    let settled = is_settled(&submission);
+                    let approved = self
+                        .svm
+                        .process(instructions::approve_submission(&pool, &submission, *score))
+                        .is_ok();
+                    assert!(!settled || !approved, "settled submission approved again");
+                }
+            }
+            Op::Reject { pick, spam } => {
+                if let Some(submission) = self.pick(pick) {
+                    let settled = is_settled(&submission);
+                    let reason = if *spam {
+                        RejectionReason::Spam
+                    } else {
+                        RejectionReason::LowResolution
+                    };
+                    let rejected = self
+                        .svm
+                        .process(instructions::reject_submission(
+                            &pool,
+                            &submission,
+                            reason,
+                            String::new(),
+                        ))
+                        .is_ok();
+                    assert!(!settled || !rejected, "settled submission rejected again");
+                }
+            }
+            Op::Batch { items, atomic } => {
+                if !self.submissions.is_empty() {
+                    let items: Vec<(VideoSubmission, ReviewDecision)> = items
+                        .iter()
+                        .take(MAX_BATCH_REVIEWS)
+                        .map(|(pick, score)| {
+                            let decision = match score {
+                                Some(quality_score) => ReviewDecision::Approve {
+                                    quality_score: *quality_score,
+                                },
+                                None => ReviewDecision::Reject {
+                                    reason: RejectionReason::Other,
+                                },
+                            };
+                            (self.pick(pick).unwrap(), decision)
+                        })
+                        .collect();
+                    let items: Vec<_> = items
+                        .iter()
+                        .map(|(submission, decision)| (submission, *decision))
+                        .collect();
+                    let _ = self
+                        .svm
+                        .process(instructions::batch_review(&pool, &items, *atomic));
+                }
+            }
+            Op::Sponsor { sponsor, amount } => {
+                let sponsor = self.sponsors[*sponsor];
+                let _ = self.svm.process(instructions::contribute_to_bounty(
+                    &self.bounty_id,
+                    &sponsor,
+                    *amount,
+                ));
+            }
+            Op::ClaimRefund { sponsor } => {
+                let sponsor = self.sponsors[*sponsor];
+                let _ = self.svm.process(instructions::claim_sponsor_refund(
+                    &self.bounty_id,
+                    &sponsor,
+                ));
+            }
+            Op::FundBonus { amount } => {
+                let authority = self.authority;
+                let _ = self.svm.process(instructions::fund_bonus_pool(
+                    &self.bounty_id,
+                    &authority,
+                    &authority,
+                    *amount,
+                ));
+            }
+            Op::Pause => {
+                let authority = self.authority;
+                let _ = self
+                    .svm
+                    .process(instructions::pause_bounty(&self.bounty_id, &authority));
+            }
+            Op::Resume => {
+                let authority = self.authority;
+                let _ = self
+                    .svm
+                    .process(instructions::resume_bounty(&self.bounty_id, &authority));
+            }
+            Op::Complete => {
+                let authority = self.authority;
+                let _ = self
+                    .svm
+                    .process(instructions::complete_bounty(&self.bounty_id, &authority));
+            }
+            Op::Cancel => {
+                let authority = self.authority;
+                let _ = self
+                    .svm
+                    .process(instructions::cancel_bounty(&self.bounty_id, &authority));
+            }
+            Op::CloseExpired => {
+                let _ = self.svm.process(instructions::close_expired_bounty(&pool));
+            }
+            Op::Advance { days } => self.svm.advance(*days as i64 * DAY),
+            Op::Refresh { contributor } => {
+                // Profiles open on a contributor's first submission
+                let wallet = self.contributors[*contributor];
+                if self.svm.exists(&pda::profile(&wallet).0) {
+                    self.svm
+                        .process(instructions::refresh_reputation(&wallet))
+                        .expect("refresh_reputation");
+                }
+            }
+        }
+    }
+
+    fn check_invariants(&mut self) {
+        let svm = &self.svm;
+        let pool = self.bounty();
+        let submissions: Vec<VideoSubmission> = self
+            .submissions
+            .iter()
+            .map(|(key, _)| svm.account(key))
+            .collect();
+
+        // The program only moves lamports between the accounts involved
+        assert_eq!(
+            svm.total_lamports(&self.accounts),
+            self.total,
+            "lamports created or destroyed"
+        );
+
+        // The pool can cover every claim on it
+        let escrowed: u64 = submissions
+            .iter()
+            .filter(|submission| submission.status == SubmissionStatus::Pending)
+            .map(|submission| submission.escrow_amount)
+            .sum();
+        let refunds_owed: u64 = self
+            .sponsors
+            .iter()
+            .filter_map(|sponsor| {
+                svm.try_account::<BountySponsor>(&pda::sponsor(&self.pool, sponsor).0)
+            })
+            .map(|record| {
+                pool.refund_share(record.amount) - record.refunded
+                    + (pool.proceeds_share(record.amount) - record.proceeds_claimed)
+            })
+            .sum::<u64>()
+            + (pool.refund_share(pool.authority_deposit()) - pool.authority_refunded);
+        let claims =
+            svm.rent(&self.pool) + pool.remaining_pool + pool.bonus_pool + escrowed + refunds_owed;
+        assert!(
+            svm.lamports(&self.pool) >= claims,
+            "pool holds {} lamports for {claims} in claims",
+            svm.lamports(&self.pool)
+        );
+
+        // Each approval counts once, and never past the target
+        let approved = submissions
+            .iter()
+            .filter(|submission| submission.status == SubmissionStatus::Approved)
+            .count() as u32;
+        assert_eq!(pool.videos_collected, approved);
+        assert!(pool.videos_collected <= pool.videos_target);
+
+        // Every unsettled submission holds a slot, so approvals can't outrun the target
+        let unsettled = submissions
+            .iter()
+            .filter(|submission| {
+                submission.status == SubmissionStatus::Pending
+                    || submission.status == SubmissionStatus::Disputed
+            })
+            .count() as u32;
+        assert_eq!(pool.pending_submissions, unsettled);
+        assert!(pool.videos_collected + pool.pending_submissions <= pool.videos_target);
+
+        // Settled submissions stay settled
+        for (previous, submission) in self.statuses.iter().zip(&submissions) {
+            if *previous == SubmissionStatus::Approved || *previous == SubmissionStatus::Rejected {
+                assert!(
+                    submission.status == *previous,
+                    "settled submission reopened"
+                );
+            }
+        }
+        self.statuses = submissions.iter().map(|s| s.status.clone()).collect();
+
+        for &wallet in &self.contributors {
+            let profile_address = pda::profile(&wallet).0;
+            let category_address = pda::category_reputation(&wallet, CATEGORY).0;
+            let Some(profile) = svm.try_account::<ContributorProfile>(&profile_address) else {
+                // Without a profile the contributor never submitted, so paid for nothing
+                assert_eq!(svm.lamports(&wallet), self.funded[&wallet]);
+                continue;
+            };
+            let category: CategoryReputation = svm.account(&category_address);
+            assert!(profile.reputation_score <= MAX_REPUTATION);
+            assert!(category.reputation_score <= MAX_REPUTATION);
+
+            // A contributor's wallet moves only by the rent of the accounts its
+            // submissions created, bonds and recorded earnings, so a reward paid twice or
+            // paid without being recorded shows up here
+            let theirs = || {
+                self.submissions
+                    .iter()
+                    .zip(&submissions)
+                    .filter(move |(_, submission)| submission.contributor == wallet)
+            };
+            let rent_paid: u64 = svm.rent(&profile_address)
+                + svm.rent(&category_address)
+                + theirs()
+                    .map(|((address, _), _)| {
+                        let [content, bounty_content] = self.content[address];
+                        svm.rent(address) + svm.rent(&content) + svm.rent(&bounty_content)
+                    })
+                    .sum::<u64>();
+            let bonds_held: u64 = theirs().map(|(_, submission)| submission.bond_amount).sum();
+            let bonds_forfeited: u64 = theirs()
+                .filter(|(_, submission)| {
+                    submission
+                        .rejection_reason
+                        .is_some_and(|reason| reason.forfeits_bond())
+                })
+                .count() as u64
+                * pool.submission_bond;
+            assert_eq!(
+                svm.lamports(&wallet) + rent_paid + bonds_held + bonds_forfeited,
+                self.funded[&wallet] + profile.total_earnings,
+                "contributor balance doesn't match recorded earnings"
+            );
+
+            // No approval pays more than the top tier of its escrow
+            let max_earnings: u64 = theirs()
+                .filter(|(_, submission)| submission.status == SubmissionStatus::Approved)
+                .map(|(_, submission)| {
+                    submission.escrow_amount * RewardTier::MAX_PAYOUT_BPS as u64
+                        / RewardTier::BASE_PAYOUT_BPS as u64
+                })
+                .sum();
+            assert!(profile.total_earnings <= max_earnings);
+        }
+    }
+}
+
+fn is_settled(submission: &VideoSubmission) -> bool {
+    submission.status == SubmissionStatus::Approved
+        || submission.status == SubmissionStatus::Rejected
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(128))]
+
+    #[test]
+    fn pool_accounting_holds_across_random_sequences(
+        target in 1..6u32,
+        bond in prop_oneof![Just(0), Just(SOL / 100)],
+        tiers in any::<bool>(),
+        ops in prop::collection::vec(op(), 1..48),
+    ) {
+        let mut harness = Harness::new(target, bond, tiers);
+        harness.check_invariants();
+        for op in &ops {
+            harness.apply(op);
+            harness.check_invariants();
+        }
+    }
+}
@@ -0,0 +1,192 @@
+//! A bounty from creation to its last refund claim, with every lamport accounted for.
+//! Fees come from the harness's own fee payer, so the wallets and program accounts below
+//! only trade lamports with each other.
+
+#![cfg(program_built)]
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::instruction::Instruction;
+use unimake_backend::{
+    instruction as args, BountyStatus, RejectionReason, RewardTier, SubmissionStatus,
+    VideoSubmission,
+};
+use unimake_client::{instructions, pda};
+use unimake_testing::*;
+
+/// Every account the lifecycle touches, and the lamports they held at the start
+struct Ledger {
+    accounts: Vec<Pubkey>,
+    total: u128,
+}
+
+impl Ledger {
+    fn new(svm: &Svm, accounts: Vec<Pubkey>) -> Self {
+        let total = svm.total_lamports(&accounts);
+        Ledger { accounts, total }
+    }
+
+    #[track_caller]
+    fn check(&self, svm: &Svm) {
+        assert_eq!(svm.total_lamports(&self.accounts), self.total);
+    }
+}
+
+fn process(svm: &mut Svm, ledger: &Ledger, ix: Instruction) {
+    svm.process(ix).unwrap();
+    ledger.check(svm);
+}
+
+#[test]
+fn lamports_are_conserved_from_creation_to_the_last_refund() {
+    let mut svm = Svm::new();
+    let authority = svm.wallet();
+    let sponsor = svm.wallet();
+    let contributors = [svm.wallet(), svm.wallet()];
+    let bounty_id = id16("lifecycle");
+    let (pool, _) = pda::bounty(&bounty_id);
+
+    let mut accounts = vec![
+        authority,
+        sponsor,
+        pool,
+        pda::buyer_profile(&authority).0,
+        pda::sponsor(&pool, &sponsor).0,
+    ];
+    for sequence in 0..3 {
+        accounts.push(pda::submission(&pool, sequence).0);
+    }
+    for (contributor, label) in contributors.iter().zip(["a", "b"]) {
+        let wallet = *contributor;
+        accounts.extend([
+            wallet,
+            pda::profile(&wallet).0,
+            pda::category_reputation(&wallet, CATEGORY).0,
+        ]);
+        for video in [format!("{label}-1"), format!("{label}-2")] {
+            let hash = content_hash(&video);
+            accounts.extend([pda::content(&hash).0, pda::bounty_content(&pool, &hash).0]);
+        }
+    }
+    let ledger = Ledger::new(&svm, accounts);
+
+    // Create, co-fund and set up the bounty
+    let args = bounty_args("lifecycle", SOL / 10, 3, svm.now() + 30 * DAY);
+    process(
+        &mut svm,
+        &ledger,
+        instructions::create_bounty(&authority, &authority, args),
+    );
+    process(
+        &mut svm,
+        &ledger,
+        instructions::contribute_to_bounty(&bounty_id, &sponsor, SOL / 10),
+    );
+    process(
+        &mut svm,
+        &ledger,
+        instructions::fund_bonus_pool(&bounty_id, &authority, &authority, SOL / 20),
+    );
+    process(
+        &mut svm,
+        &ledger,
+        instructions::update_bounty_settings(
+            &bounty_id,
+            &authority,
+            args::SetRewardTiers {
+                tiers: vec![
+                    RewardTier {
+                        min_score: 0,
+                        payout_bps: 5_000,
+                    },
+                    RewardTier {
+                        min_score: 90,
+                        payout_bps: 15_000,
+                    },
+                ],
+            },
+        ),
+    );
+    process(
+        &mut svm,
+        &ledger,
+        instructions::update_bounty_settings(
+            &bounty_id,
+            &authority,
+            args::SetSubmissionBond { bond: SOL / 100 },
+        ),
+    );
+
+    // Three submissions, paying rent and bonds
+    let mut submissions = Vec::new();
+    for (contributor, video) in [
+        (contributors[0], "a-1"),
+        (contributors[1], "b-1"),
+        (contributors[0], "a-2"),
+    ] {
+        let bounty = svm.bounty(&bounty_id);
+        let address = pda::submission(&pool, bounty.submission_count).0;
+        process(
+            &mut svm,
+            &ledger,
+            instructions::submit_video(&bounty, &contributor, submit_args(video)),
+        );
+        submissions.push(svm.account::<VideoSubmission>(&address));
+    }
+
+    // A bonus-tier approval and a spam rejection that forfeits the bond
+    let bounty = svm.bounty(&bounty_id);
+    process(
+        &mut svm,
+        &ledger,
+        instructions::approve_submission(&bounty, &submissions[0], 95),
+    );
+    process(
+        &mut svm,
+        &ledger,
+        instructions::reject_submission(
+            &bounty,
+            &submissions[1],
+            RejectionReason::Spam,
+            String::new(),
+        ),
+    );
+
+    // Cancel with one submission still pending, then settle it late at a reduced tier
+    process(
+        &mut svm,
+        &ledger,
+        instructions::cancel_bounty(&bounty_id, &authority),
+    );
+    let bounty = svm.bounty(&bounty_id);
+    assert!(bounty.status == BountyStatus::Cancelled);
+    process(
+        &mut svm,
+        &ledger,
+        instructions::approve_submission(&bounty, &submissions[2], 50),
+    );
+
+    // Both depositors claim their share of the refund pool
+    process(
+        &mut svm,
+        &ledger,
+        instructions::claim_sponsor_refund(&bounty_id, &sponsor),
+    );
+    process(
+        &mut svm,
+        &ledger,
+        instructions::claim_authority_refund(&bounty_id, &authority),
+    );
+
+    for (sequence, submission) in submissions.iter().enumerate() {
+        let address = pda::submission(&pool, sequence as u32).0;
+        let state: VideoSubmission = svm.account(&address);
+        assert!(state.status != SubmissionStatus::Pending);
+        // Bonds have all been paid out, leaving only rent
+        assert_eq!(svm.lamports(&address), svm.rent(&address));
+        assert_eq!(state.contributor, submission.contributor);
+    }
+
+    // The refund shares divide evenly here, so the pool is down to its rent
+    assert_eq!(svm.bounty(&bounty_id).pending_submissions, 0);
+    assert_eq!(svm.lamports(&pool), svm.rent(&pool));
+}
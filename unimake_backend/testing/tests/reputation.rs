@@ -0,0 +1,377 @@
+#![cfg(program_built)]
+
+use anchor_lang::error::ErrorCode as AnchorError;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+use anchor_spl::token_2022::spl_token_2022::extension::StateWithExtensions;
+use anchor_spl::token_2022::spl_token_2022::state::Account as TokenAccount;
+use anchor_spl::{associated_token, token_2022};
+use unimake_backend::{
+    accounts, instruction, AutoAwardRule, Badge, BadgeType, CategoryReputation, ContributorProfile,
+    ErrorCode, RejectionReason, NEUTRAL_REPUTATION,
+};
+use unimake_client::instructions;
+use unimake_testing::*;
+
+fn setup() -> (Svm, Pubkey, Pubkey) {
+    let mut svm = Svm::new();
+    let contributor = svm.wallet();
+    let profile = contributor_profile(&mut svm, contributor);
+    (svm, contributor, profile)
+}
+
+/// A contributor with `count` approved submissions scored `score`
+fn with_approvals(svm: &mut Svm, contributor: Pubkey, count: u32, score: u8) {
+    let authority = svm.wallet();
+    let bounty = open_bounty(svm, authority, "approvals", SOL / 100, count);
+    for i in 0..count {
+        let submission = submit(svm, &bounty, contributor, &format!("video-{i}"));
+        approve(svm, &bounty, submission, contributor, score);
+    }
+}
+
+fn award_badge(profile: Pubkey, admin: Pubkey, badge_type: BadgeType) -> Instruction {
+    ix(
+        accounts::AwardBadge {
+            contributor_profile: profile,
+            config: pda::config(),
+            admin,
+        },
+        instruction::AwardBadge { badge_type },
+    )
+}
+
+#[test]
+fn badges_are_awarded_once() {
+    let (mut svm, _, profile) = setup();
+    let admin = config(&mut svm);
+    let award = || award_badge(profile, admin, BadgeType::EarlyAdopter);
+
+    svm.process(award()).unwrap();
+    let stored: ContributorProfile = svm.account(&profile);
+    assert!(stored.has_badge(&BadgeType::EarlyAdopter));
+    assert_eq!(stored.badges[0].earned_at, svm.now());
+
+    assert_error(svm.process(award()), ErrorCode::BadgeAlreadyEarned);
+}
+
+#[test]
+fn only_the_admin_awards_badges() {
+    let (mut svm, _, profile) = setup();
+    config(&mut svm);
+    let stranger = svm.wallet();
+
+    assert_error(
+        svm.process(award_badge(profile, stranger, BadgeType::EarlyAdopter)),
+        AnchorError::ConstraintHasOne,
+    );
+}
+
+#[test]
+fn milestone_badges_cannot_be_awarded_by_hand() {
+    let (mut svm, _, profile) = setup();
+    let admin = config(&mut svm);
+
+    for badge_type in [
+        BadgeType::FirstVideo,
+        BadgeType::HundredVideos,
+        BadgeType::ThousandVideos,
+        BadgeType::HighQuality,
+    ] {
+        assert_error(
+            svm.process(award_badge(profile, admin, badge_type)),
+            ErrorCode::MilestoneBadge,
+        );
+    }
+    let stored: ContributorProfile = svm.account(&profile);
+    assert!(stored.badges.is_empty());
+}
+
+#[test]
+fn refreshing_decays_an_idle_reputation_toward_neutral() {
+    let (mut svm, contributor, profile) = setup();
+    with_approvals(&mut svm, contributor, 5, 95);
+    let active: ContributorProfile = svm.account(&profile);
+    let refresh = || {
+        ix(
+            accounts::RefreshReputation {
+                contributor_profile: profile,
+            },
+            instruction::RefreshReputation {},
+        )
+    };
+
+    // Refreshing right away changes nothing
+    svm.process(refresh()).unwrap();
+    let refreshed: ContributorProfile = svm.account(&profile);
+    assert_eq!(refreshed.reputation_score, active.reputation_score);
+
+    svm.advance(365 * DAY);
+    svm.process(refresh()).unwrap();
+    let idle: ContributorProfile = svm.account(&profile);
+    assert!(idle.reputation_score < active.reputation_score);
+    assert!(idle.reputation_score >= NEUTRAL_REPUTATION);
+    assert!(idle.reputation_confidence < active.reputation_confidence);
+}
+
+#[test]
+fn zero_scored_approvals_count_toward_both_averages() {
+    let (mut svm, contributor, profile) = setup();
+    let authority = svm.wallet();
+    let bounty = open_bounty(&mut svm, authority, "zero-score", SOL / 100, 2);
+    for (label, score) in [("video-100", 100), ("video-0", 0)] {
+        let submission = submit(&mut svm, &bounty, contributor, label);
+        approve(&mut svm, &bounty, submission, contributor, score);
+    }
+
+    let global: ContributorProfile = svm.account(&profile);
+    let category: CategoryReputation = svm.account(&pda::category(&contributor, CATEGORY));
+    assert_eq!(global.average_quality_score, 50);
+    assert_eq!(category.average_quality_score, 50);
+    assert_eq!(global.reputation_score, category.reputation_score);
+}
+
+#[test]
+fn idle_time_does_not_lift_a_low_reputation() {
+    let (mut svm, contributor, profile) = setup();
+    let authority = svm.wallet();
+    let bounty = open_bounty(&mut svm, authority, "penalties", SOL / 100, 3);
+    for i in 0..3 {
+        let submission = submit(&mut svm, &bounty, contributor, &format!("spam-{i}"));
+        svm.process(ix(
+            bounty.review(&svm, submission, contributor),
+            instruction::RejectSubmission {
+                reason: RejectionReason::Spam,
+                feedback_uri: String::new(),
+            },
+        ))
+        .unwrap();
+    }
+    let penalized: ContributorProfile = svm.account(&profile);
+    assert!(penalized.reputation_score < NEUTRAL_REPUTATION);
+
+    svm.advance(365 * DAY);
+    svm.process(ix(
+        accounts::RefreshReputation {
+            contributor_profile: profile,
+        },
+        instruction::RefreshReputation {},
+    ))
+    .unwrap();
+    let idle: ContributorProfile = svm.account(&profile);
+    assert_eq!(idle.reputation_score, penalized.reputation_score);
+    assert!(idle.reputation_confidence < penalized.reputation_confidence);
+}
+
+#[test]
+fn milestone_badges_follow_approvals() {
+    let (mut svm, contributor, profile) = setup();
+    with_approvals(
+        &mut svm,
+        contributor,
+        ContributorProfile::HIGH_QUALITY_MIN_VIDEOS,
+        ContributorProfile::HIGH_QUALITY_MIN_SCORE,
+    );
+
+    let stored: ContributorProfile = svm.account(&profile);
+    assert!(stored.has_badge(&BadgeType::FirstVideo));
+    assert!(stored.has_badge(&BadgeType::HighQuality));
+    assert!(!stored.has_badge(&BadgeType::HundredVideos));
+}
+
+// ============================================================================
+// BADGE DEFINITIONS
+// ============================================================================
+
+#[test]
+fn the_admin_awards_defined_badges_once() {
+    let (mut svm, _, profile) = setup();
+    let admin = config(&mut svm);
+    let definition = badge_definition(&mut svm, 12, AutoAwardRule::Manual);
+    let award = |admin| {
+        ix(
+            accounts::AwardDefinedBadge {
+                contributor_profile: profile,
+                badge_definition: definition,
+                config: pda::config(),
+                admin,
+            },
+            instruction::AwardDefinedBadge {},
+        )
+    };
+
+    let stranger = svm.wallet();
+    assert_error(svm.process(award(stranger)), AnchorError::ConstraintHasOne);
+
+    svm.process(award(admin)).unwrap();
+    let stored: ContributorProfile = svm.account(&profile);
+    assert!(stored.has_defined_badge(12));
+    assert!(!stored.has_defined_badge(13));
+
+    assert_error(svm.process(award(admin)), ErrorCode::BadgeAlreadyEarned);
+}
+
+#[test]
+fn defined_badges_are_claimable_once_their_rule_is_met() {
+    let (mut svm, contributor, profile) = setup();
+    config(&mut svm);
+    let definition = badge_definition(&mut svm, 3, AutoAwardRule::AcceptedSubmissions { min: 2 });
+    let manual = badge_definition(&mut svm, 4, AutoAwardRule::Manual);
+    let claim = |badge_definition| {
+        ix(
+            accounts::ClaimDefinedBadge {
+                contributor_profile: profile,
+                badge_definition,
+            },
+            instruction::ClaimDefinedBadge {},
+        )
+    };
+
+    with_approvals(&mut svm, contributor, 1, 80);
+    assert_error(
+        svm.process(claim(definition)),
+        ErrorCode::BadgeCriteriaNotMet,
+    );
+
+    with_approvals(&mut svm, contributor, 1, 80);
+    svm.process(claim(definition)).unwrap();
+    let stored: ContributorProfile = svm.account(&profile);
+    assert!(stored.has_defined_badge(3));
+
+    assert_error(
+        svm.process(claim(definition)),
+        ErrorCode::BadgeAlreadyEarned,
+    );
+    // Manual badges can never be claimed
+    assert_error(svm.process(claim(manual)), ErrorCode::BadgeCriteriaNotMet);
+}
+
+// ============================================================================
+// BADGE TOKENS
+// ============================================================================
+
+/// The Token-2022 mint for `badge_type`, created by the config admin; returns the
+/// contributor's token account for it, which their first `mint_badge_token` opens
+fn badge_token_accounts(svm: &mut Svm, contributor: Pubkey, badge_type: &BadgeType) -> Pubkey {
+    let admin = config(svm);
+    svm.process(instructions::initialize_badge_mint(
+        &admin,
+        badge_type.clone(),
+        "https://badges/first-video.json".to_string(),
+    ))
+    .expect("initialize_badge_mint");
+    get_associated_token_address_with_program_id(
+        &contributor,
+        &pda::badge_mint(badge_type.seed()),
+        &token_2022::ID,
+    )
+}
+
+fn mint_badge_token(
+    contributor: Pubkey,
+    token_account: Pubkey,
+    badge_type: BadgeType,
+) -> Instruction {
+    ix(
+        accounts::MintBadgeToken {
+            contributor_profile: pda::profile(&contributor),
+            badge_mint: pda::badge_mint(badge_type.seed()),
+            contributor_token_account: token_account,
+            contributor,
+            payer: contributor,
+            token_program: token_2022::ID,
+            associated_token_program: associated_token::ID,
+            system_program: anchor_lang::system_program::ID,
+        },
+        instruction::MintBadgeToken { badge_type },
+    )
+}
+
+#[test]
+fn badge_tokens_need_an_earned_badge() {
+    let (mut svm, contributor, _) = setup();
+    let token_account = badge_token_accounts(&mut svm, contributor, &BadgeType::FirstVideo);
+
+    assert_error(
+        svm.process(mint_badge_token(
+            contributor,
+            token_account,
+            BadgeType::FirstVideo,
+        )),
+        ErrorCode::BadgeNotEarned,
+    );
+}
+
+#[test]
+fn an_earned_badge_mints_one_token() {
+    let (mut svm, contributor, profile) = setup();
+    with_approvals(&mut svm, contributor, 1, 80);
+    let token_account = badge_token_accounts(&mut svm, contributor, &BadgeType::FirstVideo);
+
+    svm.process(mint_badge_token(
+        contributor,
+        token_account,
+        BadgeType::FirstVideo,
+    ))
+    .expect("mint_badge_token");
+
+    let account = svm.get_account(&token_account).unwrap();
+    let token = StateWithExtensions::<TokenAccount>::unpack(&account.data).unwrap();
+    assert_eq!(token.base.amount, 1);
+    assert_eq!(token.base.owner, contributor);
+    let profile: ContributorProfile = svm.account(&profile);
+    let badge = profile
+        .badges
+        .iter()
+        .find(|badge| badge.badge_type == BadgeType::FirstVideo)
+        .unwrap();
+    assert!(badge.token_minted);
+}
+
+#[test]
+fn badge_tokens_are_minted_once() {
+    let (mut svm, contributor, profile) = setup();
+    let token_account = badge_token_accounts(&mut svm, contributor, &BadgeType::FirstVideo);
+    let now = svm.now();
+    edit(&mut svm, &profile, |profile: &mut ContributorProfile| {
+        profile.badges.push(Badge {
+            badge_type: BadgeType::FirstVideo,
+            earned_at: now,
+            token_minted: true,
+        })
+    });
+
+    assert_error(
+        svm.process(mint_badge_token(
+            contributor,
+            token_account,
+            BadgeType::FirstVideo,
+        )),
+        ErrorCode::BadgeTokenAlreadyMinted,
+    );
+}
+
+#[test]
+fn only_the_admin_creates_badge_mints() {
+    let mut svm = Svm::new();
+    config(&mut svm);
+    let stranger = svm.wallet();
+
+    assert_error(
+        svm.process(ix(
+            accounts::InitializeBadgeMint {
+                badge_mint: pda::badge_mint(BadgeType::FirstVideo.seed()),
+                config: pda::config(),
+                admin: stranger,
+                token_program: token_2022::ID,
+                system_program: anchor_lang::system_program::ID,
+            },
+            instruction::InitializeBadgeMint {
+                badge_type: BadgeType::FirstVideo,
+                uri: "https://badges/first-video.json".to_string(),
+            },
+        )),
+        AnchorError::ConstraintHasOne,
+    );
+}
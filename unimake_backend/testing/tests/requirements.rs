@@ -0,0 +1,152 @@
+//! The requirement and spec checks `create_bounty` and `submit_video` run, exercised
+//! directly on the program's types, so they don't need the SBF build
+
+use anchor_lang::prelude::*;
+use unimake_backend::{
+    CameraPosition, ErrorCode, Orientation, Requirements, SensorStream, VideoSpec,
+};
+use unimake_testing::*;
+
+fn assert_fails(result: Result<()>, code: ErrorCode) {
+    let error = ProgramError::from(result.expect_err("check should fail"));
+    assert_eq!(error, ProgramError::Custom(code.into()));
+}
+
+#[test]
+fn requirements_must_be_coherent() {
+    assert!(requirements().validate().is_ok());
+
+    let invalid = [
+        {
+            let mut requirements = requirements();
+            requirements.max_duration_secs = requirements.min_duration_secs - 1;
+            requirements
+        },
+        {
+            let mut requirements = requirements();
+            requirements.allowed_orientations = vec![Orientation::Landscape; 4];
+            requirements
+        },
+        {
+            let mut requirements = requirements();
+            requirements.required_sensors = vec![SensorStream::Imu; 5];
+            requirements
+        },
+        {
+            let mut requirements = requirements();
+            requirements.category = "k".repeat(33);
+            requirements
+        },
+        {
+            let mut requirements = requirements();
+            requirements.tags = vec!["tag".to_string(); 9];
+            requirements
+        },
+        {
+            let mut requirements = requirements();
+            requirements.tags = vec!["t".repeat(33)];
+            requirements
+        },
+    ];
+    for requirements in invalid {
+        assert_fails(requirements.validate(), ErrorCode::InvalidRequirements);
+    }
+
+    // No upper limit
+    let mut open_ended = requirements();
+    open_ended.max_duration_secs = 0;
+    assert!(open_ended.validate().is_ok());
+}
+
+#[test]
+fn specs_are_checked_against_each_minimum() {
+    let requirements = {
+        let mut requirements = requirements();
+        requirements.allowed_orientations = vec![Orientation::Landscape, Orientation::Portrait];
+        requirements.required_sensors = vec![SensorStream::Imu];
+        requirements.camera_position = CameraPosition::Egocentric;
+        requirements
+    };
+    let valid = VideoSpec {
+        sensors: vec![SensorStream::Imu, SensorStream::Audio],
+        ..spec()
+    };
+    assert!(requirements.check(&valid).is_ok());
+
+    let cases = [
+        (
+            VideoSpec {
+                duration_secs: 29,
+                ..valid.clone()
+            },
+            ErrorCode::DurationTooShort,
+        ),
+        (
+            VideoSpec {
+                duration_secs: 601,
+                ..valid.clone()
+            },
+            ErrorCode::DurationTooLong,
+        ),
+        (
+            VideoSpec {
+                width: 1280,
+                height: 719,
+                ..valid.clone()
+            },
+            ErrorCode::ResolutionTooLow,
+        ),
+        (
+            VideoSpec {
+                fps: 24,
+                ..valid.clone()
+            },
+            ErrorCode::FpsTooLow,
+        ),
+        (
+            VideoSpec {
+                orientation: Orientation::Square,
+                ..valid.clone()
+            },
+            ErrorCode::OrientationNotAllowed,
+        ),
+        (
+            VideoSpec {
+                sensors: vec![SensorStream::Depth],
+                ..valid.clone()
+            },
+            ErrorCode::MissingSensorStream,
+        ),
+        (
+            VideoSpec {
+                camera_position: CameraPosition::ThirdPerson,
+                ..valid.clone()
+            },
+            ErrorCode::CameraPositionMismatch,
+        ),
+    ];
+    for (spec, code) in cases {
+        assert_fails(requirements.check(&spec), code);
+    }
+}
+
+#[test]
+fn portrait_videos_meet_landscape_minimums() {
+    let portrait = VideoSpec {
+        width: 720,
+        height: 1280,
+        orientation: Orientation::Portrait,
+        ..spec()
+    };
+    assert!(requirements().check(&portrait).is_ok());
+}
+
+#[test]
+fn specs_are_checked_by_the_bounty_requirements_version() {
+    let mut unknown = requirements();
+    unknown.version = Requirements::VERSION + 1;
+    assert_fails(
+        unknown.check(&spec()),
+        ErrorCode::UnsupportedRequirementsVersion,
+    );
+}
@@ -0,0 +1,228 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN } from "@coral-xyz/anchor";
+import { UnimakeBackend } from "../target/types/unimake_backend";
+import { BountyTreasury } from "../target/types/bounty_treasury";
+import { PublicKey, Keypair, LAMPORTS_PER_SOL } from "@solana/web3.js";
+import { assert } from "chai";
+import { createHash } from "crypto";
+
+// Helper to convert string to 16-byte array for IDs
+function stringToBytes16(str: string): number[] {
+  const bytes = new TextEncoder().encode(str);
+  const result = new Array(16).fill(0);
+  for (let i = 0; i < Math.min(bytes.length, 16); i++) {
+    result[i] = bytes[i];
+  }
+  return result;
+}
+
+// A bounty driven entirely by another program: the treasury PDA is the bounty
+// authority and signs every authority action through CPI
+describe("Program-controlled bounties", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.UnimakeBackend as Program<UnimakeBackend>;
+  const treasuryProgram = anchor.workspace
+    .BountyTreasury as Program<BountyTreasury>;
+
+  const admin = provider.wallet as anchor.Wallet;
+  const contributor = Keypair.generate();
+  const bountyId = stringToBytes16("bounty-treasury");
+
+  const [treasuryPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("treasury"), admin.publicKey.toBuffer()],
+    treasuryProgram.programId
+  );
+  const [bountyPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("bounty"), Buffer.from(bountyId)],
+    program.programId
+  );
+  const [buyerProfilePda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("buyer"), treasuryPda.toBuffer()],
+    program.programId
+  );
+  const [submissionPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("submission"), bountyPda.toBuffer(), Buffer.alloc(4)],
+    program.programId
+  );
+  const [categoryReputationPda] = PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("category_rep"),
+      contributor.publicKey.toBuffer(),
+      Buffer.from("kitchen"),
+    ],
+    program.programId
+  );
+  const [contributorProfilePda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("profile"), contributor.publicKey.toBuffer()],
+    program.programId
+  );
+  const [contentRecordPda] = PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("content"),
+      createHash("sha256").update("submission-treasury").digest(),
+    ],
+    program.programId
+  );
+
+  before(async () => {
+    const airdropSig = await provider.connection.requestAirdrop(
+      contributor.publicKey,
+      LAMPORTS_PER_SOL
+    );
+    await provider.connection.confirmTransaction(airdropSig);
+
+    await treasuryProgram.methods
+      .initializeTreasury()
+      .accountsPartial({
+        treasury: treasuryPda,
+        admin: admin.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .rpc();
+  });
+
+  it("Creates a bounty whose authority is the treasury PDA", async () => {
+    await treasuryProgram.methods
+      .createBounty(
+        bountyId,
+        new BN(0.1 * LAMPORTS_PER_SOL),
+        new BN(0.3 * LAMPORTS_PER_SOL),
+        3,
+        "Record a video of chopping vegetables",
+        {
+          version: 0,
+          minDurationSecs: 30,
+          maxDurationSecs: 600,
+          minWidth: 1280,
+          minHeight: 720,
+          minFps: 30,
+          allowedOrientations: [],
+          requiredSensors: [],
+          cameraPosition: { any: {} },
+          category: "kitchen",
+          tags: ["knife"],
+        },
+        new BN(Date.now() / 1000 + 86400)
+      )
+      .accountsPartial({
+        treasury: treasuryPda,
+        admin: admin.publicKey,
+        bountyPool: bountyPda,
+        buyerProfile: buyerProfilePda,
+        unimakeProgram: program.programId,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .rpc();
+
+    const bountyAccount = await program.account.bountyPool.fetch(bountyPda);
+    assert.equal(bountyAccount.authority.toString(), treasuryPda.toString());
+    assert.equal(bountyAccount.totalPool.toNumber(), 0.3 * LAMPORTS_PER_SOL);
+  });
+
+  it("Reviews a submission through the treasury", async () => {
+    await program.methods
+      .submitVideo(
+        stringToBytes16("submission-treasury"),
+        Array.from(createHash("sha256").update("submission-treasury").digest()),
+        "QmTreasury",
+        "ArweaveTreasury",
+        "https://metadata/treasury",
+        {
+          durationSecs: 60,
+          width: 1920,
+          height: 1080,
+          fps: 30,
+          orientation: { landscape: {} },
+          sensors: [],
+          cameraPosition: { egocentric: {} },
+        }
+      )
+      .accountsPartial({
+        submission: submissionPda,
+        bountyPool: bountyPda,
+        contributor: contributor.publicKey,
+        categoryReputation: categoryReputationPda,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .signers([contributor])
+      .rpc();
+
+    const balanceBefore = await provider.connection.getBalance(
+      contributor.publicKey
+    );
+    await treasuryProgram.methods
+      .approveSubmission(80)
+      .accountsPartial({
+        treasury: treasuryPda,
+        admin: admin.publicKey,
+        submission: submissionPda,
+        contentRecord: contentRecordPda,
+        bountyPool: bountyPda,
+        contributorProfile: contributorProfilePda,
+        categoryReputation: categoryReputationPda,
+        buyerProfile: buyerProfilePda,
+        contributor: contributor.publicKey,
+        unimakeProgram: program.programId,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .rpc();
+    const balanceAfter = await provider.connection.getBalance(
+      contributor.publicKey
+    );
+
+    assert.equal(balanceAfter - balanceBefore, 0.1 * LAMPORTS_PER_SOL);
+    const submission = await program.account.videoSubmission.fetch(
+      submissionPda
+    );
+    assert.deepEqual(submission.status, { approved: {} });
+  });
+
+  it("Rejects authority actions not signed by the treasury", async () => {
+    try {
+      await program.methods
+        .pauseBounty()
+        .accountsPartial({
+          bountyPool: bountyPda,
+          authority: admin.publicKey,
+        })
+        .rpc();
+
+      assert.fail("Should have thrown error");
+    } catch (err) {
+      assert.include(err.toString(), "ConstraintHasOne");
+    }
+  });
+
+  it("Cancels the bounty and refunds the treasury", async () => {
+    const treasuryBefore = await provider.connection.getBalance(treasuryPda);
+    await treasuryProgram.methods
+      .cancelBounty()
+      .accountsPartial({
+        treasury: treasuryPda,
+        admin: admin.publicKey,
+        bountyPool: bountyPda,
+        unimakeProgram: program.programId,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .rpc();
+    const treasuryAfter = await provider.connection.getBalance(treasuryPda);
+
+    const bountyAccount = await program.account.bountyPool.fetch(bountyPda);
+    assert.deepEqual(bountyAccount.status, { cancelled: {} });
+    assert.equal(treasuryAfter - treasuryBefore, 0.2 * LAMPORTS_PER_SOL);
+
+    await treasuryProgram.methods
+      .withdraw(new BN(0.2 * LAMPORTS_PER_SOL))
+      .accountsPartial({
+        treasury: treasuryPda,
+        admin: admin.publicKey,
+      })
+      .rpc();
+    assert.equal(
+      await provider.connection.getBalance(treasuryPda),
+      treasuryBefore
+    );
+  });
+});
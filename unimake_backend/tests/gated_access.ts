@@ -0,0 +1,294 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN } from "@coral-xyz/anchor";
+import { UnimakeBackend } from "../target/types/unimake_backend";
+import { GatedAccess } from "../target/types/gated_access";
+import { PublicKey, Keypair, LAMPORTS_PER_SOL } from "@solana/web3.js";
+import { assert } from "chai";
+
+// Helper to convert string to 16-byte array for IDs
+function stringToBytes16(str: string): number[] {
+  const bytes = new TextEncoder().encode(str);
+  const result = new Array(16).fill(0);
+  for (let i = 0; i < Math.min(bytes.length, 16); i++) {
+    result[i] = bytes[i];
+  }
+  return result;
+}
+
+// Another program gating its own actions on TerraTrain state through the
+// `interface` helpers and a `refresh_reputation` CPI
+describe("Composing programs", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.UnimakeBackend as Program<UnimakeBackend>;
+  const gateProgram = anchor.workspace.GatedAccess as Program<GatedAccess>;
+
+  const admin = provider.wallet as anchor.Wallet;
+  const licensee = Keypair.generate();
+  const newcomer = Keypair.generate();
+
+  const datasetId = stringToBytes16("dataset-gated");
+  const sourceBountyId = stringToBytes16("bounty-gated-src");
+  const [sourceBountyPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("bounty"), Buffer.from(sourceBountyId)],
+    program.programId
+  );
+  const [datasetPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("dataset"), Buffer.from(datasetId)],
+    program.programId
+  );
+
+  function findGatePda(gateId: number[]): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("gate"), Buffer.from(gateId)],
+      gateProgram.programId
+    )[0];
+  }
+
+  function findPassPda(gate: PublicKey, member: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("pass"), gate.toBuffer(), member.toBuffer()],
+      gateProgram.programId
+    )[0];
+  }
+
+  function findLicensePda(member: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("license"), datasetPda.toBuffer(), member.toBuffer()],
+      program.programId
+    )[0];
+  }
+
+  function findProfilePda(member: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("profile"), member.toBuffer()],
+      program.programId
+    )[0];
+  }
+
+  before(async () => {
+    for (const wallet of [licensee, newcomer]) {
+      const airdropSig = await provider.connection.requestAirdrop(
+        wallet.publicKey,
+        LAMPORTS_PER_SOL
+      );
+      await provider.connection.confirmTransaction(airdropSig);
+    }
+
+    // The dataset's source: a completed bounty without sponsors
+    await program.methods
+      .createBounty(
+        sourceBountyId,
+        new BN(0.01 * LAMPORTS_PER_SOL),
+        new BN(0.01 * LAMPORTS_PER_SOL),
+        1,
+        "Record a video of folding towels",
+        {
+          version: 0,
+          minDurationSecs: 30,
+          maxDurationSecs: 600,
+          minWidth: 1280,
+          minHeight: 720,
+          minFps: 30,
+          allowedOrientations: [],
+          requiredSensors: [],
+          cameraPosition: { any: {} },
+          category: "laundry",
+          tags: [],
+        },
+        new BN(Date.now() / 1000 + 86400)
+      )
+      .accountsPartial({
+        bountyPool: sourceBountyPda,
+        authority: admin.publicKey,
+        payer: admin.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .rpc();
+    await program.methods
+      .completeBounty()
+      .accountsPartial({
+        bountyPool: sourceBountyPda,
+        authority: admin.publicKey,
+      })
+      .rpc();
+
+    await program.methods
+      .createDataset(
+        datasetId,
+        { unlimited: {} },
+        new BN(0.05 * LAMPORTS_PER_SOL),
+        5
+      )
+      .accountsPartial({
+        datasetNft: datasetPda,
+        bountyPool: sourceBountyPda,
+        creator: admin.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .rpc();
+
+    await program.methods
+      .purchaseDataset()
+      .accountsPartial({
+        datasetNft: datasetPda,
+        buyer: licensee.publicKey,
+        creator: admin.publicKey,
+        license: findLicensePda(licensee.publicKey),
+        bountyPool: sourceBountyPda,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .signers([licensee])
+      .rpc();
+
+    await program.methods
+      .initializeProfile()
+      .accountsPartial({
+        contributorProfile: findProfilePda(newcomer.publicKey),
+        contributor: newcomer.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .signers([newcomer])
+      .rpc();
+  });
+
+  describe("License gate", () => {
+    const gateId = stringToBytes16("gate-license");
+    const gatePda = findGatePda(gateId);
+
+    before(async () => {
+      await gateProgram.methods
+        .createGate(gateId, 1000, datasetPda)
+        .accountsPartial({
+          gate: gatePda,
+          admin: admin.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .rpc();
+    });
+
+    it("Admits a license holder", async () => {
+      await gateProgram.methods
+        .enterWithLicense()
+        .accountsPartial({
+          gate: gatePda,
+          pass: findPassPda(gatePda, licensee.publicKey),
+          license: findLicensePda(licensee.publicKey),
+          member: licensee.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([licensee])
+        .rpc();
+
+      const pass = await gateProgram.account.pass.fetch(
+        findPassPda(gatePda, licensee.publicKey)
+      );
+      assert.deepEqual(pass.route, { license: {} });
+    });
+
+    it("Turns away a wallet without a license", async () => {
+      try {
+        await gateProgram.methods
+          .enterWithLicense()
+          .accountsPartial({
+            gate: gatePda,
+            pass: findPassPda(gatePda, newcomer.publicKey),
+            license: findLicensePda(newcomer.publicKey),
+            member: newcomer.publicKey,
+            systemProgram: anchor.web3.SystemProgram.programId,
+          })
+          .signers([newcomer])
+          .rpc();
+
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.include(err.toString(), "NoLicense");
+      }
+    });
+
+    it("Rejects another wallet's license", async () => {
+      try {
+        await gateProgram.methods
+          .enterWithLicense()
+          .accountsPartial({
+            gate: gatePda,
+            pass: findPassPda(gatePda, newcomer.publicKey),
+            license: findLicensePda(licensee.publicKey),
+            member: newcomer.publicKey,
+            systemProgram: anchor.web3.SystemProgram.programId,
+          })
+          .signers([newcomer])
+          .rpc();
+
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.include(err.toString(), "ConstraintSeeds");
+      }
+    });
+  });
+
+  describe("Reputation gate", () => {
+    it("Admits contributors above the threshold", async () => {
+      // A fresh profile sits at the neutral score, so an open gate admits it
+      const gateId = stringToBytes16("gate-open");
+      const gatePda = findGatePda(gateId);
+      await gateProgram.methods
+        .createGate(gateId, 0, null)
+        .accountsPartial({
+          gate: gatePda,
+          admin: admin.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .rpc();
+
+      await gateProgram.methods
+        .enterWithReputation()
+        .accountsPartial({
+          gate: gatePda,
+          pass: findPassPda(gatePda, newcomer.publicKey),
+          contributorProfile: findProfilePda(newcomer.publicKey),
+          member: newcomer.publicKey,
+          unimakeProgram: program.programId,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([newcomer])
+        .rpc();
+
+      const gate = await gateProgram.account.gate.fetch(gatePda);
+      assert.equal(gate.members, 1);
+    });
+
+    it("Turns away contributors below the threshold", async () => {
+      const gateId = stringToBytes16("gate-elite");
+      const gatePda = findGatePda(gateId);
+      await gateProgram.methods
+        .createGate(gateId, 1000, null)
+        .accountsPartial({
+          gate: gatePda,
+          admin: admin.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .rpc();
+
+      try {
+        await gateProgram.methods
+          .enterWithReputation()
+          .accountsPartial({
+            gate: gatePda,
+            pass: findPassPda(gatePda, newcomer.publicKey),
+            contributorProfile: findProfilePda(newcomer.publicKey),
+            member: newcomer.publicKey,
+            unimakeProgram: program.programId,
+            systemProgram: anchor.web3.SystemProgram.programId,
+          })
+          .signers([newcomer])
+          .rpc();
+
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.include(err.toString(), "ReputationTooLow");
+      }
+    });
+  });
+});
@@ -3,6 +3,7 @@ import { Program, BN } from "@coral-xyz/anchor";
 import { UnimakeBackend } from "../target/types/unimake_backend";
 import { PublicKey, Keypair, LAMPORTS_PER_SOL } from "@solana/web3.js";
 import { assert, expect } from "chai";
+import { createHash } from "crypto";
 
 // Helper to convert string to 16-byte array for IDs
 function stringToBytes16(str: string): number[] {
@@ -15,6 +16,43 @@ function stringToBytes16(str: string): number[] {
   return result;
 }
 
+// Stand-in for the 32-byte hash of a video file's contents
+function contentHash(label: string): number[] {
+  return Array.from(createHash("sha256").update(label).digest());
+}
+
+// Bounty requirements with sensible defaults for tests
+function makeRequirements(overrides: Partial<Record<string, any>> = {}) {
+  return {
+    version: 0, // Set by the program
+    minDurationSecs: 30,
+    maxDurationSecs: 600,
+    minWidth: 1280,
+    minHeight: 720,
+    minFps: 30,
+    allowedOrientations: [],
+    requiredSensors: [],
+    cameraPosition: { any: {} },
+    category: "kitchen",
+    tags: ["coffee"],
+    ...overrides,
+  };
+}
+
+// Self-declared video spec that satisfies makeRequirements()
+function makeVideoSpec(overrides: Partial<Record<string, any>> = {}) {
+  return {
+    durationSecs: 60,
+    width: 1920,
+    height: 1080,
+    fps: 30,
+    orientation: { landscape: {} },
+    sensors: [],
+    cameraPosition: { egocentric: {} },
+    ...overrides,
+  };
+}
+
 describe("TerraTrain Smart Contracts", () => {
   // Configure the client to use the local cluster
   const provider = anchor.AnchorProvider.env();
@@ -22,6 +60,50 @@ describe("TerraTrain Smart Contracts", () => {
 
   const program = anchor.workspace.UnimakeBackend as Program<UnimakeBackend>;
 
+  // Submissions are seeded by their bounty and a per-bounty sequence number
+  function findSubmissionPda(bounty: PublicKey, sequence: number): PublicKey {
+    const seq = Buffer.alloc(4);
+    seq.writeUInt32LE(sequence);
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("submission"), bounty.toBuffer(), seq],
+      program.programId
+    )[0];
+  }
+
+  // Per-category reputation, keyed by the bounty's requirements.category
+  function findCategoryReputationPda(
+    wallet: PublicKey,
+    category: string = "kitchen"
+  ): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("category_rep"), wallet.toBuffer(), Buffer.from(category)],
+      program.programId
+    )[0];
+  }
+
+  function findBuyerProfilePda(wallet: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("buyer"), wallet.toBuffer()],
+      program.programId
+    )[0];
+  }
+
+  async function nextSubmissionPda(bounty: PublicKey): Promise<PublicKey> {
+    const bountyAccount = await program.account.bountyPool.fetch(bounty);
+    return findSubmissionPda(bounty, bountyAccount.submissionCount);
+  }
+
+  // The program-wide content record, keyed by the submission's content hash
+  async function findContentRecordPda(submission: PublicKey): Promise<PublicKey> {
+    const submissionAccount = await program.account.videoSubmission.fetch(
+      submission
+    );
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("content"), Buffer.from(submissionAccount.contentHash)],
+      program.programId
+    )[0];
+  }
+
   // Test accounts
   const authority = provider.wallet as anchor.Wallet;
   const contributor = Keypair.generate();
@@ -58,10 +140,8 @@ describe("TerraTrain Smart Contracts", () => {
       program.programId
     );
 
-    [submissionPda] = PublicKey.findProgramAddressSync(
-      [Buffer.from("submission"), Buffer.from(stringToBytes16(submissionId))],
-      program.programId
-    );
+    // First submission to the bounty
+    submissionPda = findSubmissionPda(bountyPda, 0);
 
     [contributorProfilePda] = PublicKey.findProgramAddressSync(
       [Buffer.from("profile"), contributor.publicKey.toBuffer()],
@@ -92,14 +172,13 @@ describe("TerraTrain Smart Contracts", () => {
           totalPool,
           videosTarget,
           "Record a video of making coffee",
-          30, // min_duration_secs
-          "720p", // min_resolution
-          30, // min_fps
+          makeRequirements(),
           expiresAt
         )
         .accountsPartial({
           bountyPool: bountyPda,
           authority: authority.publicKey,
+          payer: authority.publicKey,
           systemProgram: anchor.web3.SystemProgram.programId,
         })
         .rpc();
@@ -130,9 +209,19 @@ describe("TerraTrain Smart Contracts", () => {
       assert.equal(bountyAccount.videosTarget, videosTarget);
       assert.equal(bountyAccount.videosCollected, 0);
       assert.deepEqual(bountyAccount.status, { active: {} });
+      assert.equal(bountyAccount.requirements.version, 1);
       assert.equal(bountyAccount.requirements.minDurationSecs, 30);
-      assert.equal(bountyAccount.requirements.minResolution, "720p");
+      assert.equal(bountyAccount.requirements.maxDurationSecs, 600);
+      assert.equal(bountyAccount.requirements.minWidth, 1280);
+      assert.equal(bountyAccount.requirements.minHeight, 720);
       assert.equal(bountyAccount.requirements.minFps, 30);
+      assert.equal(bountyAccount.requirements.category, "kitchen");
+
+      const buyerProfile = await program.account.buyerProfile.fetch(
+        findBuyerProfilePda(authority.publicKey)
+      );
+      assert.equal(buyerProfile.bountiesCreated, 1);
+      assert.deepEqual(bountyAccount.requirements.tags, ["coffee"]);
     });
 
     it("Pauses an active bounty", async () => {
@@ -176,14 +265,13 @@ describe("TerraTrain Smart Contracts", () => {
             new BN(0.5 * LAMPORTS_PER_SOL), // total pool (insufficient for 10 videos)
             10, // videos target
             "Test bounty",
-            30,
-            "720p",
-            30,
+            makeRequirements(),
             new BN(Date.now() / 1000 + 86400)
           )
           .accountsPartial({
             bountyPool: insufficientBountyPda,
             authority: authority.publicKey,
+            payer: authority.publicKey,
             systemProgram: anchor.web3.SystemProgram.programId,
           })
           .rpc();
@@ -222,40 +310,10 @@ describe("TerraTrain Smart Contracts", () => {
       assert.equal(profile.averageQualityScore, 0);
       assert.equal(profile.totalEarnings.toString(), "0");
       assert.equal(profile.reputationScore, 500); // Neutral start
+      assert.equal(profile.reputationConfidence, 0);
+      assert.equal(profile.reputationVersion, 4);
       assert.equal(profile.badges.length, 0);
     });
-
-    it("Awards a badge to a contributor", async () => {
-      await program.methods
-        .awardBadge({ firstVideo: {} })
-        .accountsPartial({
-          contributorProfile: contributorProfilePda,
-          authority: authority.publicKey,
-        })
-        .rpc();
-
-      const profile = await program.account.contributorProfile.fetch(
-        contributorProfilePda
-      );
-      assert.equal(profile.badges.length, 1);
-      assert.deepEqual(profile.badges[0].badgeType, { firstVideo: {} });
-    });
-
-    it("Prevents duplicate badge awards", async () => {
-      try {
-        await program.methods
-          .awardBadge({ firstVideo: {} })
-          .accountsPartial({
-            contributorProfile: contributorProfilePda,
-            authority: authority.publicKey,
-          })
-          .rpc();
-
-        assert.fail("Should have thrown error");
-      } catch (err) {
-        assert.include(err.toString(), "BadgeAlreadyEarned");
-      }
-    });
   });
 
   // ============================================================================
@@ -269,11 +327,19 @@ describe("TerraTrain Smart Contracts", () => {
       const metadataUri = "https://arweave.net/metadata";
 
       await program.methods
-        .submitVideo(stringToBytes16(submissionId), ipfsHash, arweaveTx, metadataUri)
+        .submitVideo(
+          stringToBytes16(submissionId),
+          contentHash(submissionId),
+          ipfsHash,
+          arweaveTx,
+          metadataUri,
+          makeVideoSpec()
+        )
         .accountsPartial({
           submission: submissionPda,
           bountyPool: bountyPda,
           contributor: contributor.publicKey,
+          categoryReputation: findCategoryReputationPda(contributor.publicKey),
           systemProgram: anchor.web3.SystemProgram.programId,
         })
         .signers([contributor])
@@ -298,10 +364,14 @@ describe("TerraTrain Smart Contracts", () => {
       assert.equal(submission.ipfsHash, ipfsHash);
       assert.equal(submission.arweaveTx, arweaveTx);
       assert.equal(submission.metadataUri, metadataUri);
+      assert.equal(submission.sequence, 0);
+      assert.equal(submission.spec.width, 1920);
+      assert.equal(submission.spec.height, 1080);
       assert.deepEqual(submission.status, { pending: {} });
 
       // Verify escrow was deducted from bounty pool
       const bountyAccount = await program.account.bountyPool.fetch(bountyPda);
+      assert.equal(bountyAccount.submissionCount, 1);
       assert.equal(
         bountyAccount.remainingPool.toString(),
         new BN(0.9 * LAMPORTS_PER_SOL).toString()
@@ -318,9 +388,12 @@ describe("TerraTrain Smart Contracts", () => {
         .approveSubmission(qualityScore)
         .accountsPartial({
           submission: submissionPda,
+          contentRecord: await findContentRecordPda(submissionPda),
           bountyPool: bountyPda,
           contributorProfile: contributorProfilePda,
           contributor: contributor.publicKey,
+          categoryReputation: findCategoryReputationPda(contributor.publicKey),
+          buyerProfile: findBuyerProfilePda(authority.publicKey),
           authority: authority.publicKey,
           systemProgram: anchor.web3.SystemProgram.programId,
         })
@@ -349,9 +422,30 @@ describe("TerraTrain Smart Contracts", () => {
       );
       assert.equal(profile.totalSubmissions, 1);
       assert.equal(profile.acceptedSubmissions, 1);
+      assert.equal(profile.pendingSubmissions, 0);
       assert.equal(profile.averageQualityScore, qualityScore);
       assert.isTrue(profile.totalEarnings.gt(new BN(0)));
       assert.isTrue(profile.reputationScore > 500); // Should increase from neutral
+      assert.isTrue(profile.lastActive.gte(profile.joinDate));
+
+      // Category reputation tracks the bounty's category separately
+      const categoryRep = await program.account.categoryReputation.fetch(
+        findCategoryReputationPda(contributor.publicKey)
+      );
+      assert.equal(categoryRep.category, "kitchen");
+      assert.equal(categoryRep.acceptedSubmissions, 1);
+      assert.equal(categoryRep.averageQualityScore, qualityScore);
+      assert.equal(categoryRep.reputationScore, profile.reputationScore);
+
+      // The bounty creator's record reflects the payout
+      const buyerProfile = await program.account.buyerProfile.fetch(
+        findBuyerProfilePda(authority.publicKey)
+      );
+      assert.equal(buyerProfile.approvedSubmissions, 1);
+      assert.equal(
+        buyerProfile.totalPaidOut.toString(),
+        new BN(0.1 * LAMPORTS_PER_SOL).toString()
+      );
     });
 
     it("Fails to submit video to inactive bounty", async () => {
@@ -365,18 +459,23 @@ describe("TerraTrain Smart Contracts", () => {
         .rpc();
 
       const failedSubmissionId = "submission-fail-001";
-      const [failedSubmissionPda] = PublicKey.findProgramAddressSync(
-        [Buffer.from("submission"), Buffer.from(stringToBytes16(failedSubmissionId))],
-        program.programId
-      );
+      const failedSubmissionPda = await nextSubmissionPda(bountyPda);
 
       try {
         await program.methods
-          .submitVideo(stringToBytes16(failedSubmissionId), "hash", "tx", "uri")
+          .submitVideo(
+            stringToBytes16(failedSubmissionId),
+            contentHash(failedSubmissionId),
+            "hash",
+            "tx",
+            "uri",
+            makeVideoSpec()
+          )
           .accountsPartial({
             submission: failedSubmissionPda,
             bountyPool: bountyPda,
             contributor: contributor.publicKey,
+            categoryReputation: findCategoryReputationPda(contributor.publicKey),
             systemProgram: anchor.web3.SystemProgram.programId,
           })
           .signers([contributor])
@@ -396,6 +495,109 @@ describe("TerraTrain Smart Contracts", () => {
         })
         .rpc();
     });
+
+    it("Fails to submit video below the bounty's minimum resolution", async () => {
+      const lowResSubmissionId = "submission-lowres";
+      const lowResSubmissionPda = await nextSubmissionPda(bountyPda);
+
+      try {
+        await program.methods
+          .submitVideo(
+            stringToBytes16(lowResSubmissionId),
+            contentHash(lowResSubmissionId),
+            "hash",
+            "tx",
+            "uri",
+            makeVideoSpec({ width: 640, height: 480 })
+          )
+          .accountsPartial({
+            submission: lowResSubmissionPda,
+            bountyPool: bountyPda,
+            contributor: contributor.publicKey,
+            categoryReputation: findCategoryReputationPda(contributor.publicKey),
+            systemProgram: anchor.web3.SystemProgram.programId,
+          })
+          .signers([contributor])
+          .rpc();
+
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.include(err.toString(), "ResolutionTooLow");
+      }
+    });
+
+    it("Rejects resubmitting the same content under a new ID", async () => {
+      const replaySubmissionId = "submission-replay";
+      const replaySubmissionPda = await nextSubmissionPda(bountyPda);
+
+      try {
+        await program.methods
+          .submitVideo(
+            stringToBytes16(replaySubmissionId),
+            contentHash(submissionId), // Same content as the approved submission
+            "QmTest123456789",
+            "ArweaveTest123",
+            "https://arweave.net/metadata",
+            makeVideoSpec()
+          )
+          .accountsPartial({
+            submission: replaySubmissionPda,
+            bountyPool: bountyPda,
+            contributor: contributor.publicKey,
+            categoryReputation: findCategoryReputationPda(contributor.publicKey),
+            systemProgram: anchor.web3.SystemProgram.programId,
+          })
+          .signers([contributor])
+          .rpc();
+
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.include(err.toString(), "DuplicateContent");
+      }
+    });
+
+    it("Gates submissions on category reputation", async () => {
+      await program.methods
+        .setReputationGate(1000)
+        .accountsPartial({
+          bountyPool: bountyPda,
+          authority: authority.publicKey,
+        })
+        .rpc();
+
+      try {
+        await program.methods
+          .submitVideo(
+            stringToBytes16("submission-gated"),
+            contentHash("submission-gated"),
+            "QmTest123456789",
+            "ArweaveTest123",
+            "https://arweave.net/metadata",
+            makeVideoSpec()
+          )
+          .accountsPartial({
+            submission: await nextSubmissionPda(bountyPda),
+            bountyPool: bountyPda,
+            contributor: contributor.publicKey,
+            categoryReputation: findCategoryReputationPda(contributor.publicKey),
+            systemProgram: anchor.web3.SystemProgram.programId,
+          })
+          .signers([contributor])
+          .rpc();
+
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.include(err.toString(), "ReputationTooLow");
+      }
+
+      await program.methods
+        .setReputationGate(0)
+        .accountsPartial({
+          bountyPool: bountyPda,
+          authority: authority.publicKey,
+        })
+        .rpc();
+    });
   });
 
   // ============================================================================
@@ -403,6 +605,39 @@ describe("TerraTrain Smart Contracts", () => {
   // ============================================================================
 
   describe("Dataset NFT Program", () => {
+    // Datasets are built from a completed bounty; this one has no sponsors
+    const [sourceBountyPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("bounty"), Buffer.from(stringToBytes16("bounty-dataset-src"))],
+      program.programId
+    );
+
+    before(async () => {
+      await program.methods
+        .createBounty(
+          stringToBytes16("bounty-dataset-src"),
+          new BN(0.1 * LAMPORTS_PER_SOL),
+          new BN(0.1 * LAMPORTS_PER_SOL),
+          1,
+          "Record a video of slicing bread",
+          makeRequirements(),
+          new BN(Date.now() / 1000 + 86400)
+        )
+        .accountsPartial({
+          bountyPool: sourceBountyPda,
+          authority: authority.publicKey,
+          payer: authority.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .rpc();
+      await program.methods
+        .completeBounty()
+        .accountsPartial({
+          bountyPool: sourceBountyPda,
+          authority: authority.publicKey,
+        })
+        .rpc();
+    });
+
     it("Creates a dataset NFT", async () => {
       const price = new BN(5 * LAMPORTS_PER_SOL);
       const royaltyPercentage = 10;
@@ -416,6 +651,7 @@ describe("TerraTrain Smart Contracts", () => {
         )
         .accountsPartial({
           datasetNft: datasetPda,
+          bountyPool: sourceBountyPda,
           creator: authority.publicKey,
           systemProgram: anchor.web3.SystemProgram.programId,
         })
@@ -432,6 +668,8 @@ describe("TerraTrain Smart Contracts", () => {
       assert.equal(dataset.royaltyPercentage, royaltyPercentage);
       assert.deepEqual(dataset.licenseType, { unlimited: {} });
       assert.equal(dataset.totalSales, 0);
+      assert.equal(dataset.bounty.toString(), sourceBountyPda.toString());
+      assert.equal(dataset.sponsorBps, 0);
     });
 
     it("Purchases a dataset NFT", async () => {
@@ -447,6 +685,7 @@ describe("TerraTrain Smart Contracts", () => {
         .purchaseDataset()
         .accountsPartial({
           datasetNft: datasetPda,
+          bountyPool: sourceBountyPda,
           buyer: buyer.publicKey,
           creator: authority.publicKey,
           systemProgram: anchor.web3.SystemProgram.programId,
@@ -468,6 +707,19 @@ describe("TerraTrain Smart Contracts", () => {
       // Verify sales counter updated
       const datasetAfter = await program.account.datasetNft.fetch(datasetPda);
       assert.equal(datasetAfter.totalSales, 1);
+
+      // The purchase is recorded as a license other programs can check
+      const [licensePda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("license"),
+          datasetPda.toBuffer(),
+          buyer.publicKey.toBuffer(),
+        ],
+        program.programId
+      );
+      const license = await program.account.datasetLicense.fetch(licensePda);
+      assert.equal(license.holder.toString(), buyer.publicKey.toString());
+      assert.equal(license.purchases, 1);
     });
 
     it("Fails to create dataset with invalid royalty", async () => {
@@ -487,6 +739,7 @@ describe("TerraTrain Smart Contracts", () => {
           )
           .accountsPartial({
             datasetNft: invalidDatasetPda,
+            bountyPool: sourceBountyPda,
             creator: authority.publicKey,
             systemProgram: anchor.web3.SystemProgram.programId,
           })
@@ -514,10 +767,7 @@ describe("TerraTrain Smart Contracts", () => {
         program.programId
       );
 
-      const [workflowSubmissionPda] = PublicKey.findProgramAddressSync(
-        [Buffer.from("submission"), Buffer.from(stringToBytes16(workflowSubmissionId))],
-        program.programId
-      );
+      const workflowSubmissionPda = findSubmissionPda(workflowBountyPda, 0);
 
       const [workflowDatasetPda] = PublicKey.findProgramAddressSync(
         [Buffer.from("dataset"), Buffer.from(stringToBytes16(workflowDatasetId))],
@@ -532,14 +782,18 @@ describe("TerraTrain Smart Contracts", () => {
           new BN(0.5 * LAMPORTS_PER_SOL),
           10,
           "Workflow test bounty",
-          20,
-          "1080p",
-          60,
+          makeRequirements({
+            minDurationSecs: 20,
+            minWidth: 1920,
+            minHeight: 1080,
+            minFps: 60,
+          }),
           new BN(Date.now() / 1000 + 86400 * 30)
         )
         .accountsPartial({
           bountyPool: workflowBountyPda,
           authority: authority.publicKey,
+          payer: authority.publicKey,
           systemProgram: anchor.web3.SystemProgram.programId,
         })
         .rpc();
@@ -548,14 +802,17 @@ describe("TerraTrain Smart Contracts", () => {
       await program.methods
         .submitVideo(
           stringToBytes16(workflowSubmissionId),
+          contentHash(workflowSubmissionId),
           "QmWorkflow",
           "ArweaveWorkflow",
-          "https://metadata/workflow"
+          "https://metadata/workflow",
+          makeVideoSpec({ fps: 60 })
         )
         .accountsPartial({
           submission: workflowSubmissionPda,
           bountyPool: workflowBountyPda,
           contributor: contributor.publicKey,
+          categoryReputation: findCategoryReputationPda(contributor.publicKey),
           systemProgram: anchor.web3.SystemProgram.programId,
         })
         .signers([contributor])
@@ -566,15 +823,25 @@ describe("TerraTrain Smart Contracts", () => {
         .approveSubmission(90)
         .accountsPartial({
           submission: workflowSubmissionPda,
+          contentRecord: await findContentRecordPda(workflowSubmissionPda),
           bountyPool: workflowBountyPda,
           contributorProfile: contributorProfilePda,
           contributor: contributor.publicKey,
+          categoryReputation: findCategoryReputationPda(contributor.publicKey),
+          buyerProfile: findBuyerProfilePda(authority.publicKey),
           authority: authority.publicKey,
           systemProgram: anchor.web3.SystemProgram.programId,
         })
         .rpc();
 
-      // Step 4: Create dataset from approved submissions
+      // Step 4: Close the bounty and create a dataset from its approved submissions
+      await program.methods
+        .completeBounty()
+        .accountsPartial({
+          bountyPool: workflowBountyPda,
+          authority: authority.publicKey,
+        })
+        .rpc();
       await program.methods
         .createDataset(
           stringToBytes16(workflowDatasetId),
@@ -584,6 +851,7 @@ describe("TerraTrain Smart Contracts", () => {
         )
         .accountsPartial({
           datasetNft: workflowDatasetPda,
+          bountyPool: workflowBountyPda,
           creator: authority.publicKey,
           systemProgram: anchor.web3.SystemProgram.programId,
         })
@@ -615,23 +883,23 @@ describe("TerraTrain Smart Contracts", () => {
 
     it("Handles rejection workflow correctly", async () => {
       const rejectSubmissionId = "submission-reject";
-      const [rejectSubmissionPda] = PublicKey.findProgramAddressSync(
-        [Buffer.from("submission"), Buffer.from(stringToBytes16(rejectSubmissionId))],
-        program.programId
-      );
+      const rejectSubmissionPda = await nextSubmissionPda(bountyPda);
 
       // Submit video
       await program.methods
         .submitVideo(
           stringToBytes16(rejectSubmissionId),
+          contentHash(rejectSubmissionId),
           "QmReject",
           "ArweaveReject",
-          "https://metadata/reject"
+          "https://metadata/reject",
+          makeVideoSpec()
         )
         .accountsPartial({
           submission: rejectSubmissionPda,
           bountyPool: bountyPda,
           contributor: contributor.publicKey,
+          categoryReputation: findCategoryReputationPda(contributor.publicKey),
           systemProgram: anchor.web3.SystemProgram.programId,
         })
         .signers([contributor])
@@ -642,12 +910,15 @@ describe("TerraTrain Smart Contracts", () => {
 
       // Reject submission
       await program.methods
-        .rejectSubmission()
+        .rejectSubmission({ tooShort: {} }, "ipfs://QmRejectionFeedback")
         .accountsPartial({
           submission: rejectSubmissionPda,
+          contentRecord: await findContentRecordPda(rejectSubmissionPda),
           bountyPool: bountyPda,
           contributorProfile: contributorProfilePda,
           contributor: contributor.publicKey,
+          categoryReputation: findCategoryReputationPda(contributor.publicKey),
+          buyerProfile: findBuyerProfilePda(authority.publicKey),
           authority: authority.publicKey,
           systemProgram: anchor.web3.SystemProgram.programId,
         })
@@ -662,6 +933,1375 @@ describe("TerraTrain Smart Contracts", () => {
         contributorProfilePda
       );
       assert.isTrue(profile.rejectedSubmissions > 0);
+
+      // The contributor can see why they were rejected
+      const rejected = await program.account.videoSubmission.fetch(
+        rejectSubmissionPda
+      );
+      assert.deepEqual(rejected.rejectionReason, { tooShort: {} });
+      assert.equal(rejected.feedbackUri, "ipfs://QmRejectionFeedback");
+    });
+
+    it("Awards FirstVideo automatically on first approval", async () => {
+      const newcomer = Keypair.generate();
+      const airdropSig = await provider.connection.requestAirdrop(
+        newcomer.publicKey,
+        LAMPORTS_PER_SOL
+      );
+      await provider.connection.confirmTransaction(airdropSig);
+
+      const [newcomerProfilePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("profile"), newcomer.publicKey.toBuffer()],
+        program.programId
+      );
+
+      // No initialize_profile call: the first submission creates the profile
+      const newcomerSubmissionPda = await nextSubmissionPda(bountyPda);
+      await program.methods
+        .submitVideo(
+          stringToBytes16("submission-newcomer"),
+          contentHash("submission-newcomer"),
+          "QmNewcomer",
+          "ArweaveNewcomer",
+          "https://metadata/newcomer",
+          makeVideoSpec()
+        )
+        .accountsPartial({
+          submission: newcomerSubmissionPda,
+          bountyPool: bountyPda,
+          contributor: newcomer.publicKey,
+          categoryReputation: findCategoryReputationPda(newcomer.publicKey),
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([newcomer])
+        .rpc();
+
+      const pendingProfile = await program.account.contributorProfile.fetch(
+        newcomerProfilePda
+      );
+      assert.equal(pendingProfile.reputationScore, 500);
+      assert.equal(pendingProfile.totalSubmissions, 1);
+      assert.equal(pendingProfile.pendingSubmissions, 1);
+
+      await program.methods
+        .approveSubmission(75)
+        .accountsPartial({
+          submission: newcomerSubmissionPda,
+          contentRecord: await findContentRecordPda(newcomerSubmissionPda),
+          bountyPool: bountyPda,
+          contributorProfile: newcomerProfilePda,
+          contributor: newcomer.publicKey,
+          categoryReputation: findCategoryReputationPda(newcomer.publicKey),
+          buyerProfile: findBuyerProfilePda(authority.publicKey),
+          authority: authority.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .rpc();
+
+      const profile = await program.account.contributorProfile.fetch(
+        newcomerProfilePda
+      );
+      assert.equal(profile.badges.length, 1);
+      assert.deepEqual(profile.badges[0].badgeType, { firstVideo: {} });
+    });
+  });
+
+  // ============================================================================
+  // VALIDATOR ORACLE TESTS
+  // ============================================================================
+
+  describe("Validator Oracle", () => {
+    const validator = Keypair.generate();
+    const attestedSubmissionId = "submission-attested";
+    let configPda: PublicKey;
+    let validatorOraclePda: PublicKey;
+    let attestedSubmissionPda: PublicKey;
+
+    before(async () => {
+      const airdropSig = await provider.connection.requestAirdrop(
+        validator.publicKey,
+        2 * LAMPORTS_PER_SOL
+      );
+      await provider.connection.confirmTransaction(airdropSig);
+
+      [configPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("config")],
+        program.programId
+      );
+      [validatorOraclePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("validator"), validator.publicKey.toBuffer()],
+        program.programId
+      );
+      attestedSubmissionPda = await nextSubmissionPda(bountyPda);
+    });
+
+    it("Initializes config and registers a validator", async () => {
+      const [programDataPda] = PublicKey.findProgramAddressSync(
+        [program.programId.toBuffer()],
+        new PublicKey("BPFLoaderUpgradeab1e11111111111111111111111")
+      );
+
+      await program.methods
+        .initializeConfig(authority.publicKey)
+        .accountsPartial({
+          config: configPda,
+          program: program.programId,
+          programData: programDataPda,
+          authority: authority.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .rpc();
+
+      await program.methods
+        .registerValidator(new BN(0.1 * LAMPORTS_PER_SOL))
+        .accountsPartial({
+          validatorOracle: validatorOraclePda,
+          config: configPda,
+          admin: authority.publicKey,
+          validator: validator.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([validator])
+        .rpc();
+
+      const oracle = await program.account.validatorOracle.fetch(validatorOraclePda);
+      assert.equal(oracle.validator.toString(), validator.publicKey.toString());
+      assert.equal(oracle.stake.toString(), new BN(0.1 * LAMPORTS_PER_SOL).toString());
+      assert.isTrue(oracle.active);
+    });
+
+    it("Auto-approves a submission from a passing attestation", async () => {
+      await program.methods
+        .setAttestationPolicy({
+          autoApproveMinScore: 80,
+          autoRejectMaxScore: 30,
+          minValidatorStake: new BN(0.05 * LAMPORTS_PER_SOL),
+        })
+        .accountsPartial({
+          bountyPool: bountyPda,
+          authority: authority.publicKey,
+        })
+        .rpc();
+
+      await program.methods
+        .submitVideo(
+          stringToBytes16(attestedSubmissionId),
+          contentHash(attestedSubmissionId),
+          "QmAttested",
+          "ArweaveAttested",
+          "https://metadata/attested",
+          makeVideoSpec()
+        )
+        .accountsPartial({
+          submission: attestedSubmissionPda,
+          bountyPool: bountyPda,
+          contributor: contributor.publicKey,
+          categoryReputation: findCategoryReputationPda(contributor.publicKey),
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([contributor])
+        .rpc();
+
+      const [attestationPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("attestation"),
+          attestedSubmissionPda.toBuffer(),
+          validator.publicKey.toBuffer(),
+        ],
+        program.programId
+      );
+      const contributorBalanceBefore = await provider.connection.getBalance(
+        contributor.publicKey
+      );
+
+      await program.methods
+        .attestSubmission(92, 0b1111, "qa-v1.2.0")
+        .accountsPartial({
+          attestation: attestationPda,
+          validatorOracle: validatorOraclePda,
+          submission: attestedSubmissionPda,
+          contentRecord: await findContentRecordPda(attestedSubmissionPda),
+          bountyPool: bountyPda,
+          contributorProfile: contributorProfilePda,
+          contributor: contributor.publicKey,
+          categoryReputation: findCategoryReputationPda(contributor.publicKey),
+          buyerProfile: findBuyerProfilePda(authority.publicKey),
+          validator: validator.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([validator])
+        .rpc();
+
+      const attestation = await program.account.qualityAttestation.fetch(attestationPda);
+      assert.equal(attestation.score, 92);
+      assert.equal(attestation.modelVersion, "qa-v1.2.0");
+
+      const submission = await program.account.videoSubmission.fetch(
+        attestedSubmissionPda
+      );
+      assert.deepEqual(submission.status, { approved: {} });
+      assert.equal(submission.qualityScore, 92);
+
+      const contributorBalanceAfter = await provider.connection.getBalance(
+        contributor.publicKey
+      );
+      assert.isTrue(contributorBalanceAfter > contributorBalanceBefore);
+    });
+  });
+
+  // ============================================================================
+  // SOULBOUND BADGE TESTS
+  // ============================================================================
+
+  describe("Soulbound Badges", () => {
+    const TOKEN_2022_PROGRAM_ID = new PublicKey(
+      "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+    );
+    const ASSOCIATED_TOKEN_PROGRAM_ID = new PublicKey(
+      "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+    );
+
+    it("Mints a non-transferable token for an earned badge", async () => {
+      const [configPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("config")],
+        program.programId
+      );
+      const [badgeMintPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("badge_mint"), Buffer.from([0])], // FirstVideo
+        program.programId
+      );
+      const [tokenAccount] = PublicKey.findProgramAddressSync(
+        [
+          contributor.publicKey.toBuffer(),
+          TOKEN_2022_PROGRAM_ID.toBuffer(),
+          badgeMintPda.toBuffer(),
+        ],
+        ASSOCIATED_TOKEN_PROGRAM_ID
+      );
+
+      await program.methods
+        .initializeBadgeMint({ firstVideo: {} }, "https://terratrain.xyz/badges/first-video.json")
+        .accountsPartial({
+          badgeMint: badgeMintPda,
+          config: configPda,
+          admin: authority.publicKey,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .rpc();
+
+      await program.methods
+        .mintBadgeToken({ firstVideo: {} })
+        .accountsPartial({
+          contributorProfile: contributorProfilePda,
+          badgeMint: badgeMintPda,
+          contributorTokenAccount: tokenAccount,
+          contributor: contributor.publicKey,
+          payer: authority.publicKey,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .rpc();
+
+      const balance = await provider.connection.getTokenAccountBalance(tokenAccount);
+      assert.equal(balance.value.amount, "1");
+
+      const profile = await program.account.contributorProfile.fetch(
+        contributorProfilePda
+      );
+      assert.isTrue(profile.badges[0].tokenMinted);
+
+      // A second mint for the same badge is refused
+      try {
+        await program.methods
+          .mintBadgeToken({ firstVideo: {} })
+          .accountsPartial({
+            contributorProfile: contributorProfilePda,
+            badgeMint: badgeMintPda,
+            contributorTokenAccount: tokenAccount,
+            contributor: contributor.publicKey,
+            payer: authority.publicKey,
+            tokenProgram: TOKEN_2022_PROGRAM_ID,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            systemProgram: anchor.web3.SystemProgram.programId,
+          })
+          .rpc();
+
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.include(err.toString(), "BadgeTokenAlreadyMinted");
+      }
+    });
+
+    it("Awards a subjective badge to a contributor", async () => {
+      const [configPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("config")],
+        program.programId
+      );
+      await program.methods
+        .awardBadge({ earlyAdopter: {} })
+        .accountsPartial({
+          contributorProfile: contributorProfilePda,
+          config: configPda,
+          admin: authority.publicKey,
+        })
+        .rpc();
+
+      const profile = await program.account.contributorProfile.fetch(
+        contributorProfilePda
+      );
+      assert.equal(profile.badges.length, 2);
+      assert.deepEqual(profile.badges[1].badgeType, { earlyAdopter: {} });
+
+      // A second award of the same badge is refused
+      try {
+        await program.methods
+          .awardBadge({ earlyAdopter: {} })
+          .accountsPartial({
+            contributorProfile: contributorProfilePda,
+            config: configPda,
+            admin: authority.publicKey,
+          })
+          .rpc();
+
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.include(err.toString(), "BadgeAlreadyEarned");
+      }
+    });
+
+    it("Leaves milestone badges to approvals", async () => {
+      const [configPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("config")],
+        program.programId
+      );
+      try {
+        await program.methods
+          .awardBadge({ thousandVideos: {} })
+          .accountsPartial({
+            contributorProfile: contributorProfilePda,
+            config: configPda,
+            admin: authority.publicKey,
+          })
+          .rpc();
+
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.include(err.toString(), "MilestoneBadge");
+      }
+    });
+  });
+
+  // ============================================================================
+  // BADGE DEFINITION TESTS
+  // ============================================================================
+
+  describe("Badge Definitions", () => {
+    let configPda: PublicKey;
+
+    function badgeDefinitionPda(badgeId: number): PublicKey {
+      return PublicKey.findProgramAddressSync(
+        [Buffer.from("badge_def"), Buffer.from([badgeId])],
+        program.programId
+      )[0];
+    }
+
+    before(() => {
+      [configPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("config")],
+        program.programId
+      );
+    });
+
+    it("Awards a manual category badge", async () => {
+      await program.methods
+        .createBadgeDefinition(
+          0,
+          "Kitchen Expert",
+          "kitchen",
+          "Consistently excellent kitchen task footage",
+          { manual: {} }
+        )
+        .accountsPartial({
+          badgeDefinition: badgeDefinitionPda(0),
+          config: configPda,
+          admin: authority.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .rpc();
+
+      await program.methods
+        .awardDefinedBadge()
+        .accountsPartial({
+          contributorProfile: contributorProfilePda,
+          badgeDefinition: badgeDefinitionPda(0),
+          config: configPda,
+          admin: authority.publicKey,
+        })
+        .rpc();
+
+      const definition = await program.account.badgeDefinition.fetch(
+        badgeDefinitionPda(0)
+      );
+      assert.equal(definition.category, "kitchen");
+
+      const profile = await program.account.contributorProfile.fetch(
+        contributorProfilePda
+      );
+      assert.equal(profile.definedBadges[0] & 0b1, 0b1);
+    });
+
+    it("Lets a contributor claim a badge once its rule is met", async () => {
+      await program.methods
+        .createBadgeDefinition(1, "Two Accepted", null, "Two accepted videos", {
+          acceptedSubmissions: { min: 2 },
+        })
+        .accountsPartial({
+          badgeDefinition: badgeDefinitionPda(1),
+          config: configPda,
+          admin: authority.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .rpc();
+
+      await program.methods
+        .claimDefinedBadge()
+        .accountsPartial({
+          contributorProfile: contributorProfilePda,
+          badgeDefinition: badgeDefinitionPda(1),
+        })
+        .rpc();
+
+      const profile = await program.account.contributorProfile.fetch(
+        contributorProfilePda
+      );
+      assert.equal(profile.definedBadges[0] & 0b10, 0b10);
+    });
+  });
+
+  // ============================================================================
+  // REVIEW PANEL TESTS
+  // ============================================================================
+
+  describe("Review Panel", () => {
+    const reviewerA = Keypair.generate();
+    const reviewerB = Keypair.generate();
+    const panelBountyId = "bounty-panel";
+    let panelBountyPda: PublicKey;
+
+    const submissionPdas = new Map<string, PublicKey>();
+
+    function submissionPdaFor(id: string): PublicKey {
+      return submissionPdas.get(id);
+    }
+
+    function votePdaFor(submission: PublicKey, reviewer: PublicKey): PublicKey {
+      return PublicKey.findProgramAddressSync(
+        [Buffer.from("vote"), submission.toBuffer(), reviewer.toBuffer()],
+        program.programId
+      )[0];
+    }
+
+    async function submit(id: string) {
+      submissionPdas.set(id, await nextSubmissionPda(panelBountyPda));
+      await program.methods
+        .submitVideo(
+          stringToBytes16(id),
+          contentHash(id),
+          "QmPanel",
+          "ArweavePanel",
+          "uri",
+          makeVideoSpec()
+        )
+        .accountsPartial({
+          submission: submissionPdaFor(id),
+          bountyPool: panelBountyPda,
+          contributor: contributor.publicKey,
+          categoryReputation: findCategoryReputationPda(contributor.publicKey),
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([contributor])
+        .rpc();
+    }
+
+    async function vote(id: string, reviewer: Keypair, approve: boolean, score: number) {
+      const submission = submissionPdaFor(id);
+      await program.methods
+        .castReviewVote(approve, score)
+        .accountsPartial({
+          reviewVote: votePdaFor(submission, reviewer.publicKey),
+          submission,
+          contentRecord: await findContentRecordPda(submission),
+          bountyPool: panelBountyPda,
+          contributorProfile: contributorProfilePda,
+          contributor: contributor.publicKey,
+          categoryReputation: findCategoryReputationPda(contributor.publicKey),
+          buyerProfile: findBuyerProfilePda(authority.publicKey),
+          reviewer: reviewer.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([reviewer])
+        .rpc();
+    }
+
+    before(async () => {
+      for (const reviewer of [reviewerA, reviewerB]) {
+        const sig = await provider.connection.requestAirdrop(
+          reviewer.publicKey,
+          LAMPORTS_PER_SOL
+        );
+        await provider.connection.confirmTransaction(sig);
+      }
+
+      [panelBountyPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("bounty"), Buffer.from(stringToBytes16(panelBountyId))],
+        program.programId
+      );
+
+      await program.methods
+        .createBounty(
+          stringToBytes16(panelBountyId),
+          new BN(0.05 * LAMPORTS_PER_SOL),
+          new BN(0.5 * LAMPORTS_PER_SOL),
+          10,
+          "Panel reviewed bounty",
+          makeRequirements(),
+          new BN(Date.now() / 1000 + 86400 * 30)
+        )
+        .accountsPartial({
+          bountyPool: panelBountyPda,
+          authority: authority.publicKey,
+          payer: authority.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .rpc();
+
+      await program.methods
+        .setReviewPanel([reviewerA.publicKey, reviewerB.publicKey], 2)
+        .accountsPartial({
+          bountyPool: panelBountyPda,
+          authority: authority.publicKey,
+        })
+        .rpc();
+    });
+
+    it("Finalizes on quorum with the median quality score", async () => {
+      await submit("submission-panel-ok");
+      await vote("submission-panel-ok", reviewerA, true, 80);
+
+      let submission = await program.account.videoSubmission.fetch(
+        submissionPdaFor("submission-panel-ok")
+      );
+      assert.deepEqual(submission.status, { pending: {} });
+
+      await vote("submission-panel-ok", reviewerB, true, 90);
+
+      submission = await program.account.videoSubmission.fetch(
+        submissionPdaFor("submission-panel-ok")
+      );
+      assert.deepEqual(submission.status, { approved: {} });
+      assert.equal(submission.qualityScore, 85);
+    });
+
+    it("Moves conflicting votes to Disputed for the arbiter", async () => {
+      await submit("submission-panel-split");
+      await vote("submission-panel-split", reviewerA, true, 70);
+      await vote("submission-panel-split", reviewerB, false, 20);
+
+      const submissionPda = submissionPdaFor("submission-panel-split");
+      let submission = await program.account.videoSubmission.fetch(submissionPda);
+      assert.deepEqual(submission.status, { disputed: {} });
+
+      const [configPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("config")],
+        program.programId
+      );
+      await program.methods
+        .resolveDispute(false, 0)
+        .accountsPartial({
+          submission: submissionPda,
+          contentRecord: await findContentRecordPda(submissionPda),
+          bountyPool: panelBountyPda,
+          contributorProfile: contributorProfilePda,
+          contributor: contributor.publicKey,
+          categoryReputation: findCategoryReputationPda(contributor.publicKey),
+          buyerProfile: findBuyerProfilePda(authority.publicKey),
+          config: configPda,
+          admin: authority.publicKey,
+        })
+        .rpc();
+
+      submission = await program.account.videoSubmission.fetch(submissionPda);
+      assert.deepEqual(submission.status, { rejected: {} });
+    });
+
+    it("Rejects a reviewer voting on their own submission", async () => {
+      await program.methods
+        .setReviewPanel([reviewerA.publicKey, contributor.publicKey], 2)
+        .accountsPartial({
+          bountyPool: panelBountyPda,
+          authority: authority.publicKey,
+        })
+        .rpc();
+      await submit("submission-panel-self");
+
+      try {
+        await vote("submission-panel-self", contributor, true, 100);
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.include(err.toString(), "SelfReview");
+      }
+    });
+  });
+
+  // ============================================================================
+  // REWARD TIER TESTS
+  // ============================================================================
+
+  describe("Reward Tiers", () => {
+    it("Pays a capped bonus from the bonus pool for top-tier quality", async () => {
+      await program.methods
+        .setRewardTiers([
+          { minScore: 0, payoutBps: 5_000 },
+          { minScore: 60, payoutBps: 10_000 },
+          { minScore: 91, payoutBps: 20_000 },
+        ])
+        .accountsPartial({
+          bountyPool: bountyPda,
+          authority: authority.publicKey,
+        })
+        .rpc();
+
+      // Half of the 100% bonus is funded, so the bonus is capped
+      const bonusBudget = new BN(0.05 * LAMPORTS_PER_SOL);
+      await program.methods
+        .fundBonusPool(bonusBudget)
+        .accountsPartial({
+          bountyPool: bountyPda,
+          authority: authority.publicKey,
+          payer: authority.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .rpc();
+
+      const tieredSubmissionPda = await nextSubmissionPda(bountyPda);
+      await program.methods
+        .submitVideo(
+          stringToBytes16("submission-tiered"),
+          contentHash("submission-tiered"),
+          "QmTiered",
+          "ArweaveTiered",
+          "https://metadata/tiered",
+          makeVideoSpec()
+        )
+        .accountsPartial({
+          submission: tieredSubmissionPda,
+          bountyPool: bountyPda,
+          contributor: contributor.publicKey,
+          categoryReputation: findCategoryReputationPda(contributor.publicKey),
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([contributor])
+        .rpc();
+
+      const balanceBefore = await provider.connection.getBalance(
+        contributor.publicKey
+      );
+      await program.methods
+        .approveSubmission(95)
+        .accountsPartial({
+          submission: tieredSubmissionPda,
+          contentRecord: await findContentRecordPda(tieredSubmissionPda),
+          bountyPool: bountyPda,
+          contributorProfile: contributorProfilePda,
+          contributor: contributor.publicKey,
+          categoryReputation: findCategoryReputationPda(contributor.publicKey),
+          buyerProfile: findBuyerProfilePda(authority.publicKey),
+          authority: authority.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .rpc();
+      const balanceAfter = await provider.connection.getBalance(
+        contributor.publicKey
+      );
+
+      assert.equal(
+        balanceAfter - balanceBefore,
+        0.1 * LAMPORTS_PER_SOL + bonusBudget.toNumber()
+      );
+      const bountyAccount = await program.account.bountyPool.fetch(bountyPda);
+      assert.equal(bountyAccount.bonusPool.toNumber(), 0);
+
+      await program.methods
+        .setRewardTiers([])
+        .accountsPartial({
+          bountyPool: bountyPda,
+          authority: authority.publicKey,
+        })
+        .rpc();
+    });
+  });
+
+  // ============================================================================
+  // BATCH REVIEW TESTS
+  // ============================================================================
+
+  describe("Batch Review", () => {
+    async function submit(id: string): Promise<PublicKey> {
+      const pda = await nextSubmissionPda(bountyPda);
+      await program.methods
+        .submitVideo(
+          stringToBytes16(id),
+          contentHash(id),
+          "QmBatch",
+          "ArweaveBatch",
+          "https://metadata/batch",
+          makeVideoSpec()
+        )
+        .accountsPartial({
+          submission: pda,
+          bountyPool: bountyPda,
+          contributor: contributor.publicKey,
+          categoryReputation: findCategoryReputationPda(contributor.publicKey),
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([contributor])
+        .rpc();
+      return pda;
+    }
+
+    async function itemAccounts(submission: PublicKey) {
+      return [
+        submission,
+        contributor.publicKey,
+        contributorProfilePda,
+        findCategoryReputationPda(contributor.publicKey),
+        await findContentRecordPda(submission),
+      ].map((pubkey) => ({ pubkey, isWritable: true, isSigner: false }));
+    }
+
+    it("Settles several submissions and skips invalid items", async () => {
+      const approvePda = await submit("submission-batch-approve");
+      const rejectPda = await submit("submission-batch-reject");
+
+      // The third item repeats the first, which is no longer pending by then
+      await program.methods
+        .batchReview(
+          [
+            { approve: { qualityScore: 80 } },
+            { reject: { reason: { wrongTask: {} } } },
+            { approve: { qualityScore: 80 } },
+          ],
+          false
+        )
+        .accountsPartial({
+          bountyPool: bountyPda,
+          buyerProfile: findBuyerProfilePda(authority.publicKey),
+          authority: authority.publicKey,
+        })
+        .remainingAccounts([
+          ...(await itemAccounts(approvePda)),
+          ...(await itemAccounts(rejectPda)),
+          ...(await itemAccounts(approvePda)),
+        ])
+        .rpc();
+
+      const approved = await program.account.videoSubmission.fetch(approvePda);
+      assert.deepEqual(approved.status, { approved: {} });
+      const rejected = await program.account.videoSubmission.fetch(rejectPda);
+      assert.deepEqual(rejected.status, { rejected: {} });
+      assert.deepEqual(rejected.rejectionReason, { wrongTask: {} });
+    });
+
+    it("Fails the whole batch on an invalid item when atomic", async () => {
+      const pendingPda = await submit("submission-batch-atomic");
+      const settledPda = findSubmissionPda(bountyPda, 0); // Approved earlier
+
+      try {
+        await program.methods
+          .batchReview(
+            [
+              { approve: { qualityScore: 70 } },
+              { approve: { qualityScore: 70 } },
+            ],
+            true
+          )
+          .accountsPartial({
+            bountyPool: bountyPda,
+            buyerProfile: findBuyerProfilePda(authority.publicKey),
+            authority: authority.publicKey,
+          })
+          .remainingAccounts([
+            ...(await itemAccounts(pendingPda)),
+            ...(await itemAccounts(settledPda)),
+          ])
+          .rpc();
+
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.include(err.toString(), "InvalidStatus");
+      }
+
+      const pending = await program.account.videoSubmission.fetch(pendingPda);
+      assert.deepEqual(pending.status, { pending: {} });
+    });
+  });
+
+  // ============================================================================
+  // SUBMISSION BOND TESTS
+  // ============================================================================
+
+  describe("Submission Bonds", () => {
+    const bond = new BN(0.01 * LAMPORTS_PER_SOL);
+
+    async function setBond(amount: BN) {
+      await program.methods
+        .setSubmissionBond(amount)
+        .accountsPartial({
+          bountyPool: bountyPda,
+          authority: authority.publicKey,
+        })
+        .rpc();
+    }
+
+    it("Forfeits the bond to the authority when rejected as spam", async () => {
+      await setBond(bond);
+
+      const spamSubmissionPda = await nextSubmissionPda(bountyPda);
+      await program.methods
+        .submitVideo(
+          stringToBytes16("submission-spam"),
+          contentHash("submission-spam"),
+          "QmSpam",
+          "ArweaveSpam",
+          "https://metadata/spam",
+          makeVideoSpec()
+        )
+        .accountsPartial({
+          submission: spamSubmissionPda,
+          bountyPool: bountyPda,
+          contributor: contributor.publicKey,
+          categoryReputation: findCategoryReputationPda(contributor.publicKey),
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([contributor])
+        .rpc();
+
+      let submission = await program.account.videoSubmission.fetch(
+        spamSubmissionPda
+      );
+      assert.equal(submission.bondAmount.toString(), bond.toString());
+
+      const authorityBefore = await provider.connection.getBalance(
+        authority.publicKey
+      );
+      await program.methods
+        .rejectSubmission({ spam: {} }, "")
+        .accountsPartial({
+          submission: spamSubmissionPda,
+          contentRecord: await findContentRecordPda(spamSubmissionPda),
+          bountyPool: bountyPda,
+          contributorProfile: contributorProfilePda,
+          contributor: contributor.publicKey,
+          categoryReputation: findCategoryReputationPda(contributor.publicKey),
+          buyerProfile: findBuyerProfilePda(authority.publicKey),
+          authority: authority.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .rpc();
+      const authorityAfter = await provider.connection.getBalance(
+        authority.publicKey
+      );
+
+      submission = await program.account.videoSubmission.fetch(
+        spamSubmissionPda
+      );
+      assert.equal(submission.bondAmount.toNumber(), 0);
+      // The authority gains the bond less the transaction fee
+      assert.isTrue(authorityAfter - authorityBefore > bond.toNumber() - 10_000);
+
+      await setBond(new BN(0));
+    });
+  });
+
+  // ============================================================================
+  // SUSPENSION TESTS
+  // ============================================================================
+
+  describe("Suspensions", () => {
+    const fraudster = Keypair.generate();
+    let fraudsterProfilePda: PublicKey;
+    let configPda: PublicKey;
+
+    before(async () => {
+      const airdropSig = await provider.connection.requestAirdrop(
+        fraudster.publicKey,
+        LAMPORTS_PER_SOL
+      );
+      await provider.connection.confirmTransaction(airdropSig);
+
+      [fraudsterProfilePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("profile"), fraudster.publicKey.toBuffer()],
+        program.programId
+      );
+      [configPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("config")],
+        program.programId
+      );
+    });
+
+    it("Blocks submissions while a contributor is suspended", async () => {
+      // The wallet has no profile yet; suspending creates one
+      await program.methods
+        .suspendContributor({ syntheticContent: {} }, new BN(86400))
+        .accountsPartial({
+          contributorProfile: fraudsterProfilePda,
+          contributor: fraudster.publicKey,
+          config: configPda,
+          admin: authority.publicKey,
+        })
+        .rpc();
+
+      const profile = await program.account.contributorProfile.fetch(
+        fraudsterProfilePda
+      );
+      assert.deepEqual(profile.suspensionReason, { syntheticContent: {} });
+      assert.isTrue(profile.suspendedUntil.gt(new BN(Date.now() / 1000)));
+
+      try {
+        await program.methods
+          .submitVideo(
+            stringToBytes16("submission-fraud"),
+            contentHash("submission-fraud"),
+            "QmFraud",
+            "ArweaveFraud",
+            "https://metadata/fraud",
+            makeVideoSpec()
+          )
+          .accountsPartial({
+            submission: await nextSubmissionPda(bountyPda),
+            bountyPool: bountyPda,
+            contributor: fraudster.publicKey,
+            categoryReputation: findCategoryReputationPda(fraudster.publicKey),
+            systemProgram: anchor.web3.SystemProgram.programId,
+          })
+          .signers([fraudster])
+          .rpc();
+
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.include(err.toString(), "ContributorSuspended");
+      }
+    });
+
+    it("Lifts a suspension", async () => {
+      await program.methods
+        .liftSuspension()
+        .accountsPartial({
+          contributorProfile: fraudsterProfilePda,
+          config: configPda,
+          admin: authority.publicKey,
+        })
+        .rpc();
+
+      const profile = await program.account.contributorProfile.fetch(
+        fraudsterProfilePda
+      );
+      assert.equal(profile.suspendedUntil.toNumber(), 0);
+      assert.isNull(profile.suspensionReason);
+    });
+  });
+
+  // ============================================================================
+  // OWNERSHIP TRANSFER TESTS
+  // ============================================================================
+
+  describe("Ownership Transfers", () => {
+    const newOwner = Keypair.generate();
+    const handoverId = "bounty-handover";
+    let handoverPda: PublicKey;
+
+    before(async () => {
+      const airdropSig = await provider.connection.requestAirdrop(
+        newOwner.publicKey,
+        LAMPORTS_PER_SOL
+      );
+      await provider.connection.confirmTransaction(airdropSig);
+
+      [handoverPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("bounty"), Buffer.from(stringToBytes16(handoverId))],
+        program.programId
+      );
+
+      await program.methods
+        .createBounty(
+          stringToBytes16(handoverId),
+          new BN(0.1 * LAMPORTS_PER_SOL),
+          new BN(0.1 * LAMPORTS_PER_SOL),
+          1,
+          "Record a video of washing dishes",
+          makeRequirements(),
+          new BN(Date.now() / 1000 + 86400)
+        )
+        .accountsPartial({
+          bountyPool: handoverPda,
+          authority: authority.publicKey,
+          payer: authority.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .rpc();
+    });
+
+    it("Cancels a pending bounty handover", async () => {
+      await program.methods
+        .proposeBountyAuthority(newOwner.publicKey)
+        .accountsPartial({
+          bountyPool: handoverPda,
+          authority: authority.publicKey,
+        })
+        .rpc();
+      await program.methods
+        .cancelBountyAuthorityTransfer()
+        .accountsPartial({
+          bountyPool: handoverPda,
+          authority: authority.publicKey,
+        })
+        .rpc();
+
+      const bountyAccount = await program.account.bountyPool.fetch(handoverPda);
+      assert.isNull(bountyAccount.pendingAuthority);
+
+      try {
+        await program.methods
+          .acceptBountyAuthority()
+          .accountsPartial({
+            bountyPool: handoverPda,
+            buyerProfile: findBuyerProfilePda(newOwner.publicKey),
+            newAuthority: newOwner.publicKey,
+            payer: newOwner.publicKey,
+            systemProgram: anchor.web3.SystemProgram.programId,
+          })
+          .signers([newOwner])
+          .rpc();
+
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.include(err.toString(), "NotPendingAuthority");
+      }
+    });
+
+    it("Hands a bounty to a new authority", async () => {
+      await program.methods
+        .proposeBountyAuthority(newOwner.publicKey)
+        .accountsPartial({
+          bountyPool: handoverPda,
+          authority: authority.publicKey,
+        })
+        .rpc();
+      await program.methods
+        .acceptBountyAuthority()
+        .accountsPartial({
+          bountyPool: handoverPda,
+          buyerProfile: findBuyerProfilePda(newOwner.publicKey),
+          newAuthority: newOwner.publicKey,
+          payer: newOwner.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([newOwner])
+        .rpc();
+
+      const bountyAccount = await program.account.bountyPool.fetch(handoverPda);
+      assert.equal(
+        bountyAccount.authority.toString(),
+        newOwner.publicKey.toString()
+      );
+      assert.isNull(bountyAccount.pendingAuthority);
+
+      // The previous authority has lost control
+      try {
+        await program.methods
+          .pauseBounty()
+          .accountsPartial({
+            bountyPool: handoverPda,
+            authority: authority.publicKey,
+          })
+          .rpc();
+
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.include(err.toString(), "ConstraintHasOne");
+      }
+    });
+
+    it("Hands a dataset and its sale proceeds to a new creator", async () => {
+      await program.methods
+        .proposeDatasetCreator(newOwner.publicKey)
+        .accountsPartial({
+          datasetNft: datasetPda,
+          creator: authority.publicKey,
+        })
+        .rpc();
+      await program.methods
+        .acceptDatasetCreator()
+        .accountsPartial({
+          datasetNft: datasetPda,
+          newCreator: newOwner.publicKey,
+        })
+        .signers([newOwner])
+        .rpc();
+
+      const dataset = await program.account.datasetNft.fetch(datasetPda);
+      assert.equal(dataset.creator.toString(), newOwner.publicKey.toString());
+      assert.isNull(dataset.pendingCreator);
+    });
+  });
+
+  // ============================================================================
+  // CROWDFUNDING TESTS
+  // ============================================================================
+
+  describe("Crowdfunding", () => {
+    const sponsor = Keypair.generate();
+    const crowdfundedId = "bounty-crowdfund";
+    let crowdfundedPda: PublicKey;
+    let sponsorRecordPda: PublicKey;
+
+    before(async () => {
+      const airdropSig = await provider.connection.requestAirdrop(
+        sponsor.publicKey,
+        LAMPORTS_PER_SOL
+      );
+      await provider.connection.confirmTransaction(airdropSig);
+
+      [crowdfundedPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("bounty"), Buffer.from(stringToBytes16(crowdfundedId))],
+        program.programId
+      );
+      [sponsorRecordPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("sponsor"),
+          crowdfundedPda.toBuffer(),
+          sponsor.publicKey.toBuffer(),
+        ],
+        program.programId
+      );
+
+      await program.methods
+        .createBounty(
+          stringToBytes16(crowdfundedId),
+          new BN(0.1 * LAMPORTS_PER_SOL),
+          new BN(0.3 * LAMPORTS_PER_SOL),
+          4,
+          "Record a video of folding laundry",
+          makeRequirements(),
+          new BN(Date.now() / 1000 + 86400)
+        )
+        .accountsPartial({
+          bountyPool: crowdfundedPda,
+          authority: authority.publicKey,
+          payer: authority.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .rpc();
+    });
+
+    it("Records a sponsor's share of the pool", async () => {
+      await program.methods
+        .contributeToBounty(new BN(0.1 * LAMPORTS_PER_SOL))
+        .accountsPartial({
+          bountyPool: crowdfundedPda,
+          sponsorRecord: sponsorRecordPda,
+          sponsor: sponsor.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([sponsor])
+        .rpc();
+
+      const bountyAccount = await program.account.bountyPool.fetch(
+        crowdfundedPda
+      );
+      assert.equal(bountyAccount.totalPool.toNumber(), 0.4 * LAMPORTS_PER_SOL);
+      assert.equal(
+        bountyAccount.sponsoredAmount.toNumber(),
+        0.1 * LAMPORTS_PER_SOL
+      );
+
+      const record = await program.account.bountySponsor.fetch(
+        sponsorRecordPda
+      );
+      assert.equal(record.amount.toNumber(), 0.1 * LAMPORTS_PER_SOL);
+      assert.equal(record.refunded.toNumber(), 0);
+    });
+
+    it("Refunds the sponsor pro rata after cancellation", async () => {
+      await program.methods
+        .cancelBounty()
+        .accountsPartial({
+          bountyPool: crowdfundedPda,
+          authority: authority.publicKey,
+        })
+        .rpc();
+
+      const balanceBefore = await provider.connection.getBalance(
+        sponsor.publicKey
+      );
+      await program.methods
+        .claimSponsorRefund()
+        .accountsPartial({
+          bountyPool: crowdfundedPda,
+          sponsorRecord: sponsorRecordPda,
+          sponsor: sponsor.publicKey,
+        })
+        .signers([sponsor])
+        .rpc();
+      const balanceAfter = await provider.connection.getBalance(
+        sponsor.publicKey
+      );
+
+      // Nothing was paid out, so the sponsor gets its whole deposit back
+      assert.equal(balanceAfter - balanceBefore, 0.1 * LAMPORTS_PER_SOL);
+
+      try {
+        await program.methods
+          .claimSponsorRefund()
+          .accountsPartial({
+            bountyPool: crowdfundedPda,
+            sponsorRecord: sponsorRecordPda,
+            sponsor: sponsor.publicKey,
+          })
+          .signers([sponsor])
+          .rpc();
+
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.include(err.toString(), "RefundAlreadyClaimed");
+      }
+    });
+
+    it("Shares dataset sales with the source bounty's sponsors", async () => {
+      const sourceId = "bounty-dataset-src";
+      const sourceDatasetId = "dataset-sponsored";
+      const [sourcePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("bounty"), Buffer.from(stringToBytes16(sourceId))],
+        program.programId
+      );
+      const [sourceRecordPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("sponsor"),
+          sourcePda.toBuffer(),
+          sponsor.publicKey.toBuffer(),
+        ],
+        program.programId
+      );
+      const [sourceDatasetPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("dataset"), Buffer.from(stringToBytes16(sourceDatasetId))],
+        program.programId
+      );
+
+      await program.methods
+        .createBounty(
+          stringToBytes16(sourceId),
+          new BN(0.1 * LAMPORTS_PER_SOL),
+          new BN(0.3 * LAMPORTS_PER_SOL),
+          4,
+          "Record a video of washing dishes",
+          makeRequirements(),
+          new BN(Date.now() / 1000 + 86400)
+        )
+        .accountsPartial({
+          bountyPool: sourcePda,
+          authority: authority.publicKey,
+          payer: authority.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .rpc();
+      await program.methods
+        .contributeToBounty(new BN(0.1 * LAMPORTS_PER_SOL))
+        .accountsPartial({
+          bountyPool: sourcePda,
+          sponsorRecord: sourceRecordPda,
+          sponsor: sponsor.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([sponsor])
+        .rpc();
+      await program.methods
+        .completeBounty()
+        .accountsPartial({
+          bountyPool: sourcePda,
+          authority: authority.publicKey,
+        })
+        .rpc();
+
+      await program.methods
+        .createDataset(
+          stringToBytes16(sourceDatasetId),
+          { unlimited: {} },
+          new BN(0.2 * LAMPORTS_PER_SOL),
+          10
+        )
+        .accountsPartial({
+          datasetNft: sourceDatasetPda,
+          bountyPool: sourcePda,
+          creator: authority.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .rpc();
+
+      const dataset = await program.account.datasetNft.fetch(sourceDatasetPda);
+      assert.equal(dataset.bounty.toString(), sourcePda.toString());
+      // The sponsor put up 0.1 of the 0.4 SOL pool
+      assert.equal(dataset.sponsorBps, 2500);
+
+      try {
+        await program.methods
+          .purchaseDataset()
+          .accountsPartial({
+            datasetNft: sourceDatasetPda,
+            buyer: buyer.publicKey,
+            creator: authority.publicKey,
+            bountyPool: bountyPda, // Not the dataset's source
+            systemProgram: anchor.web3.SystemProgram.programId,
+          })
+          .signers([buyer])
+          .rpc();
+
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        assert.include(err.toString(), "DatasetBountyMismatch");
+      }
+
+      await program.methods
+        .purchaseDataset()
+        .accountsPartial({
+          datasetNft: sourceDatasetPda,
+          buyer: buyer.publicKey,
+          creator: authority.publicKey,
+          bountyPool: sourcePda,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([buyer])
+        .rpc();
+
+      const balanceBefore = await provider.connection.getBalance(
+        sponsor.publicKey
+      );
+      await program.methods
+        .claimSponsorProceeds()
+        .accountsPartial({
+          bountyPool: sourcePda,
+          sponsorRecord: sourceRecordPda,
+          sponsor: sponsor.publicKey,
+        })
+        .signers([sponsor])
+        .rpc();
+      const balanceAfter = await provider.connection.getBalance(
+        sponsor.publicKey
+      );
+
+      // The sponsor is the only one, so the whole 25% share is theirs
+      assert.equal(balanceAfter - balanceBefore, 0.05 * LAMPORTS_PER_SOL);
     });
   });
 